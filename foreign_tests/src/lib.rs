@@ -0,0 +1,2 @@
+//! Tests of `specified_default_derive` against foreign crates, which live in
+//! `tests` behind the features of the same names.
@@ -0,0 +1,50 @@
+#![cfg(feature = "bevy")]
+
+#[macro_use]
+extern crate specified_default_derive;
+extern crate bevy;
+
+use bevy::ecs::resource::Resource;
+use bevy::ecs::world::World;
+
+#[derive(Resource)]
+struct Screen {
+    width: u32,
+}
+
+#[derive(Resource, SpecifiedDefault)]
+struct Layout {
+    #[default = "2"]
+    columns: u32,
+    #[default(from_world = "world.resource::<Screen>().width / 2")]
+    column_width: u32,
+    #[default(value = "8", from_world = "world.get_resource::<Screen>().map_or(4, |screen| screen.width / 100)")]
+    gutter: u32,
+    name: String,
+}
+
+#[derive(Resource, SpecifiedDefault)]
+struct Volume {
+    #[default = "0.8"]
+    level: f32,
+}
+
+#[test]
+fn from_world_fields() {
+    let mut world = World::new();
+    world.insert_resource(Screen { width: 1600 });
+    world.init_resource::<Layout>();
+
+    let layout = world.resource::<Layout>();
+    assert_eq!(layout.columns, 2);
+    assert_eq!(layout.column_width, 800);
+    assert_eq!(layout.gutter, 16);
+    assert_eq!(layout.name, "");
+}
+
+#[test]
+fn default_types_still_init() {
+    let mut world = World::new();
+    world.init_resource::<Volume>();
+    assert_eq!(world.resource::<Volume>().level, 0.8);
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "glam")]
+
+#[macro_use]
+extern crate specified_default_derive;
+extern crate glam;
+
+use glam::{DVec2, IVec3, Mat4, Quat, Vec3, Vec3A};
+
+#[derive(SpecifiedDefault)]
+#[specified_default(default_const)]
+struct Transform {
+    #[default = "0.0, 1.0, 0.0"]
+    translation: Vec3,
+    #[default = "[1, -2, 3]"]
+    cell: IVec3,
+    #[default = "(0.5, 2)"]
+    scale: DVec2,
+    #[default = "1, 1, 1"]
+    aligned: Vec3A,
+    #[default(identity)]
+    rotation: Quat,
+    #[default(identity)]
+    matrix: Mat4,
+}
+
+#[test]
+fn vectors_take_their_components() {
+    let transform = Transform::default();
+    assert_eq!(transform.translation, Vec3::Y);
+    assert_eq!(transform.cell, IVec3::new(1, -2, 3));
+    assert_eq!(transform.scale, DVec2::new(0.5, 2.0));
+    assert_eq!(transform.aligned, Vec3A::ONE);
+}
+
+#[test]
+fn transforms_default_to_their_identity() {
+    const TRANSFORM: Transform = Transform::default_const();
+    assert_eq!(TRANSFORM.rotation, Quat::IDENTITY);
+    assert_eq!(TRANSFORM.matrix, Mat4::IDENTITY);
+}
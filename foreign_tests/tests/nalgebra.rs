@@ -0,0 +1,33 @@
+#![cfg(feature = "nalgebra")]
+
+#[macro_use]
+extern crate specified_default_derive;
+extern crate nalgebra;
+
+use nalgebra::{Matrix3, Point2, UnitQuaternion, Vector3};
+
+#[derive(SpecifiedDefault)]
+struct Body {
+    #[default = "1.0, 0.0, 0.0"]
+    velocity: Vector3<f32>,
+    #[default = "[2, 3]"]
+    origin: Point2<i64>,
+    #[default(identity)]
+    inertia: Matrix3<f64>,
+    #[default(identity)]
+    orientation: UnitQuaternion<f32>,
+}
+
+#[test]
+fn vectors_take_their_components() {
+    let body = Body::default();
+    assert_eq!(body.velocity, Vector3::x());
+    assert_eq!(body.origin, Point2::new(2, 3));
+}
+
+#[test]
+fn transforms_default_to_their_identity() {
+    let body = Body::default();
+    assert_eq!(body.inertia, Matrix3::identity());
+    assert_eq!(body.orientation, UnitQuaternion::identity());
+}
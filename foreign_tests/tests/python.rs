@@ -0,0 +1,35 @@
+#![cfg(feature = "pyo3")]
+
+#[macro_use]
+extern crate specified_default_derive;
+extern crate pyo3;
+
+use pyo3::prelude::*;
+
+#[pyclass]
+#[derive(SpecifiedDefault)]
+#[specified_default(py_new)]
+struct Retry {
+    #[default = "3"]
+    #[pyo3(get)]
+    attempts: u32,
+    #[default = "exponential"]
+    #[pyo3(get)]
+    backoff: String,
+}
+
+#[test]
+fn constructor_gives_the_default() {
+    let retry = Retry::py_new();
+    assert_eq!(retry.attempts, 3);
+    assert_eq!(retry.backoff, "exponential");
+}
+
+#[test]
+fn python_calls_the_constructor() {
+    Python::attach(|py| {
+        let retry = py.get_type::<Retry>().call0().unwrap();
+        assert_eq!(retry.getattr("attempts").unwrap().extract::<u32>().unwrap(), 3);
+        assert_eq!(retry.getattr("backoff").unwrap().extract::<String>().unwrap(), "exponential");
+    });
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "wasm-bindgen")]
+
+#[macro_use]
+extern crate specified_default_derive;
+extern crate wasm_bindgen;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(SpecifiedDefault)]
+#[specified_default(wasm_constructor)]
+pub struct Viewport {
+    #[default = "1280"]
+    pub width: u32,
+    #[default = "720"]
+    pub height: u32,
+    #[default = "1.0"]
+    pub scale: f64,
+}
+
+#[test]
+fn constructor_gives_the_default() {
+    let viewport = Viewport::new();
+    assert_eq!(viewport.width, 1280);
+    assert_eq!(viewport.height, 720);
+    assert_eq!(viewport.scale, 1.0);
+}
@@ -0,0 +1,295 @@
+//! `specified_default` re-exports the `SpecifiedDefault` derive from
+//! `specified_default_derive` alongside the runtime support some of its
+//! features rely on.
+//!
+//! # Registry
+//!
+//! With the `inventory` feature enabled, every field of each type deriving
+//! `SpecifiedDefault` is registered as a [`DefaultEntry`], so the defaults
+//! compiled into a binary can be listed at runtime.
+//!
+//! ```
+//! #[macro_use] extern crate specified_default;
+//! #
+//! # #[cfg(feature = "inventory")]
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Window {
+//!     #[default = "640"]
+//!     width: u32,
+//! }
+//!
+//! for entry in specified_default::registered_defaults() {
+//!     println!("{}.{} = {:?}", entry.type_name, entry.field, entry.value);
+//! }
+//! # }
+//! #
+//! # #[cfg(not(feature = "inventory"))]
+//! # fn main() {}
+//! ```
+//!
+//! # Describing defaults
+//!
+//! Types deriving `SpecifiedDefault` with
+//! `#[specified_default(describe)]` get a `describe_defaults` function that
+//! returns a [`DefaultsNode`] tree of their fields' defaults. Fields marked
+//! `#[default(nested)]` are described by their own type's tree, and the
+//! tree prints as an indented list, for output such as that of a
+//! `--print-default-config` flag.
+//!
+//! ```
+//! #[macro_use] extern crate specified_default;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(describe)]
+//! struct Tls {
+//!     #[default = "false"]
+//!     enabled: bool,
+//! }
+//!
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(describe)]
+//! struct Server {
+//!     #[default = "8080"]
+//!     port: u16,
+//!     #[default(nested)]
+//!     tls: Tls,
+//! }
+//!
+//! assert_eq!(Server::describe_defaults().to_string(), "Server\n  port = 8080\n  tls\n    enabled = false\n");
+//! # }
+//! ```
+//!
+//! # Configuration layers
+//!
+//! With the `config` feature enabled, `#[specified_default(config_source)]`
+//! generates a `<Type>Defaults` unit struct implementing `config::Source`,
+//! so the specified defaults can be the lowest layer of a `config::Config`.
+//! Only fields with a `#[default]` are included, and `#[default(nested)]`
+//! fields are tables taken from their type's own source.
+//!
+//! ```
+//! #[macro_use] extern crate specified_default;
+//! # #[cfg(feature = "config")]
+//! extern crate config;
+//! #
+//! # #[cfg(feature = "config")]
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(config_source)]
+//! struct Server {
+//!     #[default = "8080"]
+//!     port: u16,
+//! }
+//!
+//! let settings = config::Config::builder()
+//!     .add_source(ServerDefaults)
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(settings.get_int("port").unwrap(), 8080);
+//! # }
+//! #
+//! # #[cfg(not(feature = "config"))]
+//! # fn main() {}
+//! ```
+//!
+//! The `figment` feature does the same for `figment`:
+//! `#[specified_default(defaults_provider)]` generates a `defaults_provider`
+//! function returning a provider of the specified defaults to merge other
+//! providers over.
+//!
+//! ```
+//! #[macro_use] extern crate specified_default;
+//! # #[cfg(feature = "figment")]
+//! extern crate figment;
+//! #
+//! # #[cfg(feature = "figment")]
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(defaults_provider)]
+//! struct Server {
+//!     #[default = "8080"]
+//!     port: u16,
+//! }
+//!
+//! let figment = figment::Figment::from(Server::defaults_provider());
+//! assert_eq!(figment.extract_inner::<u16>("port").unwrap(), 8080);
+//! # }
+//! #
+//! # #[cfg(not(feature = "figment"))]
+//! # fn main() {}
+//! ```
+
+//!
+//! # Provenance
+//!
+//! `#[specified_default(provenance)]` generates a `<Type>Provenance` struct
+//! with a [`Provenance`] for each field. Its `Default` implementation records
+//! where `default` takes each field from, and types with `env_prefix` or
+//! `overrides` get `default_from_env_with_provenance` and
+//! `default_with_overrides_with_provenance`, which record the fields those
+//! replace, for answering where a value came from.
+//!
+//! ```
+//! #[macro_use] extern crate specified_default;
+//! #
+//! # fn main() {
+//! use specified_default::Provenance;
+//!
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(env_prefix = "DOCS_SERVER_", provenance)]
+//! struct Server {
+//!     #[default = "8080"]
+//!     port: u16,
+//!     workers: u8,
+//! }
+//!
+//! std::env::set_var("DOCS_SERVER_WORKERS", "4");
+//! let (server, provenance) = Server::default_from_env_with_provenance();
+//! assert_eq!(server.workers, 4);
+//! assert_eq!(provenance.port, Provenance::Specified);
+//! assert_eq!(provenance.workers, Provenance::Environment("DOCS_SERVER_WORKERS"));
+//! # }
+//! ```
+
+#[allow(unused_imports)]
+#[macro_use]
+extern crate specified_default_derive;
+
+#[cfg(feature = "config")]
+#[doc(hidden)]
+pub extern crate config;
+
+#[cfg(feature = "const-default")]
+#[doc(hidden)]
+pub extern crate const_default;
+
+#[cfg(feature = "weighted-defaults")]
+#[doc(hidden)]
+pub extern crate fastrand;
+
+#[cfg(feature = "figment")]
+#[doc(hidden)]
+pub extern crate figment;
+
+#[cfg(feature = "system-defaults")]
+#[doc(hidden)]
+pub extern crate hostname;
+
+#[cfg(feature = "inventory")]
+#[doc(hidden)]
+pub extern crate inventory;
+
+#[cfg(feature = "trace-defaults")]
+#[doc(hidden)]
+pub extern crate log;
+
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+pub extern crate proptest;
+
+#[cfg(feature = "ron")]
+#[doc(hidden)]
+pub extern crate ron;
+
+#[cfg(feature = "serde_json")]
+#[doc(hidden)]
+pub extern crate serde_json;
+
+#[cfg(feature = "serde_yaml")]
+#[doc(hidden)]
+pub extern crate serde_yaml;
+
+#[cfg(feature = "toml")]
+#[doc(hidden)]
+pub extern crate toml;
+
+pub use specified_default_derive::*;
+
+use std::fmt;
+
+/// A field's default, or a whole type's, as described by
+/// `describe_defaults`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DefaultsNode {
+    /// The name of the field, or of the type at the root of the tree.
+    pub name: &'static str,
+    /// The specified default, or `None` when the field uses its type's
+    /// `Default` implementation or is described by its children.
+    pub value: Option<String>,
+    /// The fields of a nested type.
+    pub children: Vec<DefaultsNode>,
+}
+
+impl DefaultsNode {
+    fn write(&self, f: &mut fmt::Formatter, depth: usize) -> fmt::Result {
+        write!(f, "{:indent$}{}", "", self.name, indent = depth * 2)?;
+        if let Some(ref value) = self.value {
+            write!(f, " = {}", value)?;
+        }
+        writeln!(f)?;
+
+        for child in &self.children {
+            child.write(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DefaultsNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+/// Where a field's value came from, as recorded by the `<Type>Provenance`
+/// structs generated with `#[specified_default(provenance)]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Provenance {
+    /// The field's `#[default]`.
+    Specified,
+    /// The `Default` implementation of the field's type, as the field has no
+    /// `#[default]`.
+    TypeDefault,
+    /// The `base` the type's defaults start from.
+    Base,
+    /// The environment variable `default_from_env` read.
+    Environment(&'static str),
+    /// The overrides given to `default_with_overrides`.
+    Override,
+}
+
+impl fmt::Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Provenance::Specified => f.write_str("the specified default"),
+            Provenance::TypeDefault => f.write_str("the type's default"),
+            Provenance::Base => f.write_str("the base defaults"),
+            Provenance::Environment(var) => write!(f, "${}", var),
+            Provenance::Override => f.write_str("an override"),
+        }
+    }
+}
+
+/// A field default registered by `#[derive(SpecifiedDefault)]`.
+#[cfg(feature = "inventory")]
+#[derive(Debug)]
+pub struct DefaultEntry {
+    /// The path of the type the field belongs to.
+    pub type_name: &'static str,
+    /// The name of the field.
+    pub field: &'static str,
+    /// The specified value, or `None` when the field uses its type's
+    /// `Default` implementation.
+    pub value: Option<&'static str>,
+}
+
+#[cfg(feature = "inventory")]
+inventory::collect!(DefaultEntry);
+
+/// Every field default registered in the binary.
+#[cfg(feature = "inventory")]
+pub fn registered_defaults() -> impl Iterator<Item = &'static DefaultEntry> {
+    inventory::iter::<DefaultEntry>.into_iter()
+}
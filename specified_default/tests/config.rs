@@ -0,0 +1,62 @@
+#![cfg(feature = "config")]
+
+extern crate config;
+#[macro_use]
+extern crate serde;
+#[macro_use]
+extern crate specified_default;
+
+use config::{Config, Source};
+
+#[derive(Debug, PartialEq, Deserialize, SpecifiedDefault)]
+#[specified_default(config_source)]
+struct Tls {
+    #[default = "false"]
+    enabled: bool,
+}
+
+#[derive(Debug, PartialEq, Deserialize, SpecifiedDefault)]
+#[specified_default(config_source)]
+struct Server {
+    #[default = "localhost"]
+    host: String,
+    #[default = "8080"]
+    port: u16,
+    #[default = "[1, 2]"]
+    workers: Vec<i64>,
+    #[default(nested)]
+    tls: Tls,
+}
+
+#[test]
+fn collects_specified_defaults() {
+    let map = ServerDefaults.collect().unwrap();
+    let mut keys = map.keys().cloned().collect::<Vec<_>>();
+    keys.sort();
+    assert_eq!(keys, ["host", "port", "tls", "workers"]);
+    assert_eq!(map["port"].clone().into_int().unwrap(), 8080);
+    assert!(!map["tls"].clone().into_table().unwrap()["enabled"].clone().into_bool().unwrap());
+}
+
+#[test]
+fn defaults_are_the_lowest_layer() {
+    let settings = Config::builder()
+        .add_source(ServerDefaults)
+        .set_override("port", 9090).unwrap()
+        .set_override("tls.enabled", true).unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(settings.try_deserialize::<Server>().unwrap(), Server {
+        host: "localhost".to_owned(),
+        port: 9090,
+        workers: vec![1, 2],
+        tls: Tls { enabled: true },
+    });
+}
+
+#[test]
+fn values_record_their_origin() {
+    let map = TlsDefaults.collect().unwrap();
+    assert_eq!(map["enabled"].origin(), Some("config::Tls defaults"));
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "const-default")]
+
+#[macro_use]
+extern crate specified_default;
+
+use specified_default::const_default::ConstDefault;
+
+#[derive(Debug, PartialEq, SpecifiedDefault)]
+#[specified_default(default_const)]
+struct Limits {
+    #[default = "1024"]
+    max_connections: u32,
+    #[default = "localhost"]
+    host: &'static str,
+}
+
+#[derive(Debug, PartialEq, SpecifiedDefault)]
+#[specified_default(default_const)]
+enum Level {
+    #[allow(dead_code)]
+    Debug,
+    #[default]
+    Info,
+}
+
+static LIMITS: Limits = Limits::DEFAULT;
+
+#[test]
+fn const_default_struct() {
+    assert_eq!(LIMITS, Limits::default());
+    assert_eq!(LIMITS.max_connections, 1024);
+}
+
+#[test]
+fn const_default_enum() {
+    const LEVEL: Level = <Level as ConstDefault>::DEFAULT;
+    assert_eq!(LEVEL, Level::Info);
+}
+
+#[test]
+fn const_default_generic() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(default_const)]
+    struct Slot<T> {
+        #[default(null)]
+        value: *const T,
+        #[default = "4"]
+        size: usize,
+    }
+
+    const SLOT: Slot<u8> = Slot::DEFAULT;
+    assert!(SLOT.value.is_null());
+    assert_eq!(SLOT.size, 4);
+}
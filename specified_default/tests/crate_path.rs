@@ -0,0 +1,18 @@
+// Named as a facade crate would re-export it, so that `specified_default`
+// itself doesn't resolve
+#[macro_use]
+extern crate specified_default as facade;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(crate = "::facade", describe, provenance)]
+struct Server {
+    #[default = "8080"]
+    port: u16,
+}
+
+#[test]
+fn generated_code_uses_the_crate_path() {
+    assert_eq!(Server::describe_defaults().to_string(), "Server\n  port = 8080\n");
+    assert_eq!(ServerProvenance::default().port, facade::Provenance::Specified);
+    assert_eq!(Server::default().port, 8080);
+}
@@ -0,0 +1,55 @@
+#[macro_use]
+extern crate specified_default;
+
+use specified_default::DefaultsNode;
+
+#[allow(dead_code)]
+#[derive(SpecifiedDefault)]
+#[specified_default(describe)]
+enum Level {
+    Debug,
+    #[default]
+    Info,
+}
+
+#[allow(dead_code)]
+#[derive(SpecifiedDefault)]
+#[specified_default(describe)]
+struct Logging {
+    #[default(nested)]
+    level: Level,
+    #[default(empty)]
+    targets: Vec<String>,
+}
+
+#[allow(dead_code)]
+#[derive(SpecifiedDefault)]
+#[specified_default(describe)]
+struct Config {
+    #[default = "8080"]
+    port: u16,
+    name: String,
+    #[default(nested)]
+    logging: Logging,
+}
+
+#[test]
+fn nested_types_are_described() {
+    let tree = Config::describe_defaults();
+    assert_eq!(tree.name, "Config");
+    assert_eq!(tree.children[0], DefaultsNode { name: "port", value: Some("8080".to_owned()), children: Vec::new() });
+    assert_eq!(tree.children[1].value, None);
+    assert_eq!(tree.children[2].children[0].value, Some("Info".to_owned()));
+}
+
+#[test]
+fn trees_print_as_indented_lists() {
+    assert_eq!(Config::describe_defaults().to_string(), "\
+Config
+  port = 8080
+  name
+  logging
+    level = Info
+    targets = empty
+");
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "figment")]
+
+extern crate figment;
+#[macro_use]
+extern crate serde;
+#[macro_use]
+extern crate specified_default;
+
+use figment::Figment;
+use figment::providers::{Env, Format, Json};
+
+#[derive(Debug, PartialEq, Deserialize, SpecifiedDefault)]
+#[specified_default(defaults_provider)]
+struct Tls {
+    #[default = "false"]
+    enabled: bool,
+}
+
+#[derive(Debug, PartialEq, Deserialize, SpecifiedDefault)]
+#[specified_default(defaults_provider)]
+struct AppConfig {
+    #[default = "localhost"]
+    host: String,
+    #[default = "8080"]
+    port: u16,
+    #[default = r#"["info", "warn"]"#]
+    levels: Vec<String>,
+    #[default(nested)]
+    tls: Tls,
+}
+
+#[test]
+fn extracts_the_defaults() {
+    let config: AppConfig = Figment::from(AppConfig::defaults_provider()).extract().unwrap();
+    assert_eq!(config, AppConfig::default());
+}
+
+#[test]
+fn later_providers_override_the_defaults() {
+    let config: AppConfig = Figment::from(AppConfig::defaults_provider())
+        .merge(Json::string(r#"{ "port": 9090, "tls": { "enabled": true } }"#))
+        .extract()
+        .unwrap();
+
+    assert_eq!(config.host, "localhost");
+    assert_eq!(config.port, 9090);
+    assert_eq!(config.tls, Tls { enabled: true });
+}
+
+#[test]
+fn merges_with_the_environment() {
+    ::std::env::set_var("FIGMENT_TEST_HOST", "example.com");
+    let config: AppConfig = Figment::from(AppConfig::defaults_provider())
+        .merge(Env::prefixed("FIGMENT_TEST_"))
+        .extract()
+        .unwrap();
+
+    assert_eq!(config.host, "example.com");
+    assert_eq!(config.port, 8080);
+}
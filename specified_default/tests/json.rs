@@ -0,0 +1,39 @@
+#![cfg(feature = "serde_json")]
+
+#[macro_use]
+extern crate serde;
+#[macro_use]
+extern crate specified_default;
+
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Retry {
+    retries: u32,
+    backoff_ms: u64,
+}
+
+#[derive(SpecifiedDefault)]
+struct Client {
+    #[default(json = r#"{"retries": 3, "backoff_ms": 100}"#)]
+    retry: Retry,
+    #[default(json = r#"{"accept": "application/json"}"#)]
+    headers: HashMap<String, String>,
+    #[default(json = "[80, 443]")]
+    ports: Vec<u16>,
+    #[default(json = "true")]
+    keep_alive: bool,
+}
+
+#[test]
+fn nested_values_are_deserialized() {
+    let client = Client::default();
+    assert_eq!(client.retry, Retry { retries: 3, backoff_ms: 100 });
+    assert_eq!(client.headers["accept"], "application/json");
+    assert_eq!(client.ports, vec![80, 443]);
+}
+
+#[test]
+fn scalars_are_built_like_values() {
+    assert!(Client::default().keep_alive);
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "toml")]
+
+#[macro_use]
+extern crate specified_default;
+
+#[specified_defaults(file = "tests/assets/defaults.toml")]
+mod config {
+    #[derive(SpecifiedDefault)]
+    pub struct Server {
+        pub host: String,
+        pub port: u16,
+        pub tls: bool,
+        pub mirrors: Vec<String>,
+        pub backoff: Vec<f64>,
+        #[default = "4"]
+        pub workers: usize,
+        pub name: Option<String>,
+    }
+
+    #[derive(SpecifiedDefault)]
+    pub struct Database {
+        pub url: String,
+    }
+
+    #[derive(SpecifiedDefault)]
+    pub struct Cache {
+        pub capacity: usize,
+    }
+
+    pub struct Unlisted {
+        pub value: u32,
+    }
+}
+
+#[test]
+fn manifest_values_are_field_defaults() {
+    let server = config::Server::default();
+    assert_eq!(server.host, "localhost");
+    assert_eq!(server.port, 8080);
+    assert!(server.tls);
+    assert_eq!(server.mirrors, ["eu.example.com", "us.example.com"]);
+    assert_eq!(server.backoff, [1.5, 3.0]);
+    assert_eq!(server.workers, 4);
+    assert_eq!(server.name, None);
+}
+
+#[test]
+fn dotted_keys_are_field_defaults() {
+    assert_eq!(config::Database::default().url, "postgres://localhost/app");
+    assert_eq!(config::Cache::default().capacity, 256);
+    assert_eq!(config::Unlisted { value: 1 }.value, 1);
+}
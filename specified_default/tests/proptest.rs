@@ -0,0 +1,67 @@
+#![cfg(feature = "proptest")]
+
+#[macro_use]
+extern crate proptest;
+#[macro_use]
+extern crate specified_default;
+
+use proptest::strategy::{Strategy, ValueTree};
+use proptest::test_runner::TestRunner;
+
+#[derive(Debug, PartialEq, SpecifiedDefault)]
+#[specified_default(arbitrary_near_default)]
+struct Pool {
+    #[default = "16"]
+    max_connections: u32,
+    #[default = "30"]
+    timeout_secs: u64,
+    #[default = "true"]
+    keep_alive: bool,
+    retries: u8,
+}
+
+#[test]
+fn mostly_yields_the_defaults() {
+    let mut runner = TestRunner::deterministic();
+    let strategy = Pool::arbitrary_near_default();
+    let samples = (0..1000)
+        .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+        .collect::<Vec<_>>();
+
+    let defaults = samples.iter().filter(|&pool| *pool == Pool::default()).count();
+    assert!(defaults > 400 && defaults < 650, "{} of the samples were the default", defaults);
+    assert!(samples.iter().any(|pool| pool.max_connections != 16));
+    assert!(samples.iter().all(|pool| pool.retries == 0));
+}
+
+#[test]
+fn many_fields() {
+    #[derive(Debug, SpecifiedDefault)]
+    #[specified_default(arbitrary_near_default)]
+    struct Wide {
+        #[default = "1"] a: u8,
+        #[default = "2"] b: u8,
+        #[default = "3"] c: u8,
+        #[default = "4"] d: u8,
+        #[default = "5"] e: u8,
+        #[default = "6"] f: u8,
+        #[default = "7"] g: u8,
+        #[default = "8"] h: u8,
+        #[default = "9"] i: u8,
+        #[default = "10"] j: u8,
+        #[default = "11"] k: u8,
+        #[default = "12"] l: u8,
+        #[default = "13"] m: u8,
+    }
+
+    let mut runner = TestRunner::deterministic();
+    let wide = Wide::arbitrary_near_default().new_tree(&mut runner).unwrap().current();
+    let _ = (wide.a, wide.b, wide.c, wide.d, wide.e, wide.f, wide.g, wide.h, wide.i, wide.j, wide.k, wide.l, wide.m);
+}
+
+proptest! {
+    #[test]
+    fn fields_without_defaults_are_not_perturbed(pool in Pool::arbitrary_near_default()) {
+        prop_assert_eq!(pool.retries, 0);
+    }
+}
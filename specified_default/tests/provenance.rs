@@ -0,0 +1,65 @@
+#[macro_use]
+extern crate specified_default;
+
+use std::collections::HashMap;
+
+use specified_default::Provenance;
+
+#[derive(Debug, SpecifiedDefault)]
+#[specified_default(env_prefix = "PROVENANCE_TEST_", overrides, provenance)]
+struct Server {
+    #[default = "localhost"]
+    host: String,
+    #[default = "8080"]
+    port: u16,
+    workers: u8,
+}
+
+#[test]
+fn default_provenance() {
+    assert_eq!(ServerProvenance::default(), ServerProvenance {
+        host: Provenance::Specified,
+        port: Provenance::Specified,
+        workers: Provenance::TypeDefault,
+    });
+}
+
+#[test]
+fn environment_provenance() {
+    std::env::set_var("PROVENANCE_TEST_PORT", "9090");
+    let (server, provenance) = Server::default_from_env_with_provenance();
+
+    assert_eq!(server.port, 9090);
+    assert_eq!(provenance.host, Provenance::Specified);
+    assert_eq!(provenance.port, Provenance::Environment("PROVENANCE_TEST_PORT"));
+    assert_eq!(provenance.port.to_string(), "$PROVENANCE_TEST_PORT");
+}
+
+#[test]
+fn override_provenance() {
+    let overrides = vec![("workers", "4")].into_iter().collect::<HashMap<_, _>>();
+    let (server, provenance) = Server::default_with_overrides_with_provenance(&overrides).unwrap();
+
+    assert_eq!((server.host.as_str(), server.workers), ("localhost", 4));
+    assert_eq!(provenance.workers, Provenance::Override);
+    assert_eq!(provenance.host, Provenance::Specified);
+
+    let overrides = vec![("threads", "4")].into_iter().collect::<HashMap<_, _>>();
+    assert!(Server::default_with_overrides_with_provenance(&overrides).is_err());
+}
+
+#[test]
+fn base_provenance() {
+    const BASE: Limits = Limits { soft: 1, hard: 2 };
+
+    #[derive(Clone, Debug, SpecifiedDefault)]
+    #[specified_default(base = "BASE", provenance)]
+    struct Limits {
+        #[default = "10"]
+        soft: u32,
+        hard: u32,
+    }
+
+    assert_eq!(LimitsProvenance::default(), LimitsProvenance { soft: Provenance::Specified, hard: Provenance::Base });
+    assert_eq!((Limits::default().soft, Limits::default().hard), (10, BASE.hard));
+}
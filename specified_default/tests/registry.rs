@@ -0,0 +1,29 @@
+#![cfg(feature = "inventory")]
+
+#[macro_use]
+extern crate specified_default;
+
+#[allow(dead_code)]
+#[derive(SpecifiedDefault)]
+struct Window {
+    #[default = "640"]
+    width: u32,
+    #[default(debug = "trace", release = "warn")]
+    log_level: String,
+
+    title: String,
+}
+
+#[test]
+fn fields_are_registered() {
+    let entries = specified_default::registered_defaults()
+        .filter(|entry| entry.type_name == "registry::Window")
+        .map(|entry| (entry.field, entry.value))
+        .collect::<Vec<_>>();
+
+    let log_level = if cfg!(debug_assertions) { "trace" } else { "warn" };
+    assert_eq!(entries.len(), 3);
+    assert!(entries.contains(&("width", Some("640"))));
+    assert!(entries.contains(&("log_level", Some(log_level))));
+    assert!(entries.contains(&("title", None)));
+}
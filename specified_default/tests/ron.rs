@@ -0,0 +1,36 @@
+#![cfg(feature = "ron")]
+
+#[macro_use]
+extern crate serde;
+#[macro_use]
+extern crate specified_default;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Vec2 {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+enum Team {
+    Red,
+    Blue,
+}
+
+#[derive(SpecifiedDefault)]
+struct Spawn {
+    #[default(ron = "(x: 1.0, y: 2.0)")]
+    position: Vec2,
+    #[default(ron = "[Red, Blue]")]
+    teams: Vec<Team>,
+}
+
+#[test]
+fn structs_are_deserialized() {
+    assert_eq!(Spawn::default().position, Vec2 { x: 1.0, y: 2.0 });
+}
+
+#[test]
+fn enum_variants_are_deserialized() {
+    assert_eq!(Spawn::default().teams, vec![Team::Red, Team::Blue]);
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "serde_json")]
+
+#[macro_use]
+extern crate serde;
+#[macro_use]
+extern crate specified_default;
+extern crate serde_json;
+
+#[specified_default(serde)]
+#[derive(Debug, Deserialize, PartialEq)]
+struct Server {
+    #[default = "localhost"]
+    host: String,
+    #[default = "8080"]
+    port: u16,
+    #[serde(default = "default_workers")]
+    #[default = "4"]
+    workers: u32,
+    tls: bool,
+}
+
+fn default_workers() -> u32 {
+    16
+}
+
+#[test]
+fn missing_fields_take_their_defaults() {
+    let server: Server = serde_json::from_str(r#"{"port": 9090, "tls": true}"#).unwrap();
+    assert_eq!(server, Server { host: "localhost".to_owned(), port: 9090, workers: 16, tls: true });
+}
+
+#[test]
+fn fields_without_a_default_are_still_required() {
+    assert!(serde_json::from_str::<Server>("{}").is_err());
+}
+
+#[test]
+fn default_is_unchanged() {
+    assert_eq!(Server::default(), Server { host: "localhost".to_owned(), port: 8080, workers: 4, tls: false });
+}
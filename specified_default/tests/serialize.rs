@@ -0,0 +1,42 @@
+#![cfg(all(feature = "serde_json", feature = "serde_yaml", feature = "toml", feature = "ron"))]
+
+#[macro_use]
+extern crate serde;
+#[macro_use]
+extern crate specified_default;
+
+#[derive(Serialize, SpecifiedDefault)]
+#[specified_default(serialize(json, toml, yaml, ron))]
+struct Server {
+    #[default = "localhost"]
+    host: String,
+    #[default = "8080"]
+    port: u16,
+    tls: Tls,
+}
+
+#[derive(Serialize, SpecifiedDefault)]
+struct Tls {
+    #[default = "true"]
+    enabled: bool,
+}
+
+#[test]
+fn json() {
+    assert_eq!(Server::default_json(), "{\n  \"host\": \"localhost\",\n  \"port\": 8080,\n  \"tls\": {\n    \"enabled\": true\n  }\n}");
+}
+
+#[test]
+fn toml() {
+    assert_eq!(Server::default_toml(), "host = \"localhost\"\nport = 8080\n\n[tls]\nenabled = true\n");
+}
+
+#[test]
+fn yaml() {
+    assert_eq!(Server::default_yaml(), "host: localhost\nport: 8080\ntls:\n  enabled: true\n");
+}
+
+#[test]
+fn ron() {
+    assert!(Server::default_ron().contains("host: \"localhost\""));
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "system-defaults")]
+
+#[macro_use]
+extern crate specified_default;
+
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+#[derive(SpecifiedDefault)]
+struct Worker {
+    #[default(num_cpus)]
+    threads: usize,
+    #[default(num_cpus)]
+    shards: u16,
+    #[default(hostname)]
+    node: String,
+    #[default(hostname)]
+    label: Rc<str>,
+    #[default(temp_dir)]
+    scratch: PathBuf,
+    #[default(temp_dir)]
+    spill: Box<Path>,
+}
+
+#[test]
+fn reads_the_machine() {
+    let worker = Worker::default();
+    let cpus = std::thread::available_parallelism().unwrap().get();
+    assert_eq!(worker.threads, cpus);
+    assert_eq!(usize::from(worker.shards), cpus);
+    assert!(!worker.node.is_empty());
+    assert_eq!(*worker.label, *worker.node);
+    assert_eq!(worker.scratch, std::env::temp_dir());
+    assert_eq!(&*worker.spill, worker.scratch.as_path());
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "toml")]
+
+#[macro_use]
+extern crate serde;
+#[macro_use]
+extern crate specified_default;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Server {
+    host: String,
+    port: u16,
+    tls: Tls,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Tls {
+    enabled: bool,
+}
+
+#[derive(SpecifiedDefault)]
+struct Config {
+    #[default(toml = r#"
+        host = "localhost"
+        port = 8080
+
+        [tls]
+        enabled = false
+    "#)]
+    server: Server,
+}
+
+#[test]
+fn tables_are_deserialized() {
+    let server = Config::default().server;
+    assert_eq!(server.host, "localhost");
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.tls, Tls { enabled: false });
+}
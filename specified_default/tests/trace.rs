@@ -0,0 +1,71 @@
+#![cfg(feature = "trace-defaults")]
+
+extern crate log;
+#[macro_use]
+extern crate specified_default;
+
+use std::sync::{Mutex, Once};
+
+static RECORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct Recorder;
+
+impl log::Log for Recorder {
+    fn enabled(&self, _: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        RECORDS.lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+fn records() -> Vec<String> {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        log::set_logger(&Recorder).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+    });
+    RECORDS.lock().unwrap().clone()
+}
+
+#[allow(dead_code)]
+#[derive(SpecifiedDefault)]
+#[specified_default(env_prefix = "TRACE_SERVER_")]
+struct Server {
+    #[default = "8080"]
+    port: u16,
+    #[default(include_str = "assets/motd.txt")]
+    motd: String,
+}
+
+#[allow(dead_code)]
+#[derive(SpecifiedDefault)]
+#[specified_default(env = "TRACE_LEVEL")]
+enum Level {
+    #[default]
+    Info,
+    Debug,
+}
+
+#[test]
+fn building_defaults_is_traced() {
+    records();
+    let _ = Server::default();
+    assert!(records().contains(&"building the default trace::Server; `motd` is included from assets/motd.txt".to_owned()));
+}
+
+#[test]
+fn environment_sources_are_traced() {
+    records();
+    std::env::set_var("TRACE_SERVER_PORT", "9090");
+    std::env::set_var("TRACE_LEVEL", "debug");
+    let _ = Server::default_from_env();
+    let _ = Level::default();
+
+    let records = records();
+    assert!(records.contains(&"taking trace::Server.port from $TRACE_SERVER_PORT".to_owned()));
+    assert!(records.contains(&"building the default trace::Level from $TRACE_LEVEL = Some(\"debug\")".to_owned()));
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "weighted-defaults")]
+
+#[macro_use]
+extern crate specified_default;
+
+#[derive(Debug, PartialEq, SpecifiedDefault)]
+enum Weather {
+    #[default(weight = 6)]
+    Clear,
+    #[default(weight = 3)]
+    Rain,
+    #[default(weight = 1)]
+    Storm,
+    #[allow(dead_code)]
+    #[default(weight = 0)]
+    Hail,
+    #[allow(dead_code)]
+    Snow,
+}
+
+#[derive(Debug, PartialEq, SpecifiedDefault)]
+#[specified_default(variant_defaults)]
+enum Agent {
+    #[default(weight = 1)]
+    Idle,
+    #[default(weight = 1)]
+    Walking {
+        #[default = "1.5"]
+        speed: f32,
+    },
+}
+
+#[test]
+fn picks_by_weight() {
+    specified_default::fastrand::seed(7);
+    let mut counts = [0; 3];
+    for _ in 0..10_000 {
+        match Weather::default() {
+            Weather::Clear => counts[0] += 1,
+            Weather::Rain => counts[1] += 1,
+            Weather::Storm => counts[2] += 1,
+            other => panic!("picked {:?}, which has no weight", other),
+        }
+    }
+
+    assert!((5_500..6_500).contains(&counts[0]), "{:?}", counts);
+    assert!((2_500..3_500).contains(&counts[1]), "{:?}", counts);
+    assert!((700..1_300).contains(&counts[2]), "{:?}", counts);
+}
+
+#[test]
+fn is_repeatable_with_a_seed() {
+    specified_default::fastrand::seed(42);
+    let first: Vec<_> = (0..32).map(|_| Weather::default()).collect();
+    specified_default::fastrand::seed(42);
+    let second: Vec<_> = (0..32).map(|_| Weather::default()).collect();
+    assert_eq!(first, second);
+}
+
+#[test]
+fn builds_variant_fields() {
+    let agents: Vec<_> = (0..64).map(|_| Agent::default()).collect();
+    assert!(agents.contains(&Agent::Idle));
+    assert!(agents.contains(&Agent::Walking { speed: 1.5 }));
+}
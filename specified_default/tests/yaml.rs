@@ -0,0 +1,57 @@
+#![cfg(feature = "serde_yaml")]
+
+#[macro_use]
+extern crate serde;
+#[macro_use]
+extern crate specified_default;
+
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Retry {
+    attempts: u32,
+    backoff_ms: Vec<u64>,
+}
+
+#[derive(SpecifiedDefault)]
+struct Client {
+    #[default(yaml = "attempts: 3\nbackoff_ms: [100, 200, 400]")]
+    retry: Retry,
+    #[default(yaml = "{ accept: json, encoding: gzip }")]
+    headers: BTreeMap<String, String>,
+    #[default(yaml = "30")]
+    timeout_secs: u64,
+}
+
+#[test]
+fn nested_values_are_deserialized() {
+    let client = Client::default();
+    assert_eq!(client.retry, Retry { attempts: 3, backoff_ms: vec![100, 200, 400] });
+    assert_eq!(client.headers["accept"], "json");
+    assert_eq!(client.headers["encoding"], "gzip");
+}
+
+#[test]
+fn scalars_are_built_like_values() {
+    assert_eq!(Client::default().timeout_secs, 30);
+}
+
+#[derive(SpecifiedDefault)]
+struct Pool {
+    #[default(yaml = "- 1\n- 2\n- 3", ty = "Vec<u8>")]
+    ids: Vec<u8>,
+    #[default(yaml = "workers: 2")]
+    limits: Limits,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Limits {
+    workers: usize,
+}
+
+#[test]
+fn sequences_and_type_hints_are_deserialized() {
+    let pool = Pool::default();
+    assert_eq!(pool.ids, vec![1, 2, 3]);
+    assert_eq!(pool.limits, Limits { workers: 2 });
+}
@@ -0,0 +1,593 @@
+//! Parsing of the `#[specified_default(...)]` and `#[default]` attributes.
+
+use syn;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+
+use formats::Format;
+use msrv::Msrv;
+use redundant;
+use system::System;
+
+pub const ATTRIBUTE_NAME: &str = "default";
+pub const CONTAINER_ATTRIBUTE_NAME: &str = "specified_default";
+
+const CONTAINER_KEYS: &[&str] = &["remote", "base", "overrides", "env_prefix", "default_ref", "prototype", "generate_tests", "discriminant", "debug_expand", "default_const", "no_panic", "env", "from_docs", "fingerprint", "context", "describe", "default_field", "variant_defaults", "config_source", "defaults_provider", "arbitrary_near_default", "serialize", "provenance", "crate", "assert_defaults", "wasm_constructor", "py_new", "ffi", "msrv", "redundant_defaults"];
+const FIELD_KEYS: &[&str] = &["value", "expr", "bound", "via", "try_via", "ty", "debug", "release", "variant", "path", "empty", "capacity", "repeat", "null", "zeroed", "identity", "nested", "shared", "base64", "include_bytes", "include_str", "regex", "flags", "secret", "secret_env", "from_ctx", "from_world", "group", "since", "previously", "num_cpus", "hostname", "temp_dir", "yaml", "json", "toml", "ron"];
+
+/// Options given by `#[specified_default(...)]` on the deriving type itself.
+#[derive(Default)]
+pub struct ContainerOptions {
+    /// A foreign type to construct instead of the deriving type.
+    pub remote: Option<syn::Path>,
+    /// An expression whose value is cloned for any fields without overrides.
+    pub base: Option<syn::Expr>,
+    /// Whether to generate `default_with_overrides`.
+    pub overrides: bool,
+    /// The prefix of the environment variables read by `default_from_env`.
+    pub env_prefix: Option<String>,
+    /// Whether to generate `default_ref`.
+    pub default_ref: bool,
+    /// Whether `default` clones a prototype built the first time it's called.
+    pub prototype: bool,
+    /// Whether to generate unit tests that build the defaults.
+    pub generate_tests: bool,
+    /// The discriminant of the enum variant to use as the default.
+    pub discriminant: Option<syn::Expr>,
+    /// Whether to print the generated code while compiling.
+    pub debug_expand: bool,
+    /// Whether to generate `default_const`.
+    pub default_const: bool,
+    /// Whether `default` must be built without anything that could panic.
+    pub no_panic: bool,
+    /// The environment variable that picks an enum's default variant.
+    pub env: Option<syn::LitStr>,
+    /// Whether fields take their defaults from `Default:` lines in their docs.
+    pub from_docs: bool,
+    /// Whether to generate `DEFAULTS_FINGERPRINT`.
+    pub fingerprint: bool,
+    /// The context type `default_with` takes fields from.
+    pub context: Option<syn::Type>,
+    /// Whether to generate `describe_defaults`.
+    pub describe: bool,
+    /// Whether to generate `default_<variant>` constructors, whose variant
+    /// fields take defaults.
+    pub variant_defaults: bool,
+    /// Whether to generate `default_field`.
+    pub default_field: bool,
+    /// Whether to generate `assert_specified_defaults`.
+    pub assert_defaults: bool,
+    /// Whether to generate a `<Type>Defaults` configuration source.
+    pub config_source: bool,
+    /// Whether to generate `defaults_provider`, a `figment` provider.
+    pub defaults_provider: bool,
+    /// Whether to generate `arbitrary_near_default`, a `proptest` strategy.
+    pub arbitrary_near_default: bool,
+    /// The formats to generate `default_<format>` functions serializing the
+    /// default to, each with the key it was named by.
+    pub serialize: Vec<(Format, syn::Path)>,
+    /// Whether to generate a `<Type>Provenance` recording where each field's
+    /// value came from.
+    pub provenance: bool,
+    /// Whether to generate a `wasm_bindgen` constructor returning the default.
+    pub wasm_constructor: bool,
+    /// Whether to generate a `pyo3` `#[new]` constructor returning the default.
+    pub py_new: bool,
+    /// Whether to export an `extern "C"` function returning the default.
+    pub ffi: bool,
+    /// The oldest compiler the generated code must build on.
+    pub msrv: Option<Msrv>,
+    /// How to report specified defaults that match their type's `Default`.
+    pub redundant_defaults: Option<redundant::Level>,
+    /// The path generated code names the `specified_default` crate by, for
+    /// when it is re-exported from another crate.
+    pub krate: Option<syn::Path>,
+}
+
+impl ContainerOptions {
+    pub fn from_attrs(attrs: &[syn::Attribute]) -> syn::Result<ContainerOptions> {
+        let mut options = ContainerOptions::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident(CONTAINER_ATTRIBUTE_NAME)) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("remote") {
+                    options.remote = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+                } else if meta.path.is_ident("base") {
+                    options.base = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+                } else if meta.path.is_ident("overrides") {
+                    options.overrides = true;
+                } else if meta.path.is_ident("env_prefix") {
+                    options.env_prefix = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                } else if meta.path.is_ident("default_ref") {
+                    options.default_ref = true;
+                } else if meta.path.is_ident("prototype") {
+                    options.prototype = true;
+                } else if meta.path.is_ident("generate_tests") {
+                    options.generate_tests = true;
+                } else if meta.path.is_ident("discriminant") {
+                    options.discriminant = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("debug_expand") {
+                    options.debug_expand = true;
+                } else if meta.path.is_ident("default_const") {
+                    options.default_const = true;
+                } else if meta.path.is_ident("no_panic") {
+                    options.no_panic = true;
+                } else if meta.path.is_ident("env") {
+                    options.env = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("from_docs") {
+                    options.from_docs = true;
+                } else if meta.path.is_ident("fingerprint") {
+                    options.fingerprint = true;
+                } else if meta.path.is_ident("context") {
+                    options.context = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+                } else if meta.path.is_ident("describe") {
+                    options.describe = true;
+                } else if meta.path.is_ident("default_field") {
+                    options.default_field = true;
+                } else if meta.path.is_ident("assert_defaults") {
+                    options.assert_defaults = true;
+                } else if meta.path.is_ident("variant_defaults") {
+                    options.variant_defaults = true;
+                } else if meta.path.is_ident("config_source") {
+                    options.config_source = true;
+                } else if meta.path.is_ident("defaults_provider") {
+                    options.defaults_provider = true;
+                } else if meta.path.is_ident("arbitrary_near_default") {
+                    options.arbitrary_near_default = true;
+                } else if meta.path.is_ident("crate") {
+                    options.krate = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+                } else if meta.path.is_ident("wasm_constructor") {
+                    options.wasm_constructor = true;
+                } else if meta.path.is_ident("py_new") {
+                    options.py_new = true;
+                } else if meta.path.is_ident("ffi") {
+                    options.ffi = true;
+                } else if meta.path.is_ident("redundant_defaults") {
+                    options.redundant_defaults = Some(redundant::Level::parse(&meta.value()?.parse()?)?);
+                } else if meta.path.is_ident("msrv") {
+                    options.msrv = Some(Msrv::parse(&meta.value()?.parse()?)?);
+                } else if meta.path.is_ident("provenance") {
+                    options.provenance = true;
+                } else if meta.path.is_ident("serialize") {
+                    meta.parse_nested_meta(|format| {
+                        match Format::from_key(&format.path) {
+                            Some(key) => options.serialize.push((key, format.path)),
+                            None => return Err(syn::Error::new_spanned(
+                                &format.path,
+                                "unknown format to serialize to, expected one of `yaml`, `json`, `toml`, `ron`",
+                            )),
+                        }
+                        Ok(())
+                    })?;
+                } else {
+                    return Err(unknown_key(&meta.path, CONTAINER_ATTRIBUTE_NAME, CONTAINER_KEYS));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(options)
+    }
+}
+
+/// Options given by `#[default]` on a struct field.
+#[derive(Default)]
+pub struct FieldOptions {
+    /// The string the default is created from.
+    pub value: Option<syn::LitStr>,
+    /// An intermediate type the value is converted from with `Into`.
+    pub via: Option<syn::Type>,
+    /// An intermediate type the value is converted from with `TryFrom`.
+    pub try_via: Option<syn::Type>,
+    /// The concrete type to create the value as, for when the field's type
+    /// doesn't make it obvious.
+    pub ty: Option<syn::Type>,
+    /// The value used instead in builds with debug assertions.
+    pub debug: Option<syn::LitStr>,
+    /// The value used instead in builds without debug assertions.
+    pub release: Option<syn::LitStr>,
+    /// The enum variant the field is set to, as a path or constructor call.
+    pub variant: Option<syn::LitStr>,
+    /// The function a function pointer field points to.
+    pub path: Option<syn::LitStr>,
+    /// Whether the field is an empty container made with `new`.
+    pub empty: bool,
+    /// The capacity an empty container is made with `with_capacity` for.
+    pub capacity: Option<syn::LitStr>,
+    /// An element and the number of times an array or `Vec` repeats it,
+    /// separated by a semicolon. Arrays can leave the count out.
+    pub repeat: Option<syn::LitStr>,
+    /// Whether the field is a null raw pointer.
+    pub null: bool,
+    /// Whether the field is built from zeroed memory.
+    pub zeroed: bool,
+    /// Whether the field is the identity of a matrix or transform.
+    pub identity: bool,
+    /// Whether the field is a type deriving `SpecifiedDefault` itself, which
+    /// `describe_defaults` describes in turn.
+    pub nested: bool,
+    /// An expression used as the field's value as it is.
+    pub expr: Option<syn::Expr>,
+    /// Where predicates the default relies on, used instead of any the
+    /// field's type would otherwise add.
+    pub bound: Option<Punctuated<syn::WherePredicate, Token![,]>>,
+    /// Base64-encoded bytes, decoded while expanding.
+    pub base64: Option<syn::LitStr>,
+    /// A file whose contents are included as the field's bytes.
+    pub include_bytes: Option<syn::LitStr>,
+    /// A file whose contents are included as the field's text.
+    pub include_str: Option<syn::LitStr>,
+    /// A regex pattern, checked while expanding.
+    pub regex: Option<syn::LitStr>,
+    /// `bitflags` flag names joined by `|`.
+    pub flags: Option<syn::LitStr>,
+    /// The value of a `secrecy` secret.
+    pub secret: Option<syn::LitStr>,
+    /// The environment variable a `secrecy` secret is read from when set.
+    pub secret_env: Option<syn::LitStr>,
+    /// The field of the context `default_with` takes this field from, as a
+    /// dotted path.
+    pub from_ctx: Option<syn::LitStr>,
+    /// An expression taking the field from the Bevy `world` its `FromWorld`
+    /// implementation is given.
+    pub from_world: Option<syn::LitStr>,
+    /// The group whose `apply_<group>_defaults` resets this field.
+    pub group: Option<syn::Ident>,
+    /// The release that changed the field's default to `value`.
+    pub since: Option<syn::LitStr>,
+    /// The field's default in releases before `since`.
+    pub previously: Option<syn::LitStr>,
+    /// A property of the machine read when the default is built.
+    pub system: Option<System>,
+    /// A snippet of a serialization format deserialized into the field.
+    pub serialized: Option<(Format, syn::LitStr)>,
+    /// Whether the value is built once and shared between all defaults
+    /// through an `Arc`.
+    pub shared: bool,
+}
+
+impl FieldOptions {
+    /// Reads the field's `#[default]` attribute, if it has one.
+    pub fn from_field(field: &syn::Field) -> syn::Result<Option<FieldOptions>> {
+        let attr = match field.attrs.iter().find(|attr| attr.path().is_ident(ATTRIBUTE_NAME)) {
+            Some(attr) => attr,
+            #[cfg(feature = "derivative")]
+            None => return ::derivative::field_options(field),
+            #[cfg(not(feature = "derivative"))]
+            None => return Ok(None),
+        };
+
+        let mut options = FieldOptions::default();
+        match attr.meta {
+            syn::Meta::NameValue(ref name_value) => options.value = Some(lit_str(&name_value.value)?),
+            syn::Meta::List(ref list) if !is_key_list(list) => options.expr = Some(list.parse_args()?),
+            syn::Meta::List(_) => {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("value") {
+                        options.value = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("expr") {
+                        options.expr = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+                    } else if meta.path.is_ident("bound") {
+                        let bound = meta.value()?.parse::<syn::LitStr>()?;
+                        options.bound = Some(bound.parse_with(Punctuated::parse_terminated)?);
+                    } else if meta.path.is_ident("via") {
+                        options.via = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+                    } else if meta.path.is_ident("try_via") {
+                        options.try_via = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+                    } else if meta.path.is_ident("ty") {
+                        options.ty = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+                    } else if meta.path.is_ident("debug") {
+                        options.debug = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("release") {
+                        options.release = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("variant") {
+                        options.variant = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("path") {
+                        options.path = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("empty") {
+                        options.empty = true;
+                    } else if meta.path.is_ident("capacity") {
+                        options.capacity = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("repeat") {
+                        options.repeat = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("null") {
+                        options.null = true;
+                    } else if meta.path.is_ident("zeroed") {
+                        options.zeroed = true;
+                    } else if meta.path.is_ident("identity") {
+                        options.identity = true;
+                    } else if meta.path.is_ident("nested") {
+                        options.nested = true;
+                    } else if meta.path.is_ident("shared") {
+                        options.shared = true;
+                    } else if meta.path.is_ident("base64") {
+                        options.base64 = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("include_bytes") {
+                        options.include_bytes = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("include_str") {
+                        options.include_str = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("regex") {
+                        options.regex = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("flags") {
+                        options.flags = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("secret") {
+                        options.secret = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("secret_env") {
+                        options.secret_env = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("from_ctx") {
+                        options.from_ctx = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("from_world") {
+                        options.from_world = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("group") {
+                        options.group = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+                    } else if meta.path.is_ident("since") {
+                        options.since = Some(meta.value()?.parse()?);
+                    } else if meta.path.is_ident("previously") {
+                        options.previously = Some(meta.value()?.parse()?);
+                    } else if let Some(system) = System::from_key(&meta.path) {
+                        options.system = Some(system);
+                    } else if let Some(format) = Format::from_key(&meta.path) {
+                        options.serialized = Some((format, meta.value()?.parse()?));
+                    } else {
+                        return Err(unknown_key(&meta.path, ATTRIBUTE_NAME, FIELD_KEYS));
+                    }
+
+                    Ok(())
+                })?;
+            },
+            syn::Meta::Path(_) => {
+                return Err(syn::Error::new_spanned(attr, "expected `#[default = \"...\"]` or `#[default(...)]`"));
+            },
+        }
+
+        if [options.via.is_some(), options.try_via.is_some(), options.ty.is_some()].iter().filter(|&&given| given).count() > 1 {
+            return Err(syn::Error::new_spanned(attr, "only one of `via`, `try_via` and `ty` can be given for one field"));
+        }
+        if let Some((ref format, _)) = options.serialized {
+            if options.via.is_some() || options.try_via.is_some() {
+                return Err(syn::Error::new_spanned(attr, format!("`{}` defaults are deserialized as the field's type, so they can't use `via` or `try_via`", format.key())));
+            }
+        }
+
+        if options.since.is_some() != options.previously.is_some() {
+            return Err(syn::Error::new_spanned(attr, "`since` and `previously` have to be given together"));
+        }
+        if options.since.is_some() && options.value.is_none() {
+            return Err(syn::Error::new_spanned(attr, "`since` and `previously` need the current default as a `value`"));
+        }
+
+        let sources = [
+            options.value.is_some(),
+            options.debug.is_some() || options.release.is_some(),
+            options.variant.is_some(),
+            options.path.is_some(),
+            options.empty,
+            options.capacity.is_some(),
+            options.repeat.is_some(),
+            options.null,
+            options.zeroed,
+            options.identity,
+            options.nested,
+            options.expr.is_some(),
+            options.base64.is_some(),
+            options.include_bytes.is_some(),
+            options.include_str.is_some(),
+            options.regex.is_some(),
+            options.flags.is_some(),
+            options.secret.is_some() || options.secret_env.is_some(),
+            options.system.is_some(),
+            options.serialized.is_some(),
+        ];
+        let paired = options.debug.is_some() == options.release.is_some();
+
+        // A field taken from the context or the world, or in a group, can
+        // leave `default` to its type
+        let needed = if options.from_ctx.is_some() || options.from_world.is_some() || options.group.is_some() { 0..=1 } else { 1..=1 };
+        if paired && needed.contains(&sources.iter().filter(|&&source| source).count()) {
+            Ok(Some(options))
+        } else {
+            Err(syn::Error::new(
+                attr.span(),
+                "expected one of a `value` key, both `debug` and `release` keys, a `variant` key, a `path` key, `empty`, a `capacity` key, a `repeat` key, `null`, `zeroed`, `identity`, `nested`, `base64`, `include_bytes`, `include_str`, `regex`, a `flags` key, `secret` or `secret_env`, `num_cpus`, `hostname`, `temp_dir`, a `yaml`, `json`, `toml` or `ron` snippet, or an expression",
+            ))
+        }
+    }
+}
+
+impl FieldOptions {
+    /// Whether the value is parsed from a string, rather than given as an
+    /// expression, path or marker.
+    pub fn parses_value(&self) -> bool {
+        self.value.is_some() || self.debug.is_some() || self.release.is_some()
+    }
+
+    /// The options written out as `key = "value"` pairs, which change
+    /// whenever the default they specify does.
+    pub fn describe(&self) -> String {
+        let strings = [
+            ("value", &self.value), ("debug", &self.debug), ("release", &self.release),
+            ("variant", &self.variant), ("path", &self.path), ("capacity", &self.capacity), ("repeat", &self.repeat),
+            ("base64", &self.base64), ("include_bytes", &self.include_bytes), ("include_str", &self.include_str),
+            ("regex", &self.regex), ("flags", &self.flags), ("secret_env", &self.secret_env), ("from_ctx", &self.from_ctx),
+            ("from_world", &self.from_world), ("since", &self.since), ("previously", &self.previously),
+        ];
+        let types = [("via", &self.via), ("try_via", &self.try_via), ("ty", &self.ty)];
+        // The secret itself is left out, so that it can't be recovered
+        let flags = [("empty", self.empty), ("null", self.null), ("zeroed", self.zeroed), ("identity", self.identity), ("nested", self.nested), ("shared", self.shared), ("secret", self.secret.is_some())];
+
+        let mut pairs = Vec::new();
+        pairs.extend(strings.iter().filter_map(|&(key, value)| value.as_ref().map(|value| format!("{} = {:?}", key, value.value()))));
+        pairs.extend(types.iter().filter_map(|&(key, ty)| ty.as_ref().map(|ty| format!("{} = {:?}", key, quote!(#ty).to_string()))));
+        pairs.extend(flags.iter().filter(|&&(_, set)| set).map(|&(key, _)| key.to_owned()));
+        if let Some(ref expr) = self.expr {
+            pairs.push(format!("expr = {:?}", quote!(#expr).to_string()));
+        }
+        if let Some(system) = self.system {
+            pairs.push(system.key().to_owned());
+        }
+        if let Some((ref format, ref snippet)) = self.serialized {
+            pairs.push(format!("{} = {:?}", format.key(), snippet.value()));
+        }
+
+        pairs.join(", ")
+    }
+}
+
+/// Adds `#[default = "..."]` to each named field whose doc comment has a line
+/// such as `/// Default: 640`, and checks that fields with both agree.
+pub fn doc_defaults(ast: &syn::DeriveInput) -> syn::Result<syn::DeriveInput> {
+    let mut ast = ast.clone();
+    let fields = match ast.data {
+        syn::Data::Struct(syn::DataStruct { fields: syn::Fields::Named(ref mut fields), .. }) => fields,
+        _ => return Err(syn::Error::new_spanned(&ast.ident, "`from_docs` is only supported for structs with named fields")),
+    };
+
+    for field in fields.named.iter_mut() {
+        let documented = match documented_default(&field.attrs) {
+            Some(documented) => documented,
+            None => continue,
+        };
+
+        match FieldOptions::from_field(field)? {
+            Some(FieldOptions { value: Some(ref value), .. }) if value.value() != documented.value() => {
+                let mut err = syn::Error::new(documented.span(), format!(
+                    "the documented default `{}` disagrees with `#[default]`", documented.value(),
+                ));
+                err.combine(syn::Error::new(value.span(), "`#[default]` given here"));
+                return Err(err);
+            },
+            Some(_) => {},
+            None => field.attrs.push(parse_quote! { #[default = #documented] }),
+        }
+    }
+
+    Ok(ast)
+}
+
+/// The value of the first `Default:` line in a doc comment, without any
+/// backticks around it.
+fn documented_default(attrs: &[syn::Attribute]) -> Option<syn::LitStr> {
+    attrs.iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(ref doc), .. }), ..
+            }) => Some(doc),
+            _ => None,
+        })
+        .flat_map(|doc| {
+            let text = doc.value();
+            text.lines()
+                .filter_map(|line| line.trim().strip_prefix("Default:"))
+                .map(|value| {
+                    let value = value.trim();
+                    let value = value.strip_prefix('`').and_then(|value| value.strip_suffix('`')).unwrap_or(value);
+                    syn::LitStr::new(value, doc.span())
+                })
+                .collect::<Vec<_>>()
+        })
+        .next()
+}
+
+/// The variants of `data` given a weight with `#[default(weight = N)]`, to
+/// be sampled among for the default, each with its weight.
+pub fn variant_weights(data: &syn::DataEnum) -> syn::Result<Vec<(&syn::Variant, syn::LitInt)>> {
+    let mut weights = Vec::new();
+    for variant in &data.variants {
+        for attr in variant.attrs.iter().filter(|attr| attr.path().is_ident(ATTRIBUTE_NAME)) {
+            if let syn::Meta::List(_) = attr.meta {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("weight") {
+                        weights.push((variant, meta.value()?.parse()?));
+                        Ok(())
+                    } else {
+                        Err(meta.error("expected `weight`, the only key `#[default(...)]` takes on an enum variant"))
+                    }
+                })?;
+            }
+        }
+    }
+    Ok(weights)
+}
+
+/// Reports `#[default]` attributes placed where they have no effect: on the
+/// type itself, as a name-value pair on an enum variant, or on the fields of
+/// an enum variant without `variant_defaults`. Lists of keys on variants are
+/// checked by `variant_weights`.
+pub fn check_placement(ast: &syn::DeriveInput, options: &ContainerOptions) -> syn::Result<()> {
+    let mut errors = Vec::new();
+
+    for attr in ast.attrs.iter().filter(|attr| attr.path().is_ident(ATTRIBUTE_NAME)) {
+        errors.push(syn::Error::new_spanned(attr, format!(
+            "`#[default]` goes on fields or enum variants; options for the whole type go in `#[{}(...)]`",
+            CONTAINER_ATTRIBUTE_NAME,
+        )));
+    }
+
+    if let syn::Data::Enum(ref data) = ast.data {
+        for variant in &data.variants {
+            for attr in variant.attrs.iter().filter(|attr| attr.path().is_ident(ATTRIBUTE_NAME)) {
+                if let syn::Meta::Path(_) | syn::Meta::List(_) = attr.meta {
+                    continue;
+                }
+                errors.push(syn::Error::new_spanned(attr, "the default enum variant is marked with a bare `#[default]`"));
+            }
+
+            for field in variant.fields.iter().filter(|_| !options.variant_defaults) {
+                for attr in field.attrs.iter().filter(|attr| attr.path().is_ident(ATTRIBUTE_NAME)) {
+                    errors.push(syn::Error::new_spanned(attr, format!(
+                        "`#[default]` only has an effect on the fields of enum variants with `#[{}(variant_defaults)]`",
+                        CONTAINER_ATTRIBUTE_NAME,
+                    )));
+                }
+            }
+        }
+    }
+
+    let mut errors = errors.into_iter();
+    match errors.next() {
+        Some(mut first) => {
+            first.extend(errors);
+            Err(first)
+        },
+        None => Ok(()),
+    }
+}
+
+/// Whether `#[default(...)]` holds keys rather than an expression, such as
+/// `Vec::with_capacity(16)`. Anything in the form of `key = ...` counts, so
+/// that a misspelt key is reported as one.
+pub fn is_key_list(list: &syn::MetaList) -> bool {
+    let metas = match list.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated) {
+        Ok(metas) => metas,
+        Err(_) => return false,
+    };
+
+    let is_key = |path: &syn::Path| path.get_ident().is_some_and(|ident| FIELD_KEYS.iter().any(|key| ident == key));
+    metas.iter().all(|meta| match *meta {
+        syn::Meta::NameValue(ref name_value) => name_value.path.get_ident().is_some(),
+        syn::Meta::Path(ref path) => is_key(path),
+        syn::Meta::List(ref list) => is_key(&list.path),
+    })
+}
+
+/// The error for a key that isn't one of `accepted`, spanned to the key.
+fn unknown_key(path: &syn::Path, attribute: &str, accepted: &[&str]) -> syn::Error {
+    let key = path.get_ident().map_or_else(|| quote!(#path).to_string(), |ident| ident.to_string());
+    let accepted = accepted.iter().map(|key| format!("`{}`", key)).collect::<Vec<_>>().join(", ");
+
+    syn::Error::new_spanned(
+        path,
+        format!("unknown `#[{}]` key `{}`, expected one of {}", attribute, key, accepted),
+    )
+}
+
+fn lit_str(expr: &syn::Expr) -> syn::Result<syn::LitStr> {
+    match *expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(ref lit), .. }) => Ok(lit.clone()),
+        // `macro_rules!` wraps `$value:expr` fragments in an invisible group
+        syn::Expr::Group(ref group) => lit_str(&group.expr),
+        _ => Err(syn::Error::new_spanned(expr, "expected a string literal")),
+    }
+}
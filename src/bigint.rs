@@ -0,0 +1,96 @@
+//! Compile-time parsing of `num-bigint` values.
+
+use proc_macro2::{Span, TokenStream};
+use syn;
+
+/// Parses a value such as `"123456789012345678901234567890"` for a `BigUint`
+/// or `BigInt` field and builds it from its little-endian bytes, so that no
+/// digits are parsed at runtime.
+///
+/// Returns `None` when the field is neither.
+pub fn bigint_value(ty: &syn::Type, value: &str, span: Span) -> Option<syn::Result<TokenStream>> {
+    let signed = big_integer(ty)?;
+    let name = if signed { "BigInt" } else { "BigUint" };
+
+    let (negative, mut bytes) = match parse(value) {
+        Ok(parsed) => parsed,
+        Err(reason) => return Some(Err(syn::Error::new(span, format!("`{}` isn't a valid `{}`: {}", value, name, reason)))),
+    };
+
+    if !signed {
+        if negative && bytes.iter().any(|&byte| byte != 0) {
+            return Some(Err(syn::Error::new(span, format!("`{}` is negative, but a `BigUint` can't be", value))));
+        }
+        return Some(Ok(quote! { <#ty>::from_bytes_le(&[#(#bytes),*]) }));
+    }
+
+    // Leave room for the sign bit, then take the two's complement of
+    // negative values.
+    if bytes.last().is_some_and(|&byte| byte & 0x80 != 0) {
+        bytes.push(0);
+    }
+    if negative {
+        let mut carry = true;
+        for byte in &mut bytes {
+            let (sum, overflowed) = (!*byte).overflowing_add(carry as u8);
+            *byte = sum;
+            carry = overflowed;
+        }
+    }
+    Some(Ok(quote! { <#ty>::from_signed_bytes_le(&[#(#bytes),*]) }))
+}
+
+/// Splits an integer written in decimal, or in hexadecimal, octal or binary
+/// with a `0x`, `0o` or `0b` prefix, into its sign and the little-endian
+/// bytes of its magnitude, which always has at least one byte.
+fn parse(value: &str) -> Result<(bool, Vec<u8>), &'static str> {
+    let value = value.trim();
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(value) => (true, value),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (radix, digits) = match value.get(..2) {
+        Some("0x") | Some("0X") => (16, &value[2..]),
+        Some("0o") | Some("0O") => (8, &value[2..]),
+        Some("0b") | Some("0B") => (2, &value[2..]),
+        _ => (10, value),
+    };
+
+    let digits = digits.replace('_', "");
+    if digits.is_empty() {
+        return Err("expected an integer such as `12345`");
+    }
+
+    let mut bytes = vec![0u8];
+    for c in digits.chars() {
+        let mut carry = c.to_digit(radix).ok_or("expected an integer such as `12345`")?;
+        for byte in &mut bytes {
+            let product = u32::from(*byte) * radix + carry;
+            *byte = product as u8;
+            carry = product >> 8;
+        }
+        if carry != 0 {
+            bytes.push(carry as u8);
+        }
+    }
+
+    Ok((negative, bytes))
+}
+
+/// Whether `ty` is a `BigInt`, or `None` when it's not a `BigUint` either.
+fn big_integer(ty: &syn::Type) -> Option<bool> {
+    match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path.segments.last().and_then(|segment| {
+            if !segment.arguments.is_empty() {
+                None
+            } else if segment.ident == "BigInt" {
+                Some(true)
+            } else if segment.ident == "BigUint" {
+                Some(false)
+            } else {
+                None
+            }
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,34 @@
+//! Compile-time expansion of `bitflags` flag names.
+
+use proc_macro2::TokenStream;
+use syn;
+
+/// Expands a `flags` key such as `"READ | WRITE"` into
+/// `<Ty>::READ | <Ty>::WRITE`. The flag names are spanned to the key's
+/// value, so an unknown one is reported there.
+pub fn flags_value(ty: &syn::Type, value: &syn::LitStr) -> syn::Result<TokenStream> {
+    let names = value.value();
+    let flags = names.split('|').map(str::trim).collect::<Vec<_>>();
+    if let Some(flag) = flags.iter().find(|flag| !is_flag_name(flag)) {
+        return Err(syn::Error::new(value.span(), format!("`{}` isn't a flag name such as `READ`", flag)));
+    }
+
+    let flags = flags.into_iter()
+        .map(|flag| {
+            let flag = syn::Ident::new(flag, value.span());
+            quote! { <#ty>::#flag }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(quote! { #(#flags)|* })
+}
+
+fn is_flag_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_uppercase() || first == '_' => {
+            chars.all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,90 @@
+//! Byte string defaults, such as `#[default(base64 = "...")]`.
+
+use proc_macro2::{Span, TokenStream};
+use syn;
+
+const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Decodes standard, optionally padded base64. Whitespace is ignored, so
+/// that long values can be split over several lines.
+pub fn decode_base64(encoded: &syn::LitStr) -> syn::Result<Vec<u8>> {
+    let text = encoded.value();
+    let text = text.trim_end_matches(|c: char| c == '=' || c.is_whitespace());
+
+    let mut bytes = Vec::new();
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in text.chars().filter(|c| !c.is_whitespace()) {
+        let sextet = ALPHABET.iter().position(|&letter| letter as char == c)
+            .ok_or_else(|| syn::Error::new(encoded.span(), format!("`{}` isn't a base64 character", c)))?;
+
+        buffer = buffer << 6 | sextet as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    if bits >= 6 {
+        return Err(syn::Error::new(encoded.span(), "base64 can't end with a single character"));
+    }
+
+    Ok(bytes)
+}
+
+/// Builds a field of type `ty` from `bytes`, a `&'static [u8; N]`. Slices
+/// borrow it, arrays copy it, and anything else is converted from the slice
+/// with `From`, which covers `Vec<u8>` as well as boxed and reference-counted
+/// slices. With the `bytes` feature, `Bytes` borrows it too, through the
+/// const `Bytes::from_static`.
+pub fn bytes_value(ty: &syn::Type, bytes: TokenStream) -> TokenStream {
+    match *ty {
+        syn::Type::Reference(_) => quote! { &#bytes[..] },
+        syn::Type::Array(_) => quote! { *#bytes },
+        syn::Type::Paren(ref paren) => bytes_value(&paren.elem, bytes),
+        #[cfg(feature = "bytes")]
+        _ if is_bytes(ty, "Bytes") => quote! { <#ty>::from_static(#bytes) },
+        _ => quote! { ::std::convert::From::from(&#bytes[..]) },
+    }
+}
+
+/// Builds a `Bytes` or `BytesMut` field from the bytes of the string
+/// `value`, or `None` for other types. Only `Bytes` can be built in a const
+/// fn, so `BytesMut` is left out when `constant` is set.
+#[cfg(feature = "bytes")]
+pub fn string_bytes_value(ty: &syn::Type, value: &str, span: Span, constant: bool) -> Option<TokenStream> {
+    if !is_bytes(ty, "Bytes") && (constant || !is_bytes(ty, "BytesMut")) {
+        return None;
+    }
+
+    let literal = syn::LitByteStr::new(value.as_bytes(), span);
+    Some(bytes_value(ty, quote! { #literal }))
+}
+
+#[cfg(feature = "bytes")]
+fn is_bytes(ty: &syn::Type, name: &str) -> bool {
+    match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path.segments.last().is_some_and(|segment| {
+            segment.ident == name && segment.arguments.is_empty()
+        }),
+        _ => false,
+    }
+}
+
+/// Checks that an array field is as long as the `len` bytes it holds.
+pub fn check_len(ty: &syn::Type, len: usize, span: Span) -> syn::Result<()> {
+    let expected = match *ty {
+        syn::Type::Array(syn::TypeArray { len: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(ref expected), .. }), .. }) => expected,
+        syn::Type::Paren(ref paren) => return check_len(&paren.elem, len, span),
+        _ => return Ok(()),
+    };
+
+    match expected.base10_parse::<usize>() {
+        Ok(expected) if expected != len => Err(syn::Error::new(span, format!(
+            "this decodes to {} bytes, but the field holds {}", len, expected,
+        ))),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,66 @@
+//! Colors written in hex, such as `#ff8800`.
+
+use proc_macro2::{Span, TokenStream};
+use syn;
+
+/// Color types from the `rgb` and `palette` crates with `u8` components,
+/// which are built with `new`.
+const THREE_COMPONENTS: &[&str] = &["RGB8", "RGB", "Rgb", "Srgb"];
+const FOUR_COMPONENTS: &[&str] = &["RGBA8", "RGBA", "Rgba", "Srgba"];
+
+/// Decodes `#rrggbb` or `#rrggbbaa` for `[u8; 3]` and `[u8; 4]` fields and
+/// for color types, as a literal that can also be used in a const fn. A
+/// four-component color without an alpha is opaque.
+///
+/// Returns `None` when the value isn't hex or the field isn't a color.
+pub fn color_value(ty: &syn::Type, value: &str, span: Span) -> Option<syn::Result<TokenStream>> {
+    let hex = value.trim().strip_prefix('#')?;
+    let (components, constructor) = color_components(ty)?;
+
+    Some(decode(hex, components, span).map(|bytes| match constructor {
+        true => quote! { <#ty>::new(#(#bytes),*) },
+        false => quote! { [#(#bytes),*] },
+    }))
+}
+
+/// The number of components of a color type, and whether it's built with
+/// `new` rather than being an array.
+fn color_components(ty: &syn::Type) -> Option<(usize, bool)> {
+    match *ty {
+        syn::Type::Array(syn::TypeArray { ref elem, len: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(ref len), .. }), .. }) => {
+            let is_u8 = matches!(**elem, syn::Type::Path(ref path) if path.path.is_ident("u8"));
+            match len.base10_parse().ok()? {
+                len @ 3..=4 if is_u8 => Some((len, false)),
+                _ => None,
+            }
+        },
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => {
+            let name = path.segments.last()?.ident.to_string();
+            if THREE_COMPONENTS.contains(&name.as_str()) {
+                Some((3, true))
+            } else if FOUR_COMPONENTS.contains(&name.as_str()) {
+                Some((4, true))
+            } else {
+                None
+            }
+        },
+        syn::Type::Paren(ref paren) => color_components(&paren.elem),
+        _ => None,
+    }
+}
+
+fn decode(hex: &str, components: usize, span: Span) -> syn::Result<Vec<u8>> {
+    let digits = hex.len();
+    if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) || (digits != 6 && digits != 8) {
+        return Err(syn::Error::new(span, format!("`#{}` isn't a color in the form `#rrggbb` or `#rrggbbaa`", hex)));
+    }
+    if digits / 2 > components {
+        return Err(syn::Error::new(span, format!("`#{}` has an alpha, but the field only has {} components", hex, components)));
+    }
+
+    let mut bytes = (0..digits).step_by(2)
+        .map(|start| u8::from_str_radix(&hex[start..start + 2], 16).expect("digits are checked to be hex"))
+        .collect::<Vec<_>>();
+    bytes.resize(components, u8::MAX);
+    Ok(bytes)
+}
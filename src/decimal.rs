@@ -0,0 +1,61 @@
+//! Compile-time parsing of `rust_decimal` values.
+
+use proc_macro2::{Span, TokenStream};
+use syn;
+
+/// The most digits after the decimal point a `Decimal` can hold.
+const MAX_SCALE: u32 = 28;
+
+/// Parses a value such as `"19.99"` for a `Decimal` field and builds it with
+/// `Decimal::from_parts`, which is exact and can be used in a const fn.
+///
+/// Returns `None` when the field isn't a `Decimal`.
+pub fn decimal_value(ty: &syn::Type, value: &str, span: Span) -> Option<syn::Result<TokenStream>> {
+    if !is_decimal(ty) {
+        return None;
+    }
+
+    Some(parse(value).map_err(|reason| syn::Error::new(span, format!("`{}` isn't a valid `Decimal`: {}", value, reason))).map(
+        |(negative, mantissa, scale)| {
+            let lo = mantissa as u32;
+            let mid = (mantissa >> 32) as u32;
+            let hi = (mantissa >> 64) as u32;
+            quote! { <#ty>::from_parts(#lo, #mid, #hi, #negative, #scale) }
+        },
+    ))
+}
+
+/// Splits a decimal number into its sign, its digits as an integer, and the
+/// number of those digits after the decimal point.
+fn parse(value: &str) -> Result<(bool, u128, u32), &'static str> {
+    let value = value.trim();
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(value) => (true, value),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+
+    let digits = format!("{}{}", whole, fraction).replace('_', "");
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err("expected a number such as `19.99`");
+    }
+
+    let scale = fraction.replace('_', "").len() as u32;
+    if scale > MAX_SCALE {
+        return Err("it has more than 28 digits after the decimal point");
+    }
+
+    match digits.parse::<u128>() {
+        Ok(mantissa) if mantissa >> 96 == 0 => Ok((negative, mantissa, scale)),
+        _ => Err("it doesn't fit in 96 bits"),
+    }
+}
+
+fn is_decimal(ty: &syn::Type) -> bool {
+    match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path.segments.last().is_some_and(|segment| {
+            segment.ident == "Decimal" && segment.arguments.is_empty()
+        }),
+        _ => false,
+    }
+}
@@ -0,0 +1,112 @@
+//! `specified_struct!`, which declares structs with their defaults written
+//! as initializers, `field: Type = value`.
+
+use proc_macro2::TokenStream;
+use syn;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+
+use attr::{self, ATTRIBUTE_NAME};
+
+/// The structs given to `specified_struct!`.
+pub struct Declarations {
+    structs: Vec<Declaration>,
+}
+
+struct Declaration {
+    attrs: Vec<syn::Attribute>,
+    vis: syn::Visibility,
+    struct_token: Token![struct],
+    ident: syn::Ident,
+    generics: syn::Generics,
+    fields: Punctuated<DeclaredField, Token![,]>,
+}
+
+struct DeclaredField {
+    attrs: Vec<syn::Attribute>,
+    vis: syn::Visibility,
+    ident: syn::Ident,
+    ty: syn::Type,
+    value: Option<syn::Expr>,
+}
+
+impl Parse for Declarations {
+    fn parse(input: ParseStream) -> syn::Result<Declarations> {
+        let mut structs = Vec::new();
+        while !input.is_empty() {
+            structs.push(input.parse()?);
+        }
+        Ok(Declarations { structs })
+    }
+}
+
+impl Parse for Declaration {
+    fn parse(input: ParseStream) -> syn::Result<Declaration> {
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let struct_token = input.parse()?;
+        let ident = input.parse()?;
+        let mut generics: syn::Generics = input.parse()?;
+        generics.where_clause = input.parse()?;
+
+        let content;
+        braced!(content in input);
+        let fields = content.parse_terminated(DeclaredField::parse, Token![,])?;
+        Ok(Declaration { attrs, vis, struct_token, ident, generics, fields })
+    }
+}
+
+impl Parse for DeclaredField {
+    fn parse(input: ParseStream) -> syn::Result<DeclaredField> {
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        let value = if input.parse::<Option<Token![=]>>()?.is_some() {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(DeclaredField { attrs, vis, ident, ty, value })
+    }
+}
+
+/// Expands each declaration into a struct deriving `SpecifiedDefault`, with
+/// each initializer as the field's `#[default(...)]` expression.
+pub fn expand(declarations: Declarations) -> syn::Result<TokenStream> {
+    let mut structs = Vec::new();
+    for declaration in declarations.structs {
+        let Declaration { attrs, vis, struct_token, ident, generics, fields } = declaration;
+        let where_clause = &generics.where_clause;
+
+        let fields = ::all_fields(fields.into_iter().map(|field| {
+            let DeclaredField { mut attrs, vis, ident, ty, value } = field;
+            if let Some(value) = value {
+                if let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident(ATTRIBUTE_NAME)) {
+                    return Err(syn::Error::new_spanned(attr, format!("`{}` has an initializer as well as a `#[default]`", ident)));
+                }
+                // Parenthesized, a value such as `empty` can't be taken
+                // for a key
+                let list: syn::MetaList = parse_quote! { default(#value) };
+                attrs.push(if attr::is_key_list(&list) {
+                    parse_quote! { #[default((#value))] }
+                } else {
+                    parse_quote! { #[default(#value)] }
+                });
+            }
+            Ok(quote! { #(#attrs)* #vis #ident: #ty })
+        }))?;
+
+        // The derive comes first, to introduce `#[specified_default]` as
+        // its helper before any use
+        structs.push(quote! {
+            #[derive(SpecifiedDefault)]
+            #(#attrs)*
+            #vis #struct_token #ident #generics #where_clause {
+                #(#fields),*
+            }
+        });
+    }
+    Ok(quote! { #(#structs)* })
+}
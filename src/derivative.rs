@@ -0,0 +1,60 @@
+//! Reading `derivative` crate attributes, for types migrating from
+//! `#[derive(Derivative)]` one field at a time.
+
+use syn;
+use syn::punctuated::Punctuated;
+
+use attr::FieldOptions;
+
+const ATTRIBUTE_NAME: &str = "derivative";
+
+/// Reads a field's `#[derivative(Default(value = "..."))]`, if it has one.
+/// As with `derivative`, the value is an expression rather than a string to
+/// parse.
+pub fn field_options(field: &syn::Field) -> syn::Result<Option<FieldOptions>> {
+    for list in default_lists(&field.attrs)? {
+        let mut options = FieldOptions::default();
+        list.parse_nested_meta(|meta| {
+            if meta.path.is_ident("value") {
+                options.expr = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `value`"))
+            }
+        })?;
+
+        if options.expr.is_some() {
+            return Ok(Some(options));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Whether an enum variant is marked `#[derivative(Default)]`.
+pub fn is_default_variant(variant: &syn::Variant) -> bool {
+    derivative_metas(&variant.attrs)
+        .map(|metas| metas.iter().any(|meta| matches!(*meta, syn::Meta::Path(ref path) if path.is_ident("Default"))))
+        .unwrap_or(false)
+}
+
+/// The `Default(...)` lists given in `#[derivative(...)]` attributes.
+fn default_lists(attrs: &[syn::Attribute]) -> syn::Result<Vec<syn::MetaList>> {
+    Ok(derivative_metas(attrs)?.into_iter()
+        .filter_map(|meta| match meta {
+            syn::Meta::List(list) if list.path.is_ident("Default") => Some(list),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Every trait's options in `#[derivative(...)]`, such as `Debug = "ignore"`
+/// or `Default(value = "1")`.
+fn derivative_metas(attrs: &[syn::Attribute]) -> syn::Result<Vec<syn::Meta>> {
+    let mut metas = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident(ATTRIBUTE_NAME)) {
+        metas.extend(attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)?);
+    }
+
+    Ok(metas)
+}
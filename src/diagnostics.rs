@@ -0,0 +1,105 @@
+//! Warnings about defaults that are likely mistakes, emitted through the
+//! unstable `proc_macro::Diagnostic` API on nightly.
+
+use proc_macro::{Diagnostic, Level};
+use proc_macro2::Span;
+use syn;
+use syn::spanned::Spanned;
+
+use attr::FieldOptions;
+use value::{INTEGERS, generic_arg, is_path};
+
+/// Warns about the defaults of `ast`'s fields that build but probably don't
+/// do what was meant. Fields whose attributes don't parse are left to the
+/// errors they report.
+pub fn warn_suspicious(ast: &syn::DeriveInput) {
+    let fields: Vec<&syn::Field> = match ast.data {
+        syn::Data::Struct(ref data) => data.fields.iter().collect(),
+        syn::Data::Enum(ref data) => data.variants.iter().flat_map(|variant| &variant.fields).collect(),
+        syn::Data::Union(_) => return,
+    };
+
+    for field in fields {
+        let options = match FieldOptions::from_field(field) {
+            Ok(Some(options)) => options,
+            _ => continue,
+        };
+        let ty = options.ty.as_ref().unwrap_or(&field.ty);
+        if let Some(ref expr) = options.expr {
+            check_expr(expr);
+        }
+
+        for value in [&options.value, &options.debug, &options.release].iter().filter_map(|value| value.as_ref()) {
+            check_value(ty, value);
+        }
+
+        if let (Some(debug), Some(release)) = (&options.debug, &options.release) {
+            if debug.value() == release.value() {
+                warn(debug.span(), format!(
+                    "`debug` and `release` are both `{}`, so the build profile makes no difference; give it as `value` instead",
+                    debug.value(),
+                ));
+            }
+        }
+    }
+}
+
+fn check_value(ty: &syn::Type, value: &syn::LitStr) {
+    if value.value().trim().is_empty() && !is_string_like(ty) {
+        warn(value.span(), format!(
+            "an empty default for `{}`, which isn't a string type, is unlikely to parse",
+            quote!(#ty).to_string().replace(' ', ""),
+        ));
+    }
+}
+
+/// Warns about casts of fractional numbers to integers, such as `1.5 as u32`,
+/// which drop the fraction without a word. Integer fields given strings such
+/// as `"1.5"` are already rejected as they're built.
+fn check_expr(expr: &syn::Expr) {
+    let cast = match *expr {
+        syn::Expr::Cast(ref cast) => cast,
+        syn::Expr::Paren(ref paren) => return check_expr(&paren.expr),
+        _ => return,
+    };
+    if !INTEGERS.iter().any(|integer| is_path(&cast.ty, integer)) {
+        return;
+    }
+
+    let float = match *cast.expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Float(ref float), .. }) => float,
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), ref expr, .. }) => match **expr {
+            syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Float(ref float), .. }) => float,
+            _ => return,
+        },
+        _ => return,
+    };
+    if float.base10_parse::<f64>().is_ok_and(|value| value.fract() != 0.0) {
+        let ty = &cast.ty;
+        warn(cast.span(), format!("`{}` loses its fraction when cast to `{}`", float, quote!(#ty)));
+    }
+}
+
+/// Whether an empty string is a meaningful value of `ty`. Wrappers such as
+/// `Option` are looked through.
+fn is_string_like(ty: &syn::Type) -> bool {
+    match *ty {
+        syn::Type::Reference(ref reference) => is_string_like(&reference.elem),
+        syn::Type::Paren(ref paren) => is_string_like(&paren.elem),
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => {
+            if let Some(inner) = ["Option", "Box", "Rc", "Arc", "Cow"].iter().filter_map(|name| generic_arg(ty, name)).next() {
+                return is_string_like(inner);
+            }
+            path.segments.last().is_some_and(|segment| {
+                ["String", "str", "OsString", "OsStr", "PathBuf", "Path", "CString", "CStr", "Utf8PathBuf", "Utf8Path"]
+                    .iter()
+                    .any(|name| segment.ident == name)
+            })
+        },
+        _ => false,
+    }
+}
+
+fn warn(span: Span, message: String) {
+    Diagnostic::spanned(span.unwrap(), Level::Warning, message).emit();
+}
@@ -0,0 +1,133 @@
+//! Field defaults written as snippets of a serialization format, such as
+//! `#[default(yaml = "...")]`, and deserialized into the field's type.
+
+use proc_macro2::{Span, TokenStream};
+use syn;
+
+/// A serialization format a default can be written in.
+pub enum Format {
+    Yaml,
+    Json,
+    Toml,
+    Ron,
+}
+
+impl Format {
+    /// The format named by a `#[default]` key.
+    pub fn from_key(path: &syn::Path) -> Option<Format> {
+        if path.is_ident("yaml") {
+            Some(Format::Yaml)
+        } else if path.is_ident("json") {
+            Some(Format::Json)
+        } else if path.is_ident("toml") {
+            Some(Format::Toml)
+        } else if path.is_ident("ron") {
+            Some(Format::Ron)
+        } else {
+            None
+        }
+    }
+
+    /// The `#[default]` key the format is given with.
+    pub fn key(&self) -> &'static str {
+        match *self {
+            Format::Yaml => "yaml",
+            Format::Json => "json",
+            Format::Toml => "toml",
+            Format::Ron => "ron",
+        }
+    }
+
+    /// The crate, and feature of the same name, that deserializes the format.
+    fn crate_name(&self) -> &'static str {
+        match *self {
+            Format::Yaml => "serde_yaml",
+            Format::Json => "serde_json",
+            Format::Toml => "toml",
+            Format::Ron => "ron",
+        }
+    }
+
+    /// Whether the feature the format needs is enabled.
+    fn enabled(&self) -> bool {
+        match *self {
+            Format::Yaml => cfg!(feature = "serde_yaml"),
+            Format::Json => cfg!(feature = "serde_json"),
+            Format::Toml => cfg!(feature = "toml"),
+            Format::Ron => cfg!(feature = "ron"),
+        }
+    }
+
+    /// Checks that `snippet` is well-formed, so that a typo is reported here
+    /// rather than when the default is first built. A snippet that is a lone
+    /// YAML or JSON number or boolean is returned as it is, to be built like
+    /// a `value`.
+    pub fn check(&self, snippet: &syn::LitStr) -> syn::Result<Option<String>> {
+        match *self {
+            #[cfg(feature = "serde_yaml")]
+            Format::Yaml => {
+                match ::serde_yaml::from_str::<::serde_yaml::Value>(&snippet.value()) {
+                    Ok(::serde_yaml::Value::Number(ref number)) if !number.is_nan() && !number.is_infinite() => Ok(Some(number.to_string())),
+                    Ok(::serde_yaml::Value::Bool(boolean)) => Ok(Some(boolean.to_string())),
+                    Ok(_) => Ok(None),
+                    Err(err) => Err(syn::Error::new(snippet.span(), format!("invalid YAML: {}", err))),
+                }
+            },
+            #[cfg(feature = "serde_json")]
+            Format::Json => {
+                match ::serde_json::from_str::<::serde_json::Value>(&snippet.value()) {
+                    Ok(::serde_json::Value::Number(ref number)) => Ok(Some(number.to_string())),
+                    Ok(::serde_json::Value::Bool(boolean)) => Ok(Some(boolean.to_string())),
+                    Ok(_) => Ok(None),
+                    Err(err) => Err(syn::Error::new(snippet.span(), format!("invalid JSON: {}", err))),
+                }
+            },
+            #[cfg(feature = "toml")]
+            Format::Toml => match snippet.value().parse::<::toml::Table>() {
+                Ok(_) => Ok(None),
+                Err(err) => Err(syn::Error::new(snippet.span(), format!("invalid TOML: {}", err))),
+            },
+            #[cfg(feature = "ron")]
+            Format::Ron => match ::ron::from_str::<::ron::Value>(&snippet.value()) {
+                Ok(_) => Ok(None),
+                Err(err) => Err(syn::Error::new(snippet.span(), format!("invalid RON: {}", err))),
+            },
+            #[allow(unreachable_patterns)]
+            _ => Err(syn::Error::new(snippet.span(), format!(
+                "`{}` defaults need the `{}` feature of `specified_default`", self.key(), self.crate_name(),
+            ))),
+        }
+    }
+
+    /// Serializes `value`, a reference, as a string at runtime, pretty-printed
+    /// where the format has a pretty form.
+    pub fn to_string(&self, value: TokenStream, span: Span) -> syn::Result<TokenStream> {
+        if !self.enabled() {
+            return Err(syn::Error::new(span, format!(
+                "serializing to {} needs the `{}` feature of `specified_default`", self.key(), self.crate_name(),
+            )));
+        }
+
+        Ok(match *self {
+            Format::Yaml => quote! { specified_default::serde_yaml::to_string(#value) },
+            Format::Json => quote! { specified_default::serde_json::to_string_pretty(#value) },
+            Format::Toml => quote! { specified_default::toml::to_string_pretty(#value) },
+            Format::Ron => quote! {
+                specified_default::ron::ser::to_string_pretty(#value, ::std::default::Default::default())
+            },
+        })
+    }
+
+    /// Deserializes `snippet` as a `ty` at runtime.
+    pub fn value_for(&self, ty: &syn::Type, snippet: &syn::LitStr) -> TokenStream {
+        let krate = syn::Ident::new(self.crate_name(), snippet.span());
+        let runtime = ::runtime_crate();
+        quote_spanned! {snippet.span()=>
+            {
+                #runtime
+                specified_default::#krate::from_str::<#ty>(#snippet)
+                    .expect(&format!("Failed to deserialize {}", #snippet))
+            }
+        }
+    }
+}
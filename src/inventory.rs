@@ -0,0 +1,52 @@
+//! Registration of field defaults with the `specified_default` registry.
+
+use proc_macro2::TokenStream;
+use syn;
+
+use attr::FieldOptions;
+
+/// Submits a `DefaultEntry` for each field of the struct named `type_name`.
+pub fn register<'a, I>(type_name: &str, fields: I) -> syn::Result<TokenStream>
+    where I: IntoIterator<Item = &'a syn::Field>
+{
+    let mut entries = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().map(|ident| ident.to_string());
+        let value = match FieldOptions::from_field(field)? {
+            Some(FieldOptions { value: Some(value), .. }) |
+            Some(FieldOptions { variant: Some(value), .. }) |
+            Some(FieldOptions { path: Some(value), .. }) |
+            Some(FieldOptions { base64: Some(value), .. }) |
+            Some(FieldOptions { include_bytes: Some(value), .. }) |
+            Some(FieldOptions { include_str: Some(value), .. }) |
+            Some(FieldOptions { regex: Some(value), .. }) |
+            Some(FieldOptions { serialized: Some((_, value)), .. }) => quote! { Some(#value) },
+            Some(FieldOptions { secret: Some(_), .. }) |
+            Some(FieldOptions { secret_env: Some(_), .. }) => quote! { Some("[REDACTED]") },
+            Some(FieldOptions { expr: Some(expr), .. }) => {
+                let expr = quote!(#expr).to_string();
+                quote! { Some(#expr) }
+            },
+            Some(FieldOptions { debug: Some(debug), release: Some(release), .. }) => quote! {
+                if cfg!(debug_assertions) { Some(#debug) } else { Some(#release) }
+            },
+            _ => quote! { None },
+        };
+
+        entries.push(quote! {
+            specified_default::inventory::submit! {
+                specified_default::DefaultEntry {
+                    type_name: concat!(module_path!(), "::", #type_name),
+                    field: #ident,
+                    value: #value,
+                }
+            }
+        });
+    }
+
+    let runtime = ::runtime_crate();
+    Ok(::msrv::scoped(&format!("REGISTER_{}", type_name), quote! {
+        #runtime
+        #(#entries)*
+    }))
+}
@@ -31,6 +31,28 @@
 //! # }
 //! ```
 //!
+//! Values can be given as raw or multi-line strings, which are kept exactly
+//! as written. That suits JSON blobs, SQL and templates.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Query {
+//!     #[default = r#"{"limit": 10}"#]
+//!     options: String,
+//!     #[default = "SELECT *
+//!   FROM users"]
+//!     sql: String,
+//! }
+//!
+//! let query = Query::default();
+//! assert_eq!(query.options, r#"{"limit": 10}"#);
+//! assert_eq!(query.sql, "SELECT *\n  FROM users");
+//! # }
+//! ```
+//!
 //! ## Enums
 //!
 //! ```
@@ -49,76 +71,4249 @@
 //! # }
 //! ```
 //!
-extern crate proc_macro;
-#[macro_use] extern crate quote;
-extern crate syn;
-
-use proc_macro::TokenStream;
-use syn::{Body, VariantData};
-
-#[doc(hidden)]
-#[proc_macro_derive(SpecifiedDefault, attributes(default))]
-pub fn specify_defaults(input: TokenStream) -> TokenStream {
-    let s = input.to_string();
-    let ast = syn::parse_derive_input(&s).unwrap();
-    let gen = impl_specified_defaults(&ast);
-
-    gen.parse().unwrap()
-}
-
-fn impl_specified_defaults(ast: &syn::DeriveInput) -> quote::Tokens {
-    const ATTRIBUTE_NAME: &'static str = "default";
-
-    match ast.body {
-        Body::Struct(VariantData::Struct(ref fields)) => {
-            let fields = fields.iter()
-                .map(|field| {
-                    let ident = field.ident.as_ref();
-                    let attrs = field.attrs.clone();
-
-                    match attrs.iter().find(|attr| attr.value.name() == ATTRIBUTE_NAME) {
-                        Some(attr) => {
-                            if let syn::MetaItem::NameValue(_, ref lit) = attr.value {
-                                if let syn::Lit::Str(ref value, _) = *lit {
-                                    quote! { #ident: #value.parse().expect(&format!("Failed to parse {}", #value)) }
-                                } else {
-                                    panic!("#[derive(SpecifiedDefault)] only supports string literal attributes");
-                                }
-                            } else {
-                                panic!("#[derive(SpecifiedDefault)] only supports named value attributes");
-                            }
-                        },
-                        None => quote! { #ident: Default::default() }
-                    }
-                })
-                .collect::<Vec<_>>();
-
-            let name = &ast.ident;
-            quote! {
-                impl Default for #name {
-                    fn default() -> #name {
-                        #name {
-                            #(#fields),*
-                        }
-                    }
-                }
-            }
-        },
-        Body::Enum(ref variants) => {
-            let default = variants.iter().find(|variant| {
-                variant.attrs.iter().find(|attr| attr.name() == ATTRIBUTE_NAME).is_some()
-            }).expect("#[derive(SpecifiedDefault) requires an enum variant is attributed with 'default']");
-
-            let name = &ast.ident;
-            let variant = &default.ident;
-            quote! {
-                impl Default for #name {
-                    fn default() -> #name {
-                        #name::#variant
-                    }
-                }
-            }
-        },
-        _ => panic!("#[derive(SpecifiedDefault)] does not support other struct variants")
-    }
+//! Enums that mirror a protocol's codes can pick the default by its
+//! discriminant instead.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(Debug, PartialEq, SpecifiedDefault)]
+//! #[specified_default(discriminant = 3)]
+//! enum Status {
+//!     Ok = 1,
+//!     Retry,
+//!     Failed,
+//! }
+//!
+//! assert_eq!(Status::default(), Status::Failed);
+//! # }
+//! ```
+//!
+//! With `#[specified_default(variant_defaults)]`, the fields of every
+//! variant can take defaults, and each variant gets a `default_<variant>`
+//! constructor named in snake case that builds it from them. The `Default`
+//! implementation builds the `#[default]` variant the same way.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(Debug, PartialEq, SpecifiedDefault)]
+//! #[specified_default(variant_defaults)]
+//! enum Shape {
+//!     #[default]
+//!     Circle {
+//!         #[default = "1.0"]
+//!         radius: f64,
+//!     },
+//!     Rectangle(#[default = "2.0"] f64, #[default = "3.0"] f64),
+//! }
+//!
+//! assert_eq!(Shape::default(), Shape::Circle { radius: 1.0 });
+//! assert_eq!(Shape::default_rectangle(), Shape::Rectangle(2.0, 3.0));
+//! # }
+//! ```
+//!
+//! `default_for` looks a variant up by its name, as written or in snake
+//! case, so that a mode read from a config file can be turned into a fully
+//! built value: `Shape::default_for("rectangle")` is
+//! `Some(Shape::Rectangle(2.0, 3.0))`, and unknown names give `None`.
+//!
+//! ## Platform-specific enum defaults
+//!
+//! Several variants can be marked `#[default]` as long as `#[cfg]` leaves
+//! only one of them in any given build.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(Debug, PartialEq, SpecifiedDefault)]
+//! enum Backend {
+//!     #[cfg(windows)]
+//!     #[default]
+//!     Dx12,
+//!     #[cfg(not(windows))]
+//!     #[default]
+//!     Vulkan,
+//!     Software,
+//! }
+//!
+//! # #[cfg(not(windows))]
+//! assert_eq!(Backend::default(), Backend::Vulkan);
+//! # }
+//! ```
+//!
+//! ## Weighted enum defaults
+//!
+//! With the `weighted-defaults` feature of `specified_default` enabled,
+//! variants marked `#[default(weight = N)]` are picked among at random each
+//! time the default is built, in proportion to their weights, for fuzzing
+//! harnesses and simulations that want varied starting states. The fields of
+//! the picked variant are built like those of the `variant_defaults`
+//! constructors, and `specified_default::fastrand::seed` makes the picks
+//! repeatable.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! enum Weather {
+//!     #[default(weight = 6)]
+//!     Clear,
+//!     #[default(weight = 3)]
+//!     Rain,
+//!     #[default(weight = 1)]
+//!     Storm,
+//! }
+//! ```
+//!
+//! ## Expressions
+//!
+//! Anything in `#[default(...)]` that isn't a list of keys is taken as an
+//! expression and used as the field's value as it is, so it keeps its syntax
+//! highlighting and is checked like any other code.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! const RETRIES: u32 = 3;
+//!
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Client {
+//!     #[default(Vec::with_capacity(16))]
+//!     buffer: Vec<u8>,
+//!     #[default(RETRIES * 2)]
+//!     max_attempts: u32,
+//! }
+//!
+//! let client = Client::default();
+//! assert_eq!(client.buffer.capacity(), 16);
+//! assert_eq!(client.max_attempts, 6);
+//! # }
+//! ```
+//!
+//! The expression can also be given as a string with the `expr` key, next to
+//! other keys. An expression relying on a type parameter's traits, such as
+//! its associated constants, can then name the bounds it needs with the
+//! `bound` key, which are added to the where clause of the `Default`
+//! implementation in place of any the field's type would add.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! trait Capacity {
+//!     const DEFAULT_CAPACITY: usize;
+//! }
+//!
+//! struct Frame(u32);
+//!
+//! impl Capacity for Frame {
+//!     const DEFAULT_CAPACITY: usize = 8;
+//! }
+//!
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Queue<T> {
+//!     #[default(expr = "Vec::with_capacity(T::DEFAULT_CAPACITY)", bound = "T: Capacity")]
+//!     items: Vec<T>,
+//! }
+//!
+//! assert_eq!(Queue::<Frame>::default().items.capacity(), 8);
+//! # }
+//! ```
+//!
+//! ## Conversions
+//!
+//! Types that implement `From` but not `FromStr` can be created through an
+//! intermediate type with `via`. A `via` of `&str` passes the string itself.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! struct Port(u16);
+//!
+//! impl From<u16> for Port {
+//!     fn from(port: u16) -> Port { Port(port) }
+//! }
+//!
+//! #[derive(SpecifiedDefault)]
+//! struct Server {
+//!     #[default(via = "u16", value = "80")]
+//!     port: Port,
+//! }
+//!
+//! assert_eq!(Server::default().port.0, 80);
+//! # }
+//! ```
+//!
+//! `try_via` converts with `TryFrom` instead, panicking if the conversion
+//! fails. Integer literals converted between integer types are checked when
+//! compiling, so an out of range value is a compile error.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Server {
+//!     #[default(try_via = "u32", value = "8080")]
+//!     port: u16,
+//! }
+//!
+//! assert_eq!(Server::default().port, 8080);
+//! # }
+//! ```
+//!
+//! ## Containers
+//!
+//! `Option`, `Vec` and tuple fields have each of their values parsed
+//! individually, so only the innermost types need to implement `FromStr`.
+//! Lists and tuples may be written with or without their brackets.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Server {
+//!     #[default = "[80, 443]"]
+//!     ports: Option<Vec<u16>>,
+//!     #[default = "(localhost, 8080), (example.com, 80)"]
+//!     upstreams: Vec<(String, u16)>,
+//! }
+//!
+//! let server = Server::default();
+//! assert_eq!(server.ports, Some(vec![80, 443]));
+//! assert_eq!(server.upstreams[1], ("example.com".to_string(), 80));
+//! # }
+//! ```
+//!
+//! `HashMap` and `BTreeMap` fields take `key = value` entries, such as
+//! `{a = 1, b = 2}`, and `HashSet` and `BTreeSet` fields a list, with each
+//! key and value parsed in the same way. A key given twice is a compile
+//! error. With the `indexmap` feature enabled, `IndexMap` and `IndexSet`
+//! take them too, and keep the order they're given in.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! use std::collections::{BTreeMap, HashSet};
+//!
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Router {
+//!     #[default = "{/ = index, /about = \"about page\"}"]
+//!     routes: BTreeMap<String, String>,
+//!     #[default = "[GET, HEAD]"]
+//!     methods: HashSet<String>,
+//! }
+//!
+//! let router = Router::default();
+//! assert_eq!(router.routes["/about"], "about page");
+//! assert!(router.methods.contains("HEAD"));
+//! # }
+//! ```
+//!
+//! ## Type hints
+//!
+//! When a field's type is an alias or otherwise doesn't say what the value
+//! should be created as, `ty` names the concrete type.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! type Timeout = u64;
+//!
+//! #[derive(SpecifiedDefault)]
+//! struct Client {
+//!     #[default(ty = "u64", value = "10")]
+//!     timeout: Timeout,
+//! }
+//!
+//! assert_eq!(Client::default().timeout, 10);
+//! # }
+//! ```
+//!
+//! ## Enum variants
+//!
+//! A field whose type is an enum can be set to one of its variants with
+//! `variant`, whichever variant the enum's own default is.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(Debug, PartialEq)]
+//! enum Compression {
+//!     None,
+//!     Zstd,
+//! }
+//!
+//! #[derive(SpecifiedDefault)]
+//! struct Archive {
+//!     #[default(variant = "Compression::Zstd")]
+//!     compression: Compression,
+//! }
+//!
+//! assert_eq!(Archive::default().compression, Compression::Zstd);
+//! # let _ = Compression::None;
+//! # }
+//! ```
+//!
+//! ## Build profiles
+//!
+//! `debug` and `release` give separate values for builds with and without
+//! debug assertions.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Logging {
+//!     #[default(debug = "trace", release = "warn")]
+//!     level: String,
+//! }
+//!
+//! let expected = if cfg!(debug_assertions) { "trace" } else { "warn" };
+//! assert_eq!(Logging::default().level, expected);
+//! # }
+//! ```
+//!
+//! Any other condition can wrap the attribute in `cfg_attr`, which the
+//! compiler resolves before the derive sees the field.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Buffers {
+//!     #[cfg_attr(target_pointer_width = "64", default = "4096")]
+//!     #[cfg_attr(not(target_pointer_width = "64"), default = "512")]
+//!     size: usize,
+//! }
+//! # let _ = Buffers::default();
+//! # }
+//! ```
+//!
+//! ## Borrowed strings
+//!
+//! `&'static str` and `&'static [u8]` fields borrow the attribute's literal
+//! directly, so they need no allocation or parsing.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Greeting {
+//!     #[default = "hello"]
+//!     text: &'static str,
+//!     #[default = "hi"]
+//!     bytes: &'static [u8],
+//! }
+//!
+//! assert_eq!(Greeting::default().text, "hello");
+//! assert_eq!(Greeting::default().bytes, b"hi");
+//! # }
+//! ```
+//!
+//! With the `compact_str` or `smol_str` feature enabled, `CompactString`
+//! and `SmolStr` fields are built with the const `CompactString::const_new`
+//! and `SmolStr::new_static`, which keep short defaults inline and borrow
+//! longer ones, so building them doesn't allocate. They can also be used in
+//! `default_const`.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Token {
+//!     #[default = "ident"]
+//!     kind: SmolStr,
+//!     #[default = "<anonymous>"]
+//!     name: CompactString,
+//! }
+//! ```
+//!
+//! ## Wrapper types
+//!
+//! `Box`, `Rc` and `Arc` values are built from the type they point to, and
+//! `NonZero` integers from the plain integer, so wrappers can be nested and
+//! only the innermost type needs `FromStr`. A zero `NonZero` default is a
+//! compile error. Boxed and reference-counted slices, such as `Arc<[T]>`,
+//! take a list like a `Vec` does, and `Box<str>`, `Rc<str>` and `Arc<str>`
+//! are converted from the literal without parsing.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! use std::num::NonZeroU32;
+//!
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Pool {
+//!     #[default = "8"]
+//!     workers: Option<NonZeroU32>,
+//!     #[default = "4096"]
+//!     buffer: Option<Box<u64>>,
+//!     #[default = "workers"]
+//!     name: std::sync::Arc<str>,
+//! }
+//!
+//! let pool = Pool::default();
+//! assert_eq!(pool.workers, NonZeroU32::new(8));
+//! assert_eq!(pool.buffer, Some(Box::new(4096)));
+//! assert_eq!(&*pool.name, "workers");
+//! # }
+//! ```
+//!
+//! ## Fixed-capacity collections
+//!
+//! `heapless::Vec<T, N>` and `heapless::String<N>` take lists and strings
+//! like their `std` counterparts, and are filled without allocating. A
+//! default that doesn't fit in a literal capacity is a compile error. When
+//! the capacity is a const parameter, a default that doesn't fit panics as
+//! it's built instead, so `no_panic` rejects those.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! # extern crate heapless;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Sensor {
+//!     #[default = "[4, 5]"]
+//!     pins: heapless::Vec<u8, 8>,
+//!     #[default = "sensor-01"]
+//!     name: heapless::String<16>,
+//! }
+//!
+//! assert_eq!(Sensor::default().name, "sensor-01");
+//! # }
+//! ```
+//!
+//! The `arrayvec` feature does the same for `ArrayVec<T, N>` and
+//! `ArrayString<N>`, and the `smallvec` feature for `SmallVec<[T; N]>`,
+//! whose defaults must fit in its inline storage.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Route {
+//!     #[default = "[80, 443]"]
+//!     ports: ArrayVec<u16, 4>,
+//!     #[default = "[1, 2, 3]"]
+//!     hops: SmallVec<[u32; 4]>,
+//! }
+//! ```
+//!
+//! ## Colors
+//!
+//! Hex colors in the form `#rrggbb` or `#rrggbbaa` are decoded while
+//! compiling for `[u8; 3]` and `[u8; 4]` fields, and for the `u8` color
+//! types of the `rgb` and `palette` crates such as `RGB8` and `Srgba<u8>`.
+//! Four-component colors given without an alpha are opaque.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Theme {
+//!     #[default = "#ff8800"]
+//!     accent: [u8; 3],
+//!     #[default = "#00000080"]
+//!     shadow: [u8; 4],
+//! }
+//!
+//! assert_eq!(Theme::default().accent, [0xff, 0x88, 0x00]);
+//! # }
+//! ```
+//!
+//! ## Arrays
+//!
+//! Arrays take a bracketed list with one value per element. The number of
+//! elements is checked against the array's length when the derive expands.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Kernel {
+//!     #[default = "[1, 2, 4, 8]"]
+//!     weights: [u32; 4],
+//! }
+//!
+//! assert_eq!(Kernel::default().weights, [1, 2, 4, 8]);
+//! # }
+//! ```
+//!
+//! `#[default(repeat = "0xFF; 64")]` fills an array or `Vec` with one
+//! element instead, as `[0xFF; 64]` or `vec![0xFF; 64]` would, for sentinel
+//! values and padding. Arrays can leave the count out to fill their length.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Frame {
+//!     #[default(repeat = "0xFF")]
+//!     preamble: [u8; 8],
+//!     #[default(repeat = "0; 64")]
+//!     padding: Vec<u8>,
+//! }
+//!
+//! let frame = Frame::default();
+//! assert_eq!(frame.preamble, [0xFF; 8]);
+//! assert_eq!(frame.padding, vec![0; 64]);
+//! # }
+//! ```
+//!
+//! ## Bitflags
+//!
+//! With the `bitflags` feature enabled, the `flags` key takes flag names
+//! joined by `|` and expands them to those associated constants of the
+//! field's type, so an unknown flag is a compile error rather than a runtime
+//! panic.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct File {
+//!     #[default(flags = "READ | WRITE")]
+//!     permissions: Permissions,
+//! }
+//! ```
+//!
+//! ## Decimals
+//!
+//! With the `rust_decimal` feature enabled, defaults for `Decimal` fields are
+//! parsed while compiling and built exactly with `Decimal::from_parts`, so a
+//! price such as `19.99` can't fail to parse at runtime or pick up float
+//! rounding. They can also be used in `default_const`.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Plan {
+//!     #[default = "19.99"]
+//!     price: Decimal,
+//! }
+//! ```
+//!
+//! ## Big integers
+//!
+//! With the `num-bigint` feature enabled, defaults for `BigUint` and `BigInt`
+//! fields are parsed while compiling, in decimal or with a `0x`, `0o` or `0b`
+//! prefix, and built from their bytes, so that a long modulus is checked once
+//! rather than parsed every time the default is built.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Group {
+//!     #[default = "0xffff_ffff_ffff_ffff_c90f_daa2_2168_c234"]
+//!     modulus: BigUint,
+//!     #[default = "-123456789012345678901234567890"]
+//!     offset: BigInt,
+//! }
+//! ```
+//!
+//! ## Versions
+//!
+//! With the `semver` feature enabled, defaults for fields written as
+//! `semver::Version` are checked while compiling, so a malformed version is
+//! a compile error. Versions without pre-release or build metadata are built
+//! with the const `Version::new`. A field written as a bare `Version` may
+//! be a type of the crate's own, so it is parsed with its `FromStr` as usual.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Manifest {
+//!     #[default = "1.2.3"]
+//!     protocol: semver::Version,
+//! }
+//! ```
+//!
+//! ## URLs
+//!
+//! With the `url` feature enabled, defaults for fields written as `url::Url`
+//! are checked while compiling, so an endpoint that doesn't parse is a
+//! compile error rather than a panic at startup. As with versions, a bare
+//! `Url` is parsed with its `FromStr`.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Client {
+//!     #[default = "https://api.example.com/v1"]
+//!     endpoint: url::Url,
+//! }
+//! ```
+//!
+//! ## UTF-8 paths
+//!
+//! With the `camino` feature enabled, `Utf8PathBuf` fields are converted
+//! from their string, and `&'static Utf8Path` fields borrow it with
+//! `Utf8Path::new`, so neither is parsed.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Build {
+//!     #[default = "target/debug"]
+//!     out_dir: Utf8PathBuf,
+//!     #[default = "Cargo.toml"]
+//!     manifest: &'static Utf8Path,
+//! }
+//! ```
+//!
+//! ## Vectors and transforms
+//!
+//! With the `glam` or `nalgebra` feature enabled, vectors and points such as
+//! `Vec3` and `Vector3<f32>` take a list of their components, and the
+//! `identity` marker sets a matrix, quaternion or other transform to its
+//! identity.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Transform {
+//!     #[default = "0.0, 1.0, 0.0"]
+//!     translation: Vec3,
+//!     #[default(identity)]
+//!     rotation: Quat,
+//! }
+//! ```
+//!
+//! ## Migrating from `derivative`
+//!
+//! With the `derivative` feature enabled, fields that have no `#[default]`
+//! attribute use `#[derivative(Default(value = "..."))]` instead, and an enum
+//! variant can be marked `#[derivative(Default)]`. As in `derivative`, the
+//! value is an expression. This lets `#[derive(Derivative)]` be swapped out
+//! without rewriting every field at once.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Connection {
+//!     #[derivative(Default(value = "30"))]
+//!     timeout_secs: u64,
+//!     #[default = "localhost"]
+//!     host: String,
+//! }
+//! ```
+//!
+//! ## Regexes
+//!
+//! `Regex` has no useful `Default` or `FromStr`, so with the `regex` feature
+//! enabled, `regex` gives a field's pattern instead. The pattern is checked
+//! while compiling and built the first time the default is, after which
+//! every default clones the same regex.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Validator {
+//!     #[default(regex = r"^\w+$")]
+//!     username: Regex,
+//! }
+//! ```
+//!
+//! ## Secrets
+//!
+//! With the `secrecy` feature enabled, `secret` gives the value of a
+//! `Secret<T>` or `SecretString` field, which is wrapped as it is built so
+//! that it never shows up in `Debug` output or the registry. `secret_env`
+//! reads the secret from an environment variable instead, falling back to
+//! `secret`, or to an empty value, when the variable isn't set.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Credentials {
+//!     #[default(secret_env = "API_KEY", secret = "")]
+//!     api_key: SecretString,
+//! }
+//! ```
+//!
+//! ## Generics
+//!
+//! Generic types are supported, including defaulted type parameters. Fields
+//! whose types involve a type parameter add the bounds their defaults need to
+//! the `Default` implementation.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Grid<T = f32> {
+//!     #[default = "0.5"]
+//!     cell: T,
+//! }
+//!
+//! let grid: Grid = Grid::default();
+//! assert_eq!(grid.cell, 0.5);
+//! # }
+//! ```
+//!
+//! ## Base instances
+//!
+//! A struct can start from an existing value instead of the type defaults.
+//! The base expression is cloned and any `#[default]` overrides are applied
+//! on top of it.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! #[derive(Clone, SpecifiedDefault)]
+//! #[specified_default(base = "balanced()")]
+//! struct Tuning {
+//!     threads: u32,
+//!     #[default = "64"]
+//!     batch_size: u32,
+//! }
+//!
+//! fn balanced() -> Tuning {
+//!     Tuning { threads: 4, batch_size: 16 }
+//! }
+//!
+//! # fn main() {
+//! let result = Tuning::default();
+//! assert_eq!(result.threads, 4);
+//! assert_eq!(result.batch_size, 64);
+//! # }
+//! ```
+//!
+//! ## Runtime overrides
+//!
+//! `#[specified_default(overrides)]` generates `default_with_overrides`, which
+//! starts from the specified defaults and parses string overrides by field
+//! name. Every field's type must implement `FromStr`.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! use std::collections::HashMap;
+//!
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(overrides)]
+//! struct Window {
+//!     #[default = "640"]
+//!     width: u32,
+//!     #[default = "480"]
+//!     height: u32,
+//! }
+//!
+//! let mut overrides = HashMap::new();
+//! overrides.insert("height", "720");
+//!
+//! let window = Window::default_with_overrides(&overrides).unwrap();
+//! assert_eq!(window.width, 640);
+//! assert_eq!(window.height, 720);
+//! # }
+//! ```
+//!
+//! ## Environment variables
+//!
+//! `#[specified_default(env_prefix = "APP_")]` generates `default_from_env`,
+//! which reads each field from an upper-cased variable such as `APP_WIDTH`
+//! and uses the specified default for any that aren't set. Every field's type
+//! must implement `FromStr`.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(env_prefix = "DOC_APP_")]
+//! struct Window {
+//!     #[default = "640"]
+//!     width: u32,
+//!     #[default = "480"]
+//!     height: u32,
+//! }
+//!
+//! std::env::set_var("DOC_APP_HEIGHT", "720");
+//!
+//! let window = Window::default_from_env();
+//! assert_eq!(window.width, 640);
+//! assert_eq!(window.height, 720);
+//! # }
+//! ```
+//!
+//! An enum's default variant can also be picked when `default` is called,
+//! from an environment variable holding a variant's name in any case. The
+//! `#[default]` variant is used when it's unset or names no unit variant.
+//! With `variant_defaults`, variants with fields can be named too, and are
+//! built from their field defaults.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(Debug, PartialEq, SpecifiedDefault)]
+//! #[specified_default(env = "APP_MODE")]
+//! enum Mode {
+//!     Development,
+//!     #[default]
+//!     Production,
+//! }
+//!
+//! std::env::set_var("APP_MODE", "development");
+//! assert_eq!(Mode::default(), Mode::Development);
+//! # }
+//! ```
+//!
+//! ## Runtime crate path
+//!
+//! Code generated for the features that use the `specified_default` crate
+//! refers to it by that name. When it is re-exported from another crate
+//! instead, `#[specified_default(crate = "::my_facade::specified_default")]`
+//! gives the path to use.
+//!
+//! ## Minimum supported Rust version
+//!
+//! Libraries that support old compilers can give theirs with
+//! `#[specified_default(msrv = "1.36")]`. Defaults whose generated code
+//! needs a newer compiler are then rejected while expanding, naming the
+//! version they need, rather than breaking the build on the old compiler
+//! later: `default_ref`, `prototype`, `shared` and `regex` need 1.70 for
+//! `OnceLock`, `ffi` needs 1.82, `num_cpus` needs 1.59, `try_via` needs
+//! 1.34, and a `Weak` field in `default_const` needs 1.73, `debug` and
+//! `release` there 1.46, and any value there 1.33. Code that can be written
+//! for older compilers is instead: `assert_defaults` leaves out
+//! `#[track_caller]` before 1.46, and items scoped in `const _` blocks
+//! before 1.37 are in named constants.
+//!
+//! ## Redundant defaults
+//!
+//! A `#[default]` that gives a field the value its type's `Default` already
+//! would can usually be removed. `#[specified_default(redundant_defaults =
+//! "warn")]` reports these with a warning, and `"deny"` makes them errors:
+//! zero numbers, `false`, empty strings and vectors, and `empty` standard
+//! containers. The warning is a deprecation warning, which is the only one a
+//! derive can raise on stable.
+//!
+//! ```rust,compile_fail
+//! # #[macro_use]
+//! # extern crate specified_default_derive;
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(redundant_defaults = "deny")]
+//! struct Retries {
+//!     #[default(value = "0")]
+//!     attempts: u32,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ## Registry
+//!
+//! The `inventory` feature of the `specified_default` crate registers every
+//! field default, so the defaults compiled into a binary can be listed at
+//! runtime with `specified_default::registered_defaults()`.
+//!
+//! ## Describing defaults
+//!
+//! `#[specified_default(describe)]` generates `describe_defaults`, which
+//! returns a `specified_default::DefaultsNode` tree of the fields' defaults
+//! for printing. Fields of types that also derive with `describe` can be
+//! marked `#[default(nested)]` to be described by their own tree; they are
+//! built with `Default`. Using `describe` needs a dependency on the
+//! `specified_default` crate.
+//!
+//! ## Configuration layers
+//!
+//! With the `config` feature of the `specified_default` crate,
+//! `#[specified_default(config_source)]` generates a `<Type>Defaults` unit
+//! struct implementing `config::Source`, which holds the fields that have a
+//! `#[default]` as the lowest layer of a `config-rs` configuration. Fields
+//! marked `#[default(nested)]` are tables taken from their type's own
+//! `config_source`.
+//!
+//! Similarly, with the `figment` feature, `#[specified_default(defaults_provider)]`
+//! generates `defaults_provider`, which returns a `figment` provider of the
+//! same defaults for merging other providers over, as in
+//! `Figment::from(Config::defaults_provider()).merge(Env::prefixed("APP_"))`.
+//!
+//! ## Property testing
+//!
+//! With the `proptest` feature of the `specified_default` crate,
+//! `#[specified_default(arbitrary_near_default)]` generates
+//! `arbitrary_near_default`, a strategy yielding the default with each field
+//! that has a `#[default]` replaced by an arbitrary value a fifth of the
+//! time, so property tests explore configurations close to the real one.
+//! The type must be `Debug`, and those fields' types `Arbitrary`.
+//!
+//! ## WebAssembly constructors
+//!
+//! With the `wasm-bindgen` feature, `#[specified_default(wasm_constructor)]`
+//! on a `#[wasm_bindgen]` struct generates a `new` function exported as its
+//! JavaScript constructor, so `new Viewport()` in JavaScript gives the same
+//! default as `Viewport::default()` in Rust. The struct can't be generic, and
+//! the crate needs its own dependency on `wasm-bindgen`.
+//!
+//! ```ignore
+//! #[wasm_bindgen]
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(wasm_constructor)]
+//! pub struct Viewport {
+//!     #[default = "1280"]
+//!     pub width: u32,
+//! }
+//! ```
+//!
+//! ## Python constructors
+//!
+//! With the `pyo3` feature, `#[specified_default(py_new)]` on a `#[pyclass]`
+//! generates a `py_new` function in a `#[pymethods]` block as the class's
+//! `#[new]` constructor, so calling the class from Python gives the default.
+//! Other methods of the class need pyo3's `multiple-pymethods` feature, and
+//! the crate needs its own dependency on `pyo3`.
+//!
+//! ```ignore
+//! #[pyclass]
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(py_new)]
+//! struct Retry {
+//!     #[default = "3"]
+//!     #[pyo3(get)]
+//!     attempts: u32,
+//! }
+//! ```
+//!
+//! ## C constructors
+//!
+//! With the `ffi` feature, `#[specified_default(ffi)]` on a `#[repr(C)]`
+//! type exports an `extern "C"` function named after it in snake case, so
+//! that C code gets its defaults from the same place as Rust and the two
+//! can't drift apart. A panic while building the default aborts the program
+//! rather than unwinding into C. The type can't be generic, and the
+//! function is exported with `#[unsafe(no_mangle)]`, which needs Rust 1.82.
+//!
+//! ```ignore
+//! #[repr(C)]
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(ffi)]
+//! pub struct EncoderConfig {
+//!     #[default = "44100"]
+//!     pub sample_rate: u32,
+//! }
+//!
+//! // `EncoderConfig encoder_config_default(void);` in C
+//! ```
+//!
+//! ## Bevy resources
+//!
+//! Bevy gives every `Default` type a `FromWorld` implementation, so resources
+//! and components deriving `SpecifiedDefault` already start from their
+//! specified defaults. With the `bevy` feature, fields can also be marked
+//! `from_world` with an expression that reads them from the `world`. A type
+//! with such fields implements `FromWorld` in place of `Default`, building
+//! its other fields as `default` would.
+//!
+//! ```ignore
+//! #[derive(Resource, SpecifiedDefault)]
+//! struct Layout {
+//!     #[default = "2"]
+//!     columns: u32,
+//!     #[default(from_world = "world.resource::<Screen>().width / 2")]
+//!     column_width: u32,
+//! }
+//!
+//! app.init_resource::<Layout>();
+//! ```
+//!
+//! ## Provenance
+//!
+//! `#[specified_default(provenance)]` generates `<Type>Provenance`, with a
+//! `specified_default::Provenance` for each field saying whether its value
+//! is the specified default, its type's default or from the `base`. With
+//! `env_prefix` or `overrides` as well, `default_from_env_with_provenance`
+//! and `default_with_overrides_with_provenance` return the provenance
+//! alongside the value, recording the fields the environment or overrides
+//! replaced. Using `provenance` needs a dependency on the
+//! `specified_default` crate.
+//!
+//! ## Asserting defaults
+//!
+//! `#[specified_default(assert_defaults)]` generates
+//! `assert_specified_defaults`, which panics naming the first field of an
+//! instance that differs from its default, so tests that the defaults
+//! haven't drifted fail with a readable message rather than a diff of the
+//! whole struct. The fields must implement `PartialEq` and `Debug`.
+//!
+//! ```should_panic
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(assert_defaults)]
+//! struct Limits {
+//!     #[default = "1024"]
+//!     max_connections: u32,
+//!     #[default = "30"]
+//!     timeout_secs: u64,
+//! }
+//!
+//! // Panics with: `timeout_secs` differs from its default
+//! Limits { max_connections: 1024, timeout_secs: 60 }.assert_specified_defaults();
+//! # }
+//! ```
+//!
+//! ## Shared default instance
+//!
+//! `#[specified_default(default_ref)]` generates `default_ref`, which builds
+//! the default once and returns a `&'static` reference to it from then on.
+//! The type must be `Send` and `Sync`, and can't be generic.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(default_ref)]
+//! struct Limits {
+//!     #[default = "1024"]
+//!     max_connections: u32,
+//! }
+//!
+//! assert_eq!(Limits::default_ref().max_connections, 1024);
+//! # }
+//! ```
+//!
+//! ## Shared field values
+//!
+//! Fields that are expensive to build can be marked `shared`. Their value is
+//! built once, the first time it's needed, and every default after that gets
+//! a clone of the same `Arc`. The field must be an `Arc<T>` that doesn't use
+//! the type's generic parameters, and the value is given for `T`.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! use std::sync::Arc;
+//!
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Renderer {
+//!     #[default(shared, value = "[1, 2, 4, 8, 16, 32]")]
+//!     table: Arc<Vec<u64>>,
+//! }
+//!
+//! let first = Renderer::default();
+//! let second = Renderer::default();
+//! assert!(Arc::ptr_eq(&first.table, &second.table));
+//! # }
+//! ```
+//!
+//! ## Prototype defaults
+//!
+//! `#[specified_default(prototype)]` builds the whole default the first time
+//! `default` is called and returns a clone of it from then on, which saves
+//! parsing values, allocating and reading files on every call. The type must
+//! be `Clone`, `Send` and `Sync`, and can't be generic. Anything the default
+//! reads at runtime, such as environment variables, is read only once.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(Clone, SpecifiedDefault)]
+//! #[specified_default(prototype)]
+//! struct Routes {
+//!     #[default = "/, /health, /metrics"]
+//!     paths: Vec<String>,
+//! }
+//!
+//! assert_eq!(Routes::default().paths, ["/", "/health", "/metrics"]);
+//! # }
+//! ```
+//!
+//! ## Generated tests
+//!
+//! Values are parsed when `default` is called, so a typo in one only shows
+//! up at runtime. `#[specified_default(generate_tests)]` adds a test module
+//! next to the type that calls `default` and builds every specified field
+//! value on its own, so `cargo test` points at the field that's wrong. The
+//! type must be declared at module level and can't be generic.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(generate_tests)]
+//! struct Server {
+//!     #[default = "127.0.0.1:8080"]
+//!     address: std::net::SocketAddr,
+//! }
+//! #
+//! # fn main() {}
+//! ```
+//!
+//! ## Function pointers
+//!
+//! Function pointer fields, and `Option`s of them, can be set to a function
+//! by its path with the `path` key.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! mod handlers {
+//!     pub fn noop(request: u32) -> u32 {
+//!         request
+//!     }
+//! }
+//!
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Callbacks {
+//!     #[default(path = "handlers::noop")]
+//!     on_request: fn(u32) -> u32,
+//!     #[default(path = "handlers::noop")]
+//!     on_retry: Option<fn(u32) -> u32>,
+//! }
+//!
+//! let callbacks = Callbacks::default();
+//! assert_eq!((callbacks.on_request)(7), 7);
+//! assert!(callbacks.on_retry.is_some());
+//! # }
+//! ```
+//!
+//! ## Empty containers
+//!
+//! `#[default(empty)]` sets a field to the empty value of its type with
+//! `new`, such as `Vec::new()` or `String::new()`. That's what `Default`
+//! would give anyway, but it states the intent and skips parsing.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! use std::collections::HashMap;
+//!
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Cache {
+//!     #[default(empty)]
+//!     entries: HashMap<String, Vec<u8>>,
+//! }
+//!
+//! assert!(Cache::default().entries.is_empty());
+//! # }
+//! ```
+//!
+//! `#[default(capacity = "1024")]` makes it with `with_capacity` instead, so
+//! the default is pre-sized for a known workload. The capacity can also name
+//! a constant.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! const LINE_LENGTH: usize = 120;
+//!
+//! #[derive(SpecifiedDefault)]
+//! struct Buffer {
+//!     #[default(capacity = "1024")]
+//!     bytes: Vec<u8>,
+//!     #[default(capacity = "LINE_LENGTH")]
+//!     line: String,
+//! }
+//!
+//! let buffer = Buffer::default();
+//! assert!(buffer.bytes.capacity() >= 1024);
+//! assert!(buffer.line.capacity() >= LINE_LENGTH);
+//! # }
+//! ```
+//!
+//! ## Zeroed buffers
+//!
+//! Fields marked `zeroed` are filled with zero bytes in one go rather than
+//! built element by element, which suits large plain-old-data buffers. They
+//! can be integers, floats, `bool`s, `char`s, or arrays and tuples of them,
+//! and a `Box` of any of those is allocated zeroed on the heap without the
+//! value ever passing through the stack. With the `bytemuck` feature, types
+//! implementing `bytemuck::Zeroable`, and arrays of them, are accepted too.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Framebuffer {
+//!     #[default(zeroed)]
+//!     pixels: Box<[[u8; 4]; 1920 * 1080]>,
+//! }
+//!
+//! assert!(Framebuffer::default().pixels.iter().all(|pixel| *pixel == [0; 4]));
+//! # }
+//! ```
+//!
+//! ## Customized fields
+//!
+//! `#[derive(DebugWithDefaults)]` implements `Debug` in place of the standard
+//! derive, leaving out the fields that still hold their default so that logs
+//! of large configuration types show only what was changed. A `..` marks
+//! that some were left out. Every field's type must implement `PartialEq` as
+//! well as `Debug`, and the type's default is built each time it's printed.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(DebugWithDefaults, SpecifiedDefault)]
+//! struct Server {
+//!     #[default = "localhost"]
+//!     host: String,
+//!     #[default = "8080"]
+//!     port: u16,
+//!     workers: u32,
+//! }
+//!
+//! let server = Server { port: 9090, ..Server::default() };
+//! assert_eq!(format!("{:?}", server), "Server { port: 9090, .. }");
+//! # }
+//! ```
+//!
+//! ## Parsing from strings
+//!
+//! `#[derive(FromStrWithDefaults)]` implements `FromStr` for strings of
+//! `key=value` pairs separated by commas, such as `"port=9090,workers=4"`.
+//! Each value is parsed with its field type's own `FromStr`, and keys that
+//! are left out keep the type's default, which suits compact encodings of
+//! small configuration types in command-line flags and environment
+//! variables. Values can't contain commas, and errors are described in a
+//! `String`.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(FromStrWithDefaults, SpecifiedDefault)]
+//! struct Server {
+//!     #[default = "localhost"]
+//!     host: String,
+//!     #[default = "8080"]
+//!     port: u16,
+//! }
+//!
+//! let server: Server = "port=9090".parse().unwrap();
+//! assert_eq!(server.host, "localhost");
+//! assert_eq!(server.port, 9090);
+//! # }
+//! ```
+//!
+//! ## Debugging
+//!
+//! `#[specified_default(debug_expand)]` prints the code generated for a type to
+//! stderr while it compiles. Setting `SPECIFIED_DEFAULT_DEBUG=1` does the same
+//! for every type, which helps without `cargo expand` at hand.
+//!
+//! With the `nightly` feature enabled on a nightly compiler, defaults that
+//! build but are likely mistakes get warnings: casts of fractional numbers to
+//! integers such as `#[default(1.5 as u32)]`, empty strings for types that
+//! aren't strings, and `debug` and `release` values that are the same.
+//!
+//! ## Const defaults
+//!
+//! `#[specified_default(default_const)]` also generates `default_const`, a
+//! `const fn` that can initialise statics and constants. Every field needs a
+//! default that can be built without runtime parsing: literals of primitive
+//! types, borrowed strings, and arrays, tuples, `Option`s and `NonZero`s of
+//! them, as well as variants, paths and expressions that are themselves
+//! const. With `variant_defaults`, so do the fields of an enum's default
+//! variant.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(default_const)]
+//! struct Limits {
+//!     #[default = "1024"]
+//!     max_connections: u32,
+//!     #[default = "localhost"]
+//!     host: &'static str,
+//! }
+//!
+//! static LIMITS: Limits = Limits::default_const();
+//!
+//! # fn main() {
+//! assert_eq!(LIMITS.max_connections, 1024);
+//! # }
+//! ```
+//!
+//! With the `const-default` feature of `specified_default`, types with
+//! `default_const` also implement the `const-default` crate's
+//! `ConstDefault`, whose `DEFAULT` is the same value.
+//!
+//! ## Raw pointers
+//!
+//! Raw pointers don't implement `Default`, so fields of them without a
+//! `#[default]` start out null, which lets FFI structs derive their defaults.
+//! `#[default(null)]` spells the same thing out.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! use std::os::raw::c_char;
+//!
+//! # fn main() {
+//! #[repr(C)]
+//! #[derive(SpecifiedDefault)]
+//! struct Options {
+//!     name: *const c_char,
+//!     #[default = "3"]
+//!     retries: u32,
+//! }
+//!
+//! assert!(Options::default().name.is_null());
+//! # }
+//! ```
+//!
+//! `rc::Weak` and `sync::Weak` fields likewise start out pointing to nothing
+//! with `Weak::new`, which also works in `default_const`, so parent links in
+//! trees and graphs need no attribute.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! use std::rc::Weak;
+//!
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Node {
+//!     parent: Weak<Node>,
+//!     #[default = "1"]
+//!     depth: u32,
+//! }
+//!
+//! assert!(Node::default().parent.upgrade().is_none());
+//! # }
+//! ```
+//!
+//! ## Infallible defaults
+//!
+//! `#[specified_default(no_panic)]` makes it a compile error for `default`
+//! to parse anything at runtime. Values must then be literals of primitive
+//! types, or collections and wrappers of them, which are written into the
+//! generated code directly. Fields without a `#[default]` still use their
+//! own `Default` implementation.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(no_panic)]
+//! struct Retry {
+//!     #[default = "3"]
+//!     attempts: u32,
+//!     #[default = "[100, 200, 400]"]
+//!     backoff_ms: Vec<u64>,
+//! }
+//!
+//! assert_eq!(Retry::default().attempts, 3);
+//! # }
+//! ```
+//!
+//! ## Documented defaults
+//!
+//! With `#[specified_default(from_docs)]`, a field's doc comment can give its
+//! default on a line starting with `Default:`, so the documentation and the
+//! behaviour can't drift apart. A field that also has a `#[default]` value
+//! must agree with its docs.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(from_docs)]
+//! struct Window {
+//!     /// The width of the window in pixels.
+//!     ///
+//!     /// Default: `640`
+//!     width: u32,
+//! }
+//!
+//! assert_eq!(Window::default().width, 640);
+//! # }
+//! ```
+//!
+//! ## Encoded bytes
+//!
+//! Byte fields can be given in base64 with `base64`, which is decoded while
+//! compiling into a byte string literal. It suits embedded keys, magic
+//! numbers and test vectors, and works for `Vec<u8>`, `[u8; N]`, `&[u8]` and
+//! boxed or reference-counted slices.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Header {
+//!     #[default(base64 = "iVBORw==")]
+//!     magic: [u8; 4],
+//! }
+//!
+//! assert_eq!(Header::default().magic, [0x89, b'P', b'N', b'G']);
+//! # }
+//! ```
+//!
+//! With the `bytes` feature enabled, `Bytes` and `BytesMut` fields take
+//! strings, `base64` and `include_bytes` as well. `Bytes` borrows the bytes
+//! with `Bytes::from_static` rather than copying them, and can be used in
+//! `default_const`.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Handshake {
+//!     #[default = "HELLO"]
+//!     greeting: Bytes,
+//!     #[default(base64 = "AAEC")]
+//!     nonce: BytesMut,
+//! }
+//! ```
+//!
+//! ## Included files
+//!
+//! `include_bytes` bakes a file into the binary as a byte field's default,
+//! through `include_bytes!`. As with that macro, the path is relative to the
+//! source file the attribute is in.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Theme {
+//!     #[default(include_bytes = "assets/default_icon.png")]
+//!     icon: Vec<u8>,
+//! }
+//! ```
+//!
+//! `include_str` does the same for text with `include_str!`, for `&str`,
+//! `String`, `Cow<str>` and anything else that converts from a `&str`.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Mailer {
+//!     #[default(include_str = "templates/welcome.html")]
+//!     welcome: String,
+//! }
+//! ```
+//!
+//! ## Serialized values
+//!
+//! With the `serde_yaml` feature of `specified_default` enabled, a field's
+//! default can be written as YAML, which suits nested configuration better
+//! than a single string to parse. The snippet is checked while compiling and
+//! deserialized into the field's type when the default is built, so the type
+//! must implement `Deserialize`. A snippet that is just a number or boolean
+//! is written into the generated code like any other value.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Client {
+//!     #[default(yaml = "attempts: 3\nbackoff_ms: [100, 200, 400]")]
+//!     retry: Retry,
+//! }
+//! ```
+//!
+//! The `serde_json` feature does the same for JSON. Raw strings save
+//! escaping its quotes.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Client {
+//!     #[default(json = r#"{"attempts": 3, "backoff_ms": [100, 200, 400]}"#)]
+//!     retry: Retry,
+//! }
+//! ```
+//!
+//! The `toml` feature accepts TOML tables, so fragments of a configuration
+//! file can be pasted in as they are.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Client {
+//!     #[default(toml = "attempts = 3\nbackoff_ms = [100, 200, 400]")]
+//!     retry: Retry,
+//! }
+//! ```
+//!
+//! The `ron` feature takes RON, as game data is often written in.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Spawn {
+//!     #[default(ron = "(x: 1.0, y: 2.0)")]
+//!     position: Vec2,
+//! }
+//! ```
+//!
+//! The same features serialize whole defaults the other way:
+//! `#[specified_default(serialize(json, toml))]` generates `default_json` and
+//! `default_toml`, which return the `Serialize`d default, for generating an
+//! example configuration file from the code.
+//!
+//! ```ignore
+//! #[derive(Serialize, SpecifiedDefault)]
+//! #[specified_default(serialize(toml))]
+//! struct Client {
+//!     #[default = "3"]
+//!     attempts: u32,
+//! }
+//!
+//! assert_eq!(Client::default_toml(), "attempts = 3\n");
+//! ```
+//!
+//! ## Machine-aware defaults
+//!
+//! With the `system-defaults` feature of `specified_default` enabled,
+//! `#[default(num_cpus)]`, `#[default(hostname)]` and `#[default(temp_dir)]`
+//! read the number of CPUs, the hostname and the temporary directory when the
+//! default is built, for worker counts, node names and scratch paths. The
+//! CPU count is converted to the field's type with `TryFrom<usize>`, the
+//! hostname with `From<String>` and the directory with `From<PathBuf>`. When
+//! they can't be read, the CPU count is 1 and the hostname `localhost`.
+//!
+//! ```ignore
+//! #[derive(SpecifiedDefault)]
+//! struct Worker {
+//!     #[default(num_cpus)]
+//!     threads: usize,
+//!     #[default(hostname)]
+//!     node: String,
+//!     #[default(temp_dir)]
+//!     scratch: PathBuf,
+//! }
+//! ```
+//!
+//! ## Tracing
+//!
+//! With the `trace-defaults` feature of `specified_default` enabled, `default`
+//! logs a `log::trace!` record naming the type it builds, any fields it
+//! includes from files, and the environment variable an enum's variant is
+//! read from. `default_from_env` likewise logs each field it takes from the
+//! environment, so the layer that produced a value can be traced in
+//! production.
+//!
+//! ## Fingerprints
+//!
+//! `#[specified_default(fingerprint)]` generates `DEFAULTS_FINGERPRINT`, a
+//! `u64` hash of each field's name and specified default, or of the default
+//! variant's name for enums. The hash is stable across builds and compiler
+//! versions, so a snapshot of it catches a release that changes a default.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(fingerprint)]
+//! struct Window {
+//!     #[default = "640"]
+//!     width: u32,
+//! }
+//!
+//! assert_ne!(Window::DEFAULTS_FINGERPRINT, 0);
+//! # }
+//! ```
+//!
+//! ## Field groups
+//!
+//! Fields can be put in a group with the `group` key, alongside their
+//! default. Each group gets an `apply_<group>_defaults` method, which resets
+//! just that group's fields, so coherent parts of a large configuration can
+//! be initialised or reset on their own.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Config {
+//!     #[default(group = "network", value = "8080")]
+//!     port: u16,
+//!     #[default(group = "logging", value = "info")]
+//!     level: String,
+//! }
+//!
+//! let mut config = Config { port: 1, level: "trace".to_owned() };
+//! config.apply_network_defaults();
+//! assert_eq!((config.port, config.level.as_str()), (8080, "trace"));
+//! # }
+//! ```
+//!
+//! ## Versioned defaults
+//!
+//! A default that changed in some release can keep its old value with
+//! `since` and `previously`. `default_for_version` then builds the default
+//! as any earlier release did, so that stored configurations written
+//! against it can be migrated without guessing what they left out.
+//! Versions are compared number by number, with missing numbers as zero.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! struct Pool {
+//!     #[default(value = "10", since = "2.0", previously = "5")]
+//!     max_connections: u32,
+//!     #[default = "30"]
+//!     timeout_secs: u64,
+//! }
+//!
+//! assert_eq!(Pool::default_for_version("1.4").max_connections, 5);
+//! assert_eq!(Pool::default_for_version("2.0").max_connections, 10);
+//! assert_eq!(Pool::default_for_version("1.4").timeout_secs, 30);
+//! # }
+//! ```
+//!
+//! ## Defaults manifests
+//!
+//! With the `toml` feature of `specified_default`, a module holding many
+//! configuration structs can keep their defaults in one TOML file instead
+//! of on each field. `#[specified_defaults(file = "defaults.toml")]` on an
+//! inline module gives each field the manifest has a value for under a
+//! `[Struct]` table, or as a `Struct.field` key, a `#[default]` of that
+//! value. The path is relative to the crate's `Cargo.toml`.
+//!
+//! ```ignore
+//! #[specified_defaults(file = "config/defaults.toml")]
+//! mod config {
+//!     #[derive(SpecifiedDefault)]
+//!     pub struct Server {
+//!         pub host: String,
+//!         pub port: u16,
+//!     }
+//! }
+//! ```
+//!
+//! ```toml
+//! [Server]
+//! host = "localhost"
+//! port = 8080
+//! ```
+//!
+//! Every struct and field the manifest names has to exist, and a field
+//! can't have a default both there and in a `#[default]`, so that typos
+//! and leftovers are reported rather than ignored.
+//!
+//! ## Field lookup
+//!
+//! `#[specified_default(default_field)]` generates `default_field`, which
+//! builds a single field's default by name as a `Box<dyn Any>`, or returns
+//! `None` for an unknown name. Plugin systems and scripting bridges can use
+//! it to fetch defaults without building the whole type.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(default_field)]
+//! struct Window {
+//!     #[default = "640"]
+//!     width: u32,
+//! }
+//!
+//! let width = Window::default_field("width").unwrap();
+//! assert_eq!(width.downcast_ref::<u32>(), Some(&640));
+//! # }
+//! ```
+//!
+//! ## Context defaults
+//!
+//! `#[specified_default(context = "...")]` generates `default_with`, which
+//! takes a reference to the given context type. Fields marked `from_ctx`
+//! are cloned from the named field of the context, which can be a dotted
+//! path, and the rest keep their specified defaults. In `default`, a
+//! `from_ctx` field uses its other keys, or its type's `Default` when it has
+//! none.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! struct Request {
+//!     tenant_id: u64,
+//! }
+//!
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(context = "Request")]
+//! struct Settings {
+//!     #[default(from_ctx = "tenant_id")]
+//!     tenant_id: u64,
+//!     #[default = "25"]
+//!     page_size: u32,
+//! }
+//!
+//! let settings = Settings::default_with(&Request { tenant_id: 7 });
+//! assert_eq!((settings.tenant_id, settings.page_size), (7, 25));
+//! # }
+//! ```
+//!
+//! ## Remote types
+//!
+//! Types from other crates can't implement `Default` here, but a local mirror
+//! of one can provide its constructor instead. The mirror must declare the
+//! same fields as the foreign type.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! mod other_crate {
+//!     pub struct Window {
+//!         pub width: u32,
+//!         pub height: u32,
+//!     }
+//! }
+//!
+//! # fn main() {
+//! #[derive(SpecifiedDefault)]
+//! #[specified_default(remote = "other_crate::Window")]
+//! #[allow(dead_code)]
+//! struct WindowDef {
+//!     #[default = "640"]
+//!     width: u32,
+//!     #[default = "480"]
+//!     height: u32,
+//! }
+//!
+//! let window: other_crate::Window = WindowDef::default();
+//! assert_eq!(window.width, 640);
+//! # }
+//! ```
+//!
+//! ## Declaring structs
+//!
+//! `specified_struct!` declares structs with each default written after its
+//! field's type, like an initializer. Fields with an initializer get it as
+//! a `#[default(...)]` expression, and the others their type's `Default`.
+//! Attributes on the struct and its fields are kept, so keys such as
+//! `#[specified_default(default_const)]` still apply, and `SpecifiedDefault`
+//! has to be in scope as it would for the derive.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! specified_struct! {
+//!     #[derive(Debug)]
+//!     pub struct Window {
+//!         width: u32 = 640,
+//!         height: u32 = 480,
+//!         title: String = "untitled".to_owned(),
+//!         scenes: u32,
+//!     }
+//! }
+//!
+//! # fn main() {
+//! let window = Window::default();
+//! assert_eq!((window.width, window.height, window.scenes), (640, 480, 0));
+//! assert_eq!(window.title, "untitled");
+//! # }
+//! ```
+//!
+//! ## Attribute form
+//!
+//! `#[specified_default]` can also be written as an attribute on the item
+//! in place of the derive, taking the container's options as its arguments.
+//! It implements `Default` the same way and then removes the `#[default]`
+//! and `#[specified_default]` attributes from the item, so that derives
+//! running after it, and compilers without derive helper attributes, never
+//! see them.
+//!
+//! ```
+//! # #[macro_use] extern crate specified_default_derive;
+//! #
+//! # fn main() {
+//! #[specified_default(default_const)]
+//! #[derive(Debug, PartialEq)]
+//! struct Window {
+//!     #[default = "640"]
+//!     width: u32,
+//! }
+//!
+//! const WINDOW: Window = Window::default_const();
+//! assert_eq!(WINDOW, Window::default());
+//! # }
+//! ```
+//!
+//! With `serde` among the arguments, each field with a default is also given
+//! a `#[serde(default = "...")]` returning that value, so that a field left
+//! out when deserializing gets the same default. Fields that already name a
+//! serde default keep it.
+//!
+//! ```ignore
+//! #[specified_default(serde)]
+//! #[derive(Deserialize)]
+//! struct Server {
+//!     #[default = "8080"]
+//!     port: u16,
+//! }
+//!
+//! let server: Server = serde_json::from_str("{}")?;
+//! assert_eq!(server.port, 8080);
+//! ```
+//!
+//! Attributes behind `cfg_attr` are only expanded after the attribute has
+//! run, so conditional defaults still need the derive.
+//!
+#![cfg_attr(feature = "nightly", feature(proc_macro_diagnostic))]
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use] extern crate quote;
+#[macro_use] extern crate syn;
+#[cfg(feature = "regex")]
+extern crate regex_syntax;
+#[cfg(feature = "ron")]
+extern crate ron;
+#[cfg(feature = "serde_json")]
+extern crate serde_json;
+#[cfg(feature = "semver")]
+extern crate semver;
+#[cfg(feature = "serde_yaml")]
+extern crate serde_yaml;
+#[cfg(feature = "toml")]
+extern crate toml;
+#[cfg(feature = "url")]
+extern crate url;
+
+mod attr;
+#[cfg(feature = "num-bigint")]
+mod bigint;
+#[cfg(feature = "bitflags")]
+mod bitflags;
+mod bytes;
+mod color;
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+mod declare;
+#[cfg(feature = "nightly")]
+mod diagnostics;
+#[cfg(feature = "derivative")]
+mod derivative;
+mod formats;
+#[cfg(feature = "inventory")]
+mod inventory;
+#[cfg(feature = "toml")]
+mod manifest;
+mod msrv;
+#[cfg(feature = "camino")]
+mod paths;
+mod redundant;
+#[cfg(feature = "regex")]
+mod regex;
+mod rewrite;
+#[cfg(feature = "secrecy")]
+mod secrecy;
+mod system;
+#[cfg(any(feature = "compact_str", feature = "smol_str"))]
+mod strings;
+#[cfg(feature = "trace-defaults")]
+mod trace;
+#[cfg(feature = "url")]
+mod urls;
+mod value;
+#[cfg(any(feature = "glam", feature = "nalgebra"))]
+mod vectors;
+#[cfg(feature = "semver")]
+mod version;
+mod zeroed;
+
+use std::cell::RefCell;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{Data, DeriveInput, Fields};
+use syn::spanned::Spanned;
+
+use attr::{ATTRIBUTE_NAME, ContainerOptions, FieldOptions};
+use system::System;
+use value::{Build, const_value_for, generic_arg, parsed_types, unparsed_value_for, value_for};
+
+/// Setting this environment variable to `1` prints every expansion.
+const DEBUG_VAR: &str = "SPECIFIED_DEFAULT_DEBUG";
+
+thread_local! {
+    /// The `crate` key of the type being derived, which field defaults are
+    /// built deep within reach of without the container's options.
+    static CRATE_PATH: RefCell<Option<syn::Path>> = const { RefCell::new(None) };
+}
+
+#[doc(hidden)]
+#[cfg_attr(not(feature = "derivative"), proc_macro_derive(SpecifiedDefault, attributes(default, specified_default)))]
+#[cfg_attr(feature = "derivative", proc_macro_derive(SpecifiedDefault, attributes(default, specified_default, derivative)))]
+pub fn specify_defaults(input: TokenStream) -> TokenStream {
+    let mut ast = parse_macro_input!(input as DeriveInput);
+    ungroup_types(&mut ast);
+
+    impl_specified_defaults(&ast)
+        .unwrap_or_else(compile_errors)
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro_attribute]
+pub fn specified_default(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut ast = parse_macro_input!(input as DeriveInput);
+    ungroup_types(&mut ast);
+
+    rewrite::rewrite(args.into(), ast)
+        .unwrap_or_else(compile_errors)
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn specified_struct(input: TokenStream) -> TokenStream {
+    let declarations = parse_macro_input!(input as declare::Declarations);
+
+    declare::expand(declarations)
+        .unwrap_or_else(compile_errors)
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro_attribute]
+pub fn specified_defaults(args: TokenStream, input: TokenStream) -> TokenStream {
+    let module = parse_macro_input!(input as syn::ItemMod);
+
+    manifest_defaults(args.into(), module)
+        .unwrap_or_else(compile_errors)
+        .into()
+}
+
+#[cfg(feature = "toml")]
+fn manifest_defaults(args: TokenStream2, module: syn::ItemMod) -> syn::Result<TokenStream2> {
+    manifest::apply(args, module)
+}
+
+#[cfg(not(feature = "toml"))]
+fn manifest_defaults(_: TokenStream2, module: syn::ItemMod) -> syn::Result<TokenStream2> {
+    Err(syn::Error::new_spanned(&module.ident, "`#[specified_defaults]` needs the `toml` feature of `specified_default`"))
+}
+
+#[doc(hidden)]
+#[proc_macro_derive(DebugWithDefaults)]
+pub fn debug_with_defaults(input: TokenStream) -> TokenStream {
+    let mut ast = parse_macro_input!(input as DeriveInput);
+    ungroup_types(&mut ast);
+
+    impl_debug_with_defaults(&ast)
+        .unwrap_or_else(compile_errors)
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro_derive(FromStrWithDefaults)]
+pub fn from_str_with_defaults(input: TokenStream) -> TokenStream {
+    let mut ast = parse_macro_input!(input as DeriveInput);
+    ungroup_types(&mut ast);
+
+    impl_from_str_with_defaults(&ast)
+        .unwrap_or_else(compile_errors)
+        .into()
+}
+
+/// Takes the types of `ast` out of the invisible groups `macro_rules!` wraps
+/// `$ty:ty` fragments in, so that a `Vec<T>` passed to a macro is built like
+/// one written out. Trait objects and `impl Trait` are put in parentheses
+/// instead, which keep `&$ty` meaning what it did.
+fn ungroup_types(ast: &mut DeriveInput) {
+    use syn::visit_mut::{self, VisitMut};
+
+    struct Ungroup;
+
+    impl VisitMut for Ungroup {
+        fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+            while let syn::Type::Group(ref mut group) = *ty {
+                let elem = std::mem::replace(&mut *group.elem, syn::Type::Verbatim(TokenStream2::new()));
+                *ty = match elem {
+                    syn::Type::TraitObject(_) | syn::Type::ImplTrait(_) => syn::Type::Paren(syn::TypeParen {
+                        paren_token: syn::token::Paren(group.group_token.span),
+                        elem: Box::new(elem),
+                    }),
+                    elem => elem,
+                };
+            }
+            visit_mut::visit_type_mut(self, ty);
+        }
+    }
+
+    Ungroup.visit_derive_input_mut(ast);
+}
+
+/// Attributes for every generated impl, so that lints which downstream
+/// crates deny don't fire on code they didn't write.
+fn derived_attrs() -> TokenStream2 {
+    quote! {
+        #[automatically_derived]
+        #[allow(unused_qualifications, clippy::default_trait_access)]
+    }
+}
+
+/// `Box<dyn Trait + extra>` for the trait object type `object`, such as
+/// `Any`. The parentheses keep `dyn` from being read as a path in 2015
+/// edition crates, so the items using it allow `unused_parens`.
+fn boxed_dyn(object: TokenStream2, extra: TokenStream2) -> TokenStream2 {
+    quote! { ::std::boxed::Box<dyn (#object) #extra> }
+}
+
+/// Brings the runtime crate into scope as `specified_default`, for the
+/// blocks of generated code that use it.
+fn runtime_crate() -> TokenStream2 {
+    CRATE_PATH.with(|path| match *path.borrow() {
+        Some(ref path) => quote! { use #path as specified_default; },
+        None => quote! { extern crate specified_default; },
+    })
+}
+
+/// The path of the runtime crate, for types named outside of a block.
+fn runtime_path() -> TokenStream2 {
+    CRATE_PATH.with(|path| match *path.borrow() {
+        Some(ref path) => quote! { #path },
+        None => quote! { ::specified_default },
+    })
+}
+
+/// Reports each error with `compile_error!`. syn's own conversion refers to
+/// `::core`, which doesn't resolve in 2015 edition crates.
+fn compile_errors(err: syn::Error) -> TokenStream2 {
+    err.into_iter()
+        .map(|err| {
+            let message = err.to_string();
+            quote_spanned! { err.span()=> compile_error!(#message); }
+        })
+        .collect()
+}
+
+fn impl_specified_defaults(ast: &DeriveInput) -> syn::Result<TokenStream2> {
+    let options = ContainerOptions::from_attrs(&ast.attrs)?;
+    CRATE_PATH.with(|path| *path.borrow_mut() = options.krate.clone());
+    msrv::set(options.msrv);
+    attr::check_placement(ast, &options)?;
+    #[cfg(feature = "nightly")]
+    diagnostics::warn_suspicious(ast);
+    let documented;
+    let ast = if options.from_docs {
+        documented = attr::doc_defaults(ast)?;
+        &documented
+    } else {
+        ast
+    };
+    let name = &ast.ident;
+    let target = match options.remote {
+        Some(ref remote) => quote! { #remote },
+        None => quote! { #name },
+    };
+
+    let build: Build = if options.no_panic { unparsed_value_for } else { value_for };
+    let mut bounds = Vec::new();
+    let body = match ast.data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(ref fields), .. }) => {
+            // Fields without overrides come from the base when there is one
+            let fields = fields.named.iter().filter(|field| options.base.is_none() || has_default_attr(field));
+            let initializers = all_fields(fields.map(|field| {
+                let ident = &field.ident;
+                let value = match world_value(field)? {
+                    Some(value) => value,
+                    None => field_default(field, &ast.generics, build)?,
+                };
+                if options.no_panic {
+                    check_conversion(field)?;
+                }
+
+                bounds.extend(field_bounds(field, &ast.generics)?);
+
+                Ok(quote! { #ident: #value })
+            }))?;
+
+            match options.base {
+                Some(ref base) => quote! {
+                    #target {
+                        #(#initializers,)*
+                        ..::std::clone::Clone::clone(&(#base))
+                    }
+                },
+                None => quote! {
+                    #target {
+                        #(#initializers),*
+                    }
+                },
+            }
+        },
+        // Variants gated out with `#[cfg]` are gone by now as well
+        Data::Enum(ref data) if data.variants.is_empty() => {
+            return Err(syn::Error::new_spanned(name, "an enum without variants is uninhabited, so it has no value to be its default"));
+        },
+        Data::Enum(_) if options.base.is_some() => {
+            return Err(syn::Error::new_spanned(&options.base, "a base is only supported for structs"));
+        },
+        Data::Enum(ref data) if !attr::variant_weights(data)?.is_empty() => {
+            weighted_variant(ast, data, &options, &target, build, &mut bounds)?
+        },
+        Data::Enum(ref data) => {
+            let variant = default_variant(ast, data, &options)?;
+            let mut build_variant = |variant: &syn::Variant| -> syn::Result<TokenStream2> {
+                if options.variant_defaults {
+                    for field in &variant.fields {
+                        bounds.extend(field_bounds(field, &ast.generics)?);
+                    }
+                    variant_default(variant, &target, &ast.generics, build)
+                } else {
+                    let variant = &variant.ident;
+                    Ok(quote! { #target::#variant })
+                }
+            };
+            let default = build_variant(variant)?;
+            match options.env {
+                // Without `variant_defaults`, only unit variants can be built
+                Some(ref env) => env_variant(data, env, default, |variant| match variant.fields {
+                    Fields::Unit => Some(build_variant(variant)),
+                    _ if options.variant_defaults => Some(build_variant(variant)),
+                    _ => None,
+                })?,
+                None => default,
+            }
+        },
+        _ => return Err(syn::Error::new_spanned(name, "#[derive(SpecifiedDefault)] does not support other struct variants")),
+    };
+
+    if let (Some(discriminant), Data::Struct(_)) = (options.discriminant.as_ref(), &ast.data) {
+        return Err(syn::Error::new_spanned(discriminant, "a discriminant is only supported for enums"));
+    }
+    if let (Some(env), Data::Struct(_)) = (options.env.as_ref(), &ast.data) {
+        return Err(syn::Error::new_spanned(env, "`env` is only supported for enums; structs read fields with `env_prefix`"));
+    }
+
+    #[cfg(feature = "trace-defaults")]
+    let body = {
+        let trace = trace::default_built(ast, &options)?;
+        quote! { #trace #body }
+    };
+
+    let body = if options.prototype {
+        prototype(ast, &target, body)?
+    } else {
+        body
+    };
+
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+    let where_clause = where_clause(&ast.generics, bounds);
+
+    let attrs = derived_attrs();
+    let from_world = takes_from_world(ast);
+    let mut items = vec![match options.remote {
+        Some(_) if from_world => {
+            return Err(syn::Error::new_spanned(name, "`from_world` fields are only supported for local types"));
+        },
+        // A foreign type can't implement `Default` here, so the mirror type
+        // provides the constructor instead, much like serde's remote derive.
+        Some(_) => quote! {
+            #attrs
+            impl #impl_generics #name #ty_generics #where_clause {
+                pub fn default() -> #target {
+                    #body
+                }
+            }
+        },
+        // Bevy implements `FromWorld` for every `Default` type, so a type
+        // with fields from the world implements it in place of `Default`.
+        None if from_world => quote! {
+            #attrs
+            impl #impl_generics ::bevy::ecs::world::FromWorld for #name #ty_generics #where_clause {
+                fn from_world(world: &mut ::bevy::ecs::world::World) -> #name #ty_generics {
+                    #body
+                }
+            }
+        },
+        None => quote! {
+            #attrs
+            impl #impl_generics ::std::default::Default for #name #ty_generics #where_clause {
+                fn default() -> #name #ty_generics {
+                    #body
+                }
+            }
+        },
+    }];
+
+    #[cfg(feature = "inventory")]
+    {
+        if let Data::Struct(syn::DataStruct { fields: Fields::Named(ref fields), .. }) = ast.data {
+            let type_name = match options.remote {
+                Some(ref remote) => path_name(remote),
+                None => name.to_string(),
+            };
+            items.push(inventory::register(&type_name, &fields.named)?);
+        }
+    }
+
+    if let Some(level) = options.redundant_defaults {
+        let fields: Vec<&syn::Field> = match ast.data {
+            Data::Struct(ref data) => data.fields.iter().collect(),
+            Data::Enum(ref data) => data.variants.iter().flat_map(|variant| &variant.fields).collect(),
+            Data::Union(_) => Vec::new(),
+        };
+        items.push(redundant::check(&ast.ident, fields, level)?);
+    }
+
+    if options.overrides {
+        items.push(impl_overrides(ast, &options)?);
+    }
+
+    if let Some(ref prefix) = options.env_prefix {
+        items.push(impl_env(ast, &options, prefix)?);
+    }
+
+    if options.default_ref {
+        items.push(impl_default_ref(ast, &options)?);
+    }
+
+    if options.default_const {
+        items.push(impl_default_const(ast, &options, &target)?);
+    }
+
+    if options.generate_tests {
+        items.push(impl_tests(ast)?);
+    }
+
+    if options.fingerprint {
+        items.push(impl_fingerprint(ast, &options)?);
+    }
+
+    if options.describe {
+        items.push(impl_describe(ast, &options)?);
+    }
+
+    if options.default_field {
+        items.push(impl_default_field(ast, &options, build)?);
+    }
+
+    if options.variant_defaults {
+        items.push(impl_variant_defaults(ast, build)?);
+    }
+
+    if options.assert_defaults {
+        items.push(impl_assert_defaults(ast, &options)?);
+    }
+
+    if options.config_source {
+        items.push(impl_config_source(ast, &options, build)?);
+    }
+
+    if options.defaults_provider {
+        items.push(impl_defaults_provider(ast, &options, build)?);
+    }
+
+    if options.arbitrary_near_default {
+        items.push(impl_arbitrary_near_default(ast, &options)?);
+    }
+
+    if options.wasm_constructor {
+        items.push(impl_wasm_constructor(ast, &options)?);
+    }
+
+    if options.py_new {
+        items.push(impl_py_new(ast, &options)?);
+    }
+
+    if options.ffi {
+        items.push(impl_ffi(ast, &options)?);
+    }
+
+    if !options.serialize.is_empty() {
+        items.push(impl_serialize_defaults(ast, &options)?);
+    }
+
+    if options.provenance {
+        items.push(impl_provenance(ast, &options)?);
+    }
+
+    if let Some(groups) = impl_groups(ast, &options, build)? {
+        items.push(groups);
+    }
+
+    if let Some(versions) = impl_versions(ast, &options, build)? {
+        items.push(versions);
+    }
+
+    match options.context {
+        Some(ref context) => items.push(impl_default_with(ast, &options, context)?),
+        None => check_no_context(ast)?,
+    }
+
+    let expanded = quote! { #(#items)* };
+    if options.debug_expand || ::std::env::var_os(DEBUG_VAR).is_some_and(|value| value == "1") {
+        eprintln!("#[derive(SpecifiedDefault)] on `{}` expanded to:\n{}", name, expanded);
+    }
+
+    Ok(expanded)
+}
+
+/// Implements `Debug` to show only the fields that differ from the type's
+/// `Default`, with `..` standing in for the rest.
+fn impl_debug_with_defaults(ast: &DeriveInput) -> syn::Result<TokenStream2> {
+    use syn::ext::IdentExt;
+
+    let fields = match ast.data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(ref fields), .. }) => &fields.named,
+        _ => return Err(syn::Error::new_spanned(&ast.ident, "#[derive(DebugWithDefaults)] only supports structs with named fields")),
+    };
+
+    let name = &ast.ident;
+    let type_name = name.unraw().to_string();
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+
+    let mut bounds = vec![quote! { #name #ty_generics: ::std::default::Default }];
+    let entries = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let ty = &field.ty;
+        if mentions_ty_params(ty, &ast.generics) {
+            bounds.push(quote! { #ty: ::std::fmt::Debug + ::std::cmp::PartialEq });
+        }
+
+        let field_name = ident.as_ref().map(|ident| ident.unraw().to_string()).unwrap_or_default();
+        quote! {
+            if self.#ident != defaults.#ident {
+                debug.field(#field_name, &self.#ident);
+            } else {
+                omitted = true;
+            }
+        }
+    }).collect::<Vec<_>>();
+
+    let where_clause = where_clause(&ast.generics, bounds);
+    let attrs = derived_attrs();
+    Ok(quote! {
+        #attrs
+        impl #impl_generics ::std::fmt::Debug for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                let defaults = <#name #ty_generics as ::std::default::Default>::default();
+                let mut debug = f.debug_struct(#type_name);
+                let mut omitted = false;
+                #(#entries)*
+                if omitted {
+                    debug.finish_non_exhaustive()
+                } else {
+                    debug.finish()
+                }
+            }
+        }
+    })
+}
+
+/// Implements `FromStr` to parse `key=value` pairs separated by commas into
+/// the fields they name, starting from the type's `Default` for the rest.
+fn impl_from_str_with_defaults(ast: &DeriveInput) -> syn::Result<TokenStream2> {
+    use syn::ext::IdentExt;
+
+    let fields = match ast.data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(ref fields), .. }) => &fields.named,
+        _ => return Err(syn::Error::new_spanned(&ast.ident, "#[derive(FromStrWithDefaults)] only supports structs with named fields")),
+    };
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+
+    let mut bounds = vec![quote! { #name #ty_generics: ::std::default::Default }];
+    let arms = fields.iter().enumerate().map(|(index, field)| {
+        let ident = &field.ident;
+        let ty = &field.ty;
+        if mentions_ty_params(ty, &ast.generics) {
+            bounds.push(quote! { #ty: ::std::str::FromStr });
+            bounds.push(quote! { <#ty as ::std::str::FromStr>::Err: ::std::fmt::Display });
+        }
+
+        let key = ident.as_ref().map(|ident| ident.unraw().to_string()).unwrap_or_default();
+        quote! {
+            #key => {
+                if ::std::mem::replace(&mut given[#index], true) {
+                    return ::std::result::Result::Err(format!("`{}` is given more than once", key));
+                }
+                parsed.#ident = value.parse::<#ty>().map_err(|err| format!("invalid value for `{}`: {}", key, err))?;
+            },
+        }
+    }).collect::<Vec<_>>();
+    let count = arms.len();
+
+    let where_clause = where_clause(&ast.generics, bounds);
+    let attrs = derived_attrs();
+    Ok(quote! {
+        #attrs
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::std::string::String;
+
+            #[allow(unused_mut)]
+            fn from_str(s: &str) -> ::std::result::Result<Self, ::std::string::String> {
+                let mut parsed = <#name #ty_generics as ::std::default::Default>::default();
+                let mut given = [false; #count];
+                for pair in s.split(',').map(str::trim).filter(|pair| !pair.is_empty()) {
+                    let (key, value) = match pair.split_once('=') {
+                        ::std::option::Option::Some((key, value)) => (key.trim(), value.trim()),
+                        ::std::option::Option::None => return ::std::result::Result::Err(format!("expected `key=value`, found `{}`", pair)),
+                    };
+                    match key {
+                        #(#arms)*
+                        _ => return ::std::result::Result::Err(format!("unknown key `{}`", key)),
+                    }
+                }
+                ::std::result::Result::Ok(parsed)
+            }
+        }
+    })
+}
+
+/// Collects the result for each field, reporting every field's error at
+/// once rather than only the first.
+fn all_fields<T, I>(results: I) -> syn::Result<Vec<T>>
+    where I: IntoIterator<Item = syn::Result<T>>
+{
+    let mut values = Vec::new();
+    let mut errors: Option<syn::Error> = None;
+
+    for result in results {
+        match result {
+            Ok(value) => values.push(value),
+            Err(err) => match errors {
+                Some(ref mut errors) => errors.combine(err),
+                None => errors = Some(err),
+            },
+        }
+    }
+
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(values),
+    }
+}
+
+/// Picks the variant named by the environment variable `env`, ignoring
+/// case, and falls back to `default` when it's unset or matches none. Each
+/// variant `build` gives a value for can be named.
+fn env_variant<F>(data: &syn::DataEnum, env: &syn::LitStr, default: TokenStream2, mut build: F) -> syn::Result<TokenStream2>
+    where F: FnMut(&syn::Variant) -> Option<syn::Result<TokenStream2>>
+{
+    use syn::ext::IdentExt;
+
+    let mut arms = Vec::new();
+    for variant in &data.variants {
+        if let Some(value) = build(variant) {
+            let value = value?;
+            let name = variant.ident.unraw().to_string();
+            arms.push(quote! { ::std::result::Result::Ok(ref value) if value.eq_ignore_ascii_case(#name) => #value });
+        }
+    }
+
+    Ok(quote! {
+        match ::std::env::var(#env) {
+            #(#arms,)*
+            _ => #default,
+        }
+    })
+}
+
+/// Picks among the variants given a `#[default(weight = N)]` at random, each
+/// in proportion to its weight. Their fields are built like those of the
+/// `variant_defaults` constructors.
+#[cfg(feature = "weighted-defaults")]
+fn weighted_variant(
+    ast: &DeriveInput,
+    data: &syn::DataEnum,
+    options: &ContainerOptions,
+    target: &TokenStream2,
+    build: Build,
+    bounds: &mut Vec<TokenStream2>,
+) -> syn::Result<TokenStream2> {
+    if let Some(variant) = data.variants.iter().find(|variant| is_default_variant(variant)) {
+        return Err(syn::Error::new_spanned(&variant.ident, "a `#[default]` variant can't be given as well as weighted variants"));
+    }
+    if let Some(ref discriminant) = options.discriminant {
+        return Err(syn::Error::new_spanned(discriminant, "a discriminant can't be given as well as weighted variants"));
+    }
+    if let Some(ref env) = options.env {
+        return Err(syn::Error::new_spanned(env, "`env` can't pick among weighted variants"));
+    }
+
+    let mut total = 0u64;
+    let mut choices = Vec::new();
+    for (variant, weight) in attr::variant_weights(data)? {
+        let value = weight.base10_parse::<u64>()?;
+        if value == 0 {
+            continue;
+        }
+        total = total.checked_add(value)
+            .ok_or_else(|| syn::Error::new_spanned(&weight, "the weights add up to more than `u64::MAX`"))?;
+
+        for field in &variant.fields {
+            bounds.extend(field_bounds(field, &ast.generics)?);
+        }
+        choices.push((total, variant_default(variant, target, &ast.generics, build)?));
+    }
+
+    let (_, last) = match choices.pop() {
+        Some(last) => last,
+        None => return Err(syn::Error::new_spanned(&ast.ident, "at least one weighted variant needs a weight above zero")),
+    };
+    let branches = choices.iter().map(|&(cumulative, ref value)| quote! { if pick < #cumulative { #value } else });
+
+    let runtime = runtime_crate();
+    Ok(quote! {
+        #runtime
+        let pick = specified_default::fastrand::u64(..#total);
+        #(#branches)* { #last }
+    })
+}
+
+#[cfg(not(feature = "weighted-defaults"))]
+fn weighted_variant(
+    ast: &DeriveInput,
+    _: &syn::DataEnum,
+    _: &ContainerOptions,
+    _: &TokenStream2,
+    _: Build,
+    _: &mut Vec<TokenStream2>,
+) -> syn::Result<TokenStream2> {
+    Err(syn::Error::new_spanned(&ast.ident, "weighted variants need the `weighted-defaults` feature of `specified_default`"))
+}
+
+/// The enum variant to default to, either marked `#[default]` or picked by
+/// its discriminant.
+fn default_variant<'a>(ast: &DeriveInput, data: &'a syn::DataEnum, options: &ContainerOptions) -> syn::Result<&'a syn::Variant> {
+    // Variants whose `#[cfg]` doesn't hold are removed before the derive
+    // runs, so gated defaults leave only one behind.
+    let mut attributed = data.variants.iter().filter(|variant| is_default_variant(variant));
+    let first = attributed.next();
+    if let Some(other) = attributed.next() {
+        return Err(syn::Error::new_spanned(
+            &other.ident,
+            "only one enum variant can be `#[default]` unless the others are gated with `#[cfg(...)]`",
+        ));
+    }
+
+    match (first, options.discriminant.as_ref()) {
+        (Some(variant), Some(discriminant)) => {
+            let mut err = syn::Error::new_spanned(discriminant, "a discriminant can't be given as well as a `#[default]` variant");
+            err.combine(syn::Error::new_spanned(&variant.ident, "`#[default]` variant given here"));
+            Err(err)
+        },
+        (Some(variant), None) => Ok(variant),
+        (None, Some(discriminant)) => variant_with_discriminant(data, discriminant),
+        (None, None) => {
+            Err(syn::Error::new_spanned(&ast.ident, "#[derive(SpecifiedDefault)] requires an enum variant is attributed with `#[default]`"))
+        },
+    }
+}
+
+/// The variant of `data` whose discriminant is `discriminant`. Variants
+/// without an explicit discriminant take the one after the previous
+/// variant's, as they do in the compiler.
+fn variant_with_discriminant<'a>(data: &'a syn::DataEnum, discriminant: &syn::Expr) -> syn::Result<&'a syn::Variant> {
+    let wanted = discriminant_value(discriminant)
+        .ok_or_else(|| syn::Error::new_spanned(discriminant, "expected an integer discriminant"))?;
+
+    let mut next = Some(0);
+    for variant in &data.variants {
+        let value = match variant.discriminant {
+            Some((_, ref expr)) => discriminant_value(expr),
+            None => next,
+        };
+
+        if value == Some(wanted) {
+            return Ok(variant);
+        }
+        next = value.and_then(|value| value.checked_add(1));
+    }
+
+    Err(syn::Error::new_spanned(discriminant, format!("no variant has the discriminant {}", wanted)))
+}
+
+/// The value of an integer literal discriminant, which may be negated.
+fn discriminant_value(expr: &syn::Expr) -> Option<i128> {
+    match *expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(ref int), .. }) => int.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), ref expr, .. }) => discriminant_value(expr).map(|value| -value),
+        syn::Expr::Paren(ref paren) => discriminant_value(&paren.expr),
+        syn::Expr::Group(ref group) => discriminant_value(&group.expr),
+        _ => None,
+    }
+}
+
+/// Generates `DEFAULTS_FINGERPRINT`, a hash of each field's name and
+/// specified default, or of the default variant for enums.
+fn impl_fingerprint(ast: &DeriveInput, options: &ContainerOptions) -> syn::Result<TokenStream2> {
+    let specified = match ast.data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(ref fields), .. }) => {
+            all_fields(fields.named.iter().map(|field| {
+                let ident = field.ident.as_ref().map(|ident| ident.to_string()).unwrap_or_default();
+                let options = FieldOptions::from_field(field)?;
+                let default = options.map_or_else(|| "Default".to_owned(), |options| options.describe());
+                Ok(format!("{}: {}", ident, default))
+            }))?
+                .join("\n")
+        },
+        Data::Enum(ref data) => default_variant(ast, data, options)?.ident.to_string(),
+        _ => return Err(syn::Error::new_spanned(&ast.ident, "#[derive(SpecifiedDefault)] does not support other struct variants")),
+    };
+
+    let fingerprint = fnv1a(specified.as_bytes());
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let attrs = derived_attrs();
+    Ok(quote! {
+        #attrs
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// A hash of the specified defaults, which changes whenever one
+            /// of them does.
+            pub const DEFAULTS_FINGERPRINT: u64 = #fingerprint;
+        }
+    })
+}
+
+/// The 64-bit FNV-1a hash of `bytes`, which unlike `std`'s hashers is the
+/// same across Rust versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// Generates `default_const`, which builds the same default in a `const fn`
+/// for use in statics and constants.
+fn impl_default_const(ast: &DeriveInput, options: &ContainerOptions, target: &TokenStream2) -> syn::Result<TokenStream2> {
+    if let Some(ref base) = options.base {
+        return Err(syn::Error::new_spanned(base, "`default_const` can't start from a base"));
+    }
+
+    let body = match ast.data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(ref fields), .. }) => {
+            let initializers = all_fields(fields.named.iter().map(|field| {
+                let ident = &field.ident;
+                let value = const_field_default(field)?;
+                Ok(quote! { #ident: #value })
+            }))?;
+
+            quote! { #target { #(#initializers),* } }
+        },
+        Data::Enum(ref data) => {
+            let variant = default_variant(ast, data, options)?;
+            if options.variant_defaults {
+                let values = all_fields(variant.fields.iter().map(const_field_default))?;
+                variant_value(variant, target, values)
+            } else {
+                let variant = &variant.ident;
+                quote! { #target::#variant }
+            }
+        },
+        _ => return Err(syn::Error::new_spanned(&ast.ident, "#[derive(SpecifiedDefault)] does not support other struct variants")),
+    };
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let output = match options.remote {
+        Some(_) => target.clone(),
+        None => quote! { #name #ty_generics },
+    };
+    let attrs = derived_attrs();
+    // A foreign type can't implement the foreign trait here
+    let const_default = if cfg!(feature = "const-default") && options.remote.is_none() {
+        let runtime = runtime_crate();
+        msrv::scoped(&format!("CONST_DEFAULT_{}", name), quote! {
+            #runtime
+
+            #attrs
+            impl #impl_generics specified_default::const_default::ConstDefault for #name #ty_generics #where_clause {
+                const DEFAULT: #name #ty_generics = #name::default_const();
+            }
+        })
+    } else {
+        quote! {}
+    };
+    Ok(quote! {
+        #attrs
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub const fn default_const() -> #output {
+                #body
+            }
+        }
+        #const_default
+    })
+}
+
+/// Builds a field's default for `default_const`, which can't parse values
+/// at runtime or call `Default::default`.
+fn const_field_default(field: &syn::Field) -> syn::Result<TokenStream2> {
+    let options = match FieldOptions::from_field(field)? {
+        Some(options) => options,
+        None if generic_arg(&field.ty, "Weak").is_some() => {
+            msrv::require(73, "`Weak::new` in a const fn", field.span())?;
+            return Ok(unset_pointer(&field.ty).expect("`Weak` fields start out empty"));
+        },
+        None => return unset_pointer(&field.ty)
+            .ok_or_else(|| syn::Error::new_spanned(field, "`default_const` needs every field to have a `#[default]`")),
+    };
+
+    if options.shared || options.via.is_some() || options.try_via.is_some() || options.serialized.is_some() || options.system.is_some() || options.capacity.is_some() || options.zeroed {
+        let key = match options.serialized {
+            Some((ref format, _)) => format.key(),
+            None if options.system.is_some() => options.system.unwrap().key(),
+            None if options.shared => "shared",
+            None if options.capacity.is_some() => "capacity",
+            None if options.zeroed => "zeroed",
+            None if options.via.is_some() => "via",
+            None => "try_via",
+        };
+        return Err(syn::Error::new_spanned(field, format!("`{}` defaults can't be built in a const fn", key)));
+    }
+
+    let ty = options.ty.as_ref().unwrap_or(&field.ty);
+    let build = |value: &syn::LitStr| -> syn::Result<TokenStream2> {
+        msrv::require(33, "`default_const`, which binds values with `let` in a const fn,", value.span())?;
+        let built = const_value_for(ty, &value.value(), value.span())?;
+        Ok(quote! { { let value: #ty = #built; value } })
+    };
+
+    if let Some(ref repeat) = options.repeat {
+        return repeat_value(ty, repeat, const_value_for);
+    }
+
+    Ok(match (options.value.as_ref(), options.debug.as_ref(), options.release.as_ref()) {
+        (Some(value), _, _) => build(value)?,
+        (None, Some(debug), Some(release)) => {
+            msrv::require(46, "`debug` and `release` in `default_const`, which picks one with `if` in a const fn,", debug.span())?;
+            let debug = build(debug)?;
+            let release = build(release)?;
+            quote! { if cfg!(debug_assertions) { #debug } else { #release } }
+        },
+        // The remaining forms are expressions written out as they are
+        _ => field_default(field, &syn::Generics::default(), value_for)?,
+    })
+}
+
+/// Generates `default_ref`, which lazily builds one default instance and
+/// hands out references to it.
+fn impl_default_ref(ast: &DeriveInput, options: &ContainerOptions) -> syn::Result<TokenStream2> {
+    let name = &ast.ident;
+    if options.remote.is_some() {
+        return Err(syn::Error::new_spanned(name, "`default_ref` is only supported for local types"));
+    }
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&ast.generics, "`default_ref` isn't supported for generic types"));
+    }
+    msrv::require(70, "`default_ref`, which keeps its instance in a `std::sync::OnceLock`,", name.span())?;
+
+    let attrs = derived_attrs();
+    Ok(quote! {
+        #attrs
+        impl #name {
+            pub fn default_ref() -> &'static #name {
+                static DEFAULT: ::std::sync::OnceLock<#name> = ::std::sync::OnceLock::new();
+                DEFAULT.get_or_init(<#name as ::std::default::Default>::default)
+            }
+        }
+    })
+}
+
+/// Wraps `body` so that it runs only the first time, into a static prototype
+/// that every later default is cloned from.
+fn prototype(ast: &DeriveInput, target: &TokenStream2, body: TokenStream2) -> syn::Result<TokenStream2> {
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&ast.generics, "`prototype` isn't supported for generic types"));
+    }
+    msrv::require(70, "`prototype`, which keeps its instance in a `std::sync::OnceLock`,", ast.ident.span())?;
+
+    Ok(quote! {
+        static PROTOTYPE: ::std::sync::OnceLock<#target> = ::std::sync::OnceLock::new();
+        ::std::clone::Clone::clone(PROTOTYPE.get_or_init(|| { #body }))
+    })
+}
+
+/// Generates a test module that builds the default and, separately, each
+/// specified field value, so a bad value fails the build's tests rather
+/// than the first caller at runtime.
+fn impl_tests(ast: &DeriveInput) -> syn::Result<TokenStream2> {
+    use syn::ext::IdentExt;
+
+    let name = &ast.ident;
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&ast.generics, "`generate_tests` isn't supported for generic types"));
+    }
+
+    let mut tests = Vec::new();
+    if let Data::Struct(syn::DataStruct { fields: Fields::Named(ref fields), .. }) = ast.data {
+        for field in fields.named.iter().filter(|field| has_default_attr(field)) {
+            let ty = &field.ty;
+            let value = field_default(field, &ast.generics, value_for)?;
+            let test = format_ident!("parses_{}", field.ident.as_ref().unwrap().unraw());
+
+            tests.push(quote! {
+                #[test]
+                fn #test() {
+                    let _: #ty = #value;
+                }
+            });
+        }
+    }
+
+    let module = format_ident!("__specified_default_tests_{}", name);
+    Ok(quote! {
+        #[cfg(test)]
+        #[allow(non_snake_case)]
+        mod #module {
+            use super::*;
+
+            #[test]
+            fn constructs_default() {
+                let _ = #name::default();
+            }
+
+            #(#tests)*
+        }
+    })
+}
+
+/// Generates `default_with_overrides`, which applies string overrides by
+/// field name on top of the specified defaults.
+fn impl_overrides(ast: &DeriveInput, options: &ContainerOptions) -> syn::Result<TokenStream2> {
+    let fields = local_struct_fields(ast, options, "overrides")?;
+    let idents = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+    let keys = idents.iter().map(|ident| ident.as_ref().map(|ident| ident.to_string())).collect::<Vec<_>>();
+    let arms = fields.iter()
+        .map(|field| {
+            let ident = &field.ident;
+            let key = ident.as_ref().map(|ident| ident.to_string());
+
+            quote! {
+                #key => result.#ident = value.parse().map_err(|err| {
+                    format!("Failed to parse {} for {}: {:?}", value, key, err)
+                })?
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+    let where_clause = where_clause(&ast.generics, from_str_bounds(ast, fields));
+
+    let with_provenance = if options.provenance {
+        let provenance = provenance_ident(ast);
+        let runtime = runtime_path();
+        quote! {
+            pub fn default_with_overrides_with_provenance(
+                overrides: &::std::collections::HashMap<&str, &str>,
+            ) -> ::std::result::Result<(#name #ty_generics, #provenance), ::std::string::String> {
+                let mut provenance = <#provenance as ::std::default::Default>::default();
+                for &key in overrides.keys() {
+                    match key {
+                        #(#keys => provenance.#idents = #runtime::Provenance::Override,)*
+                        _ => {},
+                    }
+                }
+
+                ::std::result::Result::Ok((Self::default_with_overrides(overrides)?, provenance))
+            }
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    let attrs = derived_attrs();
+    Ok(quote! {
+        #attrs
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn default_with_overrides(
+                overrides: &::std::collections::HashMap<&str, &str>,
+            ) -> ::std::result::Result<#name #ty_generics, ::std::string::String> {
+                let mut result = <#name #ty_generics as ::std::default::Default>::default();
+
+                for (&key, &value) in overrides {
+                    match key {
+                        #(#arms,)*
+                        _ => return Err(format!("Unknown field {}", key)),
+                    }
+                }
+
+                Ok(result)
+            }
+
+            #with_provenance
+        }
+    })
+}
+
+/// Generates `<Type>Provenance`, whose `Default` implementation records
+/// where `default` takes each field from.
+fn impl_provenance(ast: &DeriveInput, options: &ContainerOptions) -> syn::Result<TokenStream2> {
+    let fields = local_struct_fields(ast, options, "provenance")?;
+    let idents = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+    let sources = fields.iter().map(|field| {
+        if has_default_attr(field) {
+            quote! { Specified }
+        } else if options.base.is_some() {
+            quote! { Base }
+        } else {
+            quote! { TypeDefault }
+        }
+    });
+
+    let provenance = provenance_ident(ast);
+    let runtime = runtime_path();
+    let vis = &ast.vis;
+    let doc = format!("Where the fields of a [`{}`] came from.", ast.ident);
+    let attrs = derived_attrs();
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        #vis struct #provenance {
+            #(pub #idents: #runtime::Provenance),*
+        }
+
+        #attrs
+        impl ::std::default::Default for #provenance {
+            fn default() -> #provenance {
+                #provenance {
+                    #(#idents: #runtime::Provenance::#sources),*
+                }
+            }
+        }
+    })
+}
+
+fn provenance_ident(ast: &DeriveInput) -> syn::Ident {
+    syn::Ident::new(&format!("{}Provenance", ast.ident), ast.ident.span())
+}
+
+/// Generates a `default_<variant>` constructor for each variant of an enum,
+/// which builds the variant's fields from their specified defaults, and
+/// `default_for`, which looks the constructor up by the variant's name.
+fn impl_variant_defaults(ast: &DeriveInput, build: Build) -> syn::Result<TokenStream2> {
+    use syn::ext::IdentExt;
+
+    let data = match ast.data {
+        Data::Enum(ref data) => data,
+        _ => return Err(syn::Error::new_spanned(&ast.ident, "`variant_defaults` is only supported for enums")),
+    };
+
+    let mut bounds = Vec::new();
+    let mut lookups = Vec::new();
+    let constructors = all_fields(data.variants.iter().map(|variant| {
+        for field in &variant.fields {
+            bounds.extend(field_bounds(field, &ast.generics)?);
+        }
+
+        let body = variant_default(variant, &quote! { Self }, &ast.generics, build)?;
+        let variant_name = variant.ident.unraw().to_string();
+        let method = syn::Ident::new(&format!("default_{}", snake_case(&variant_name)), variant.ident.span());
+
+        // Config files tend to spell variants in snake case
+        let mut names = vec![variant_name.clone()];
+        if snake_case(&variant_name) != variant_name {
+            names.push(snake_case(&variant_name));
+        }
+        lookups.push(quote! {
+            #(#names)|* => ::std::option::Option::Some(Self::#method()),
+        });
+
+        Ok(quote! {
+            pub fn #method() -> Self {
+                #body
+            }
+        })
+    }))?;
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+    let where_clause = where_clause(&ast.generics, bounds);
+    let attrs = derived_attrs();
+    Ok(quote! {
+        #attrs
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#constructors)*
+
+            /// The variant called `name`, as written or in snake case,
+            /// built from its fields' defaults.
+            pub fn default_for(name: &str) -> ::std::option::Option<Self> {
+                match name {
+                    #(#lookups)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+    })
+}
+
+/// Builds `variant` with each of its fields set to its default.
+fn variant_default(variant: &syn::Variant, target: &TokenStream2, generics: &syn::Generics, build: Build) -> syn::Result<TokenStream2> {
+    let values = all_fields(variant.fields.iter().map(|field| field_default(field, generics, build)))?;
+    Ok(variant_value(variant, target, values))
+}
+
+/// Builds `variant` with `values` for its fields, in order.
+fn variant_value(variant: &syn::Variant, target: &TokenStream2, values: Vec<TokenStream2>) -> TokenStream2 {
+    let ident = &variant.ident;
+    match variant.fields {
+        Fields::Named(ref fields) => {
+            let idents = fields.named.iter().map(|field| &field.ident);
+            quote! { #target::#ident { #(#idents: #values),* } }
+        },
+        Fields::Unnamed(_) => quote! { #target::#ident(#(#values),*) },
+        Fields::Unit => quote! { #target::#ident },
+    }
+}
+
+/// `CamelCase` as `snake_case`, for naming methods after variants. A run of
+/// capitals is one word, so `HTTPServer` is `http_server`.
+fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut snake = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let after_word = chars[i - 1].is_lowercase() || chars[i - 1].is_ascii_digit();
+            let starts_word = chars[i - 1].is_uppercase() && chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if after_word || starts_word {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+/// Generates `assert_specified_defaults`, which panics naming the first
+/// field that differs from its default.
+fn impl_assert_defaults(ast: &DeriveInput, options: &ContainerOptions) -> syn::Result<TokenStream2> {
+    let fields = local_struct_fields(ast, options, "assert_defaults")?;
+    let checks = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let key = ident.as_ref().map(|ident| ident.to_string());
+        quote! {
+            if self.#ident != default.#ident {
+                panic!(
+                    "`{}` differs from its default\n  value: {:?}\ndefault: {:?}",
+                    #key, self.#ident, default.#ident,
+                );
+            }
+        }
+    });
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+    let mut bounds = vec![quote! { #name #ty_generics: ::std::default::Default }];
+    bounds.extend(fields.iter()
+        .filter(|field| mentions_ty_params(&field.ty, &ast.generics))
+        .map(|field| {
+            let ty = &field.ty;
+            quote! { #ty: ::std::cmp::PartialEq + ::std::fmt::Debug }
+        }));
+    let where_clause = where_clause(&ast.generics, bounds);
+    // Panics are reported where the check was called from, on compilers
+    // that can
+    let track_caller = if msrv::at_least(46) { quote! { #[track_caller] } } else { quote! {} };
+    let attrs = derived_attrs();
+    Ok(quote! {
+        #attrs
+        impl #impl_generics #name #ty_generics #where_clause {
+            #track_caller
+            pub fn assert_specified_defaults(&self) {
+                let default = <#name #ty_generics as ::std::default::Default>::default();
+                #(#checks)*
+            }
+        }
+    })
+}
+
+/// Generates `default_field`, which builds a single field's default by name
+/// without building the rest.
+fn impl_default_field(ast: &DeriveInput, options: &ContainerOptions, build: Build) -> syn::Result<TokenStream2> {
+    let fields = match ast.data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(ref fields), .. }) => &fields.named,
+        _ => return Err(syn::Error::new_spanned(&ast.ident, "`default_field` is only supported for structs with named fields")),
+    };
+
+    let mut bounds = Vec::new();
+    let arms = all_fields(fields.iter().map(|field| {
+        let ident = &field.ident;
+        let key = ident.as_ref().map(|ident| ident.to_string());
+        let ty = &field.ty;
+        let value = match options.base {
+            Some(ref base) if !has_default_attr(field) => quote! { ::std::clone::Clone::clone(&(#base).#ident) },
+            _ => field_default(field, &ast.generics, build)?,
+        };
+
+        if mentions_ty_params(ty, &ast.generics) {
+            bounds.push(quote! { #ty: 'static });
+        }
+        bounds.extend(field_bounds(field, &ast.generics)?);
+
+        Ok(quote! {
+            #key => ::std::option::Option::Some(::std::boxed::Box::new({ let value: #ty = #value; value }))
+        })
+    }))?;
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+    let where_clause = where_clause(&ast.generics, bounds);
+    let attrs = derived_attrs();
+    let any = boxed_dyn(quote! { ::std::any::Any }, quote! {});
+    Ok(quote! {
+        #attrs
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[allow(unused_parens)]
+            pub fn default_field(name: &str) -> ::std::option::Option<#any> {
+                match name {
+                    #(#arms,)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+    })
+}
+
+/// Generates `<Type>Defaults`, a `config::Source` holding the specified
+/// defaults, to be added as the lowest layer of a configuration. Fields
+/// marked `nested` are tables taken from their own type's source.
+#[cfg(feature = "config")]
+fn impl_config_source(ast: &DeriveInput, options: &ContainerOptions, build: Build) -> syn::Result<TokenStream2> {
+    let fields = local_struct_fields(ast, options, "config_source")?;
+    if let Some(param) = ast.generics.params.first() {
+        return Err(syn::Error::new_spanned(param, "`config_source` is not supported for generic types"));
+    }
+
+    let name = &ast.ident;
+    let source = syn::Ident::new(&format!("{}Defaults", name), name.span());
+    let entries = all_fields(fields.into_iter().filter(|field| has_default_attr(field)).map(|field| {
+        let key = field.ident.as_ref().map(|ident| ident.to_string());
+        let value = match FieldOptions::from_field(field)? {
+            Some(FieldOptions { nested: true, .. }) => {
+                let nested = nested_source(&field.ty)?;
+                quote! { specified_default::config::Source::collect(&#nested)? }
+            },
+            _ => {
+                let ty = &field.ty;
+                let value = field_default(field, &ast.generics, build)?;
+                quote! { { let value: #ty = #value; value } }
+            },
+        };
+
+        let ty = &field.ty;
+        Ok(quote_spanned! {ty.span()=>
+            map.insert(::std::string::String::from(#key), specified_default::config::Value::new(::std::option::Option::Some(&origin), #value));
+        })
+    }))?;
+
+    let type_name = name.to_string();
+    let vis = &ast.vis;
+    let doc = format!("The specified defaults of [`{}`], as a configuration source.", name);
+    let attrs = derived_attrs();
+    let runtime = runtime_crate();
+    let boxed_source = boxed_dyn(quote! { specified_default::config::Source }, quote! { + Send + Sync });
+    let impl_source = msrv::scoped(&format!("CONFIG_SOURCE_{}", name), quote! {
+        #runtime
+
+        #attrs
+        impl specified_default::config::Source for #source {
+            #[allow(unused_parens)]
+            fn clone_into_box(&self) -> #boxed_source {
+                ::std::boxed::Box::new(*self)
+            }
+
+            fn collect(&self) -> ::std::result::Result<
+                specified_default::config::Map<::std::string::String, specified_default::config::Value>,
+                specified_default::config::ConfigError,
+            > {
+                let origin = ::std::string::String::from(concat!(module_path!(), "::", #type_name, " defaults"));
+                let mut map = specified_default::config::Map::new();
+                #(#entries)*
+                ::std::result::Result::Ok(map)
+            }
+        }
+    });
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy, Debug, Default)]
+        #vis struct #source;
+
+        #impl_source
+    })
+}
+
+#[cfg(not(feature = "config"))]
+fn impl_config_source(ast: &DeriveInput, _: &ContainerOptions, _: Build) -> syn::Result<TokenStream2> {
+    Err(syn::Error::new_spanned(&ast.ident, "`config_source` needs the `config` feature of `specified_default`"))
+}
+
+/// The configuration source generated for a `nested` field's type, found by
+/// appending `Defaults` to the type's name.
+#[cfg(feature = "config")]
+fn nested_source(ty: &syn::Type) -> syn::Result<syn::Path> {
+    match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => {
+            let mut path = path.clone();
+            if let Some(last) = path.segments.last_mut() {
+                last.ident = syn::Ident::new(&format!("{}Defaults", last.ident), last.ident.span());
+                last.arguments = syn::PathArguments::None;
+            }
+            Ok(path)
+        },
+        syn::Type::Paren(ref paren) => nested_source(&paren.elem),
+        _ => Err(syn::Error::new_spanned(ty, "`nested` fields of a `config_source` must name a type with its own `config_source`")),
+    }
+}
+
+/// Generates `defaults_provider`, which returns a `figment` provider of the
+/// specified defaults. Fields marked `nested` are dictionaries taken from
+/// their own type's provider.
+#[cfg(feature = "figment")]
+fn impl_defaults_provider(ast: &DeriveInput, options: &ContainerOptions, build: Build) -> syn::Result<TokenStream2> {
+    let fields = local_struct_fields(ast, options, "defaults_provider")?;
+    if let Some(param) = ast.generics.params.first() {
+        return Err(syn::Error::new_spanned(param, "`defaults_provider` is not supported for generic types"));
+    }
+
+    let entries = all_fields(fields.into_iter().filter(|field| has_default_attr(field)).map(|field| {
+        let key = field.ident.as_ref().map(|ident| ident.to_string());
+        let ty = &field.ty;
+        let value = match FieldOptions::from_field(field)? {
+            Some(FieldOptions { nested: true, .. }) => quote! {
+                specified_default::figment::value::Value::from(<#ty>::defaults_provider().value)
+            },
+            _ => {
+                let value = field_default(field, &ast.generics, build)?;
+                let message = format!("Failed to serialize the default of `{}`", key.as_ref().map_or("", String::as_str));
+                quote_spanned! {ty.span()=>
+                    specified_default::figment::value::Value::serialize({ let value: #ty = #value; value }).expect(#message)
+                }
+            },
+        };
+
+        Ok(quote! {
+            dict.insert(::std::string::String::from(#key), #value);
+        })
+    }))?;
+
+    let name = &ast.ident;
+    let attrs = derived_attrs();
+    let runtime = runtime_crate();
+    Ok(msrv::scoped(&format!("DEFAULTS_PROVIDER_{}", name), quote! {
+        #runtime
+
+        #attrs
+        impl #name {
+            pub fn defaults_provider() -> specified_default::figment::providers::Serialized<specified_default::figment::value::Dict> {
+                let mut dict = specified_default::figment::value::Dict::new();
+                #(#entries)*
+                specified_default::figment::providers::Serialized::defaults(dict)
+            }
+        }
+    }))
+}
+
+#[cfg(not(feature = "figment"))]
+fn impl_defaults_provider(ast: &DeriveInput, _: &ContainerOptions, _: Build) -> syn::Result<TokenStream2> {
+    Err(syn::Error::new_spanned(&ast.ident, "`defaults_provider` needs the `figment` feature of `specified_default`"))
+}
+
+/// Generates a `default_<format>` function for each format given by the
+/// `serialize` key, returning the serialized default.
+fn impl_serialize_defaults(ast: &DeriveInput, options: &ContainerOptions) -> syn::Result<TokenStream2> {
+    let functions = all_fields(options.serialize.iter().map(|(format, key)| {
+        let method = syn::Ident::new(&format!("default_{}", format.key()), key.span());
+        let serialized = format.to_string(quote! { &<Self as ::std::default::Default>::default() }, key.span())?;
+        let message = format!("Failed to serialize the default as {}", format.key());
+        Ok(quote! {
+            pub fn #method() -> ::std::string::String {
+                #serialized.expect(#message)
+            }
+        })
+    }))?;
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let attrs = derived_attrs();
+    let runtime = runtime_crate();
+    Ok(msrv::scoped(&format!("SERIALIZE_{}", name), quote! {
+        #runtime
+
+        #attrs
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#functions)*
+        }
+    }))
+}
+
+/// How often `arbitrary_near_default` replaces a specified default with an
+/// arbitrary value.
+#[cfg(feature = "proptest")]
+const PERTURBATION_PROBABILITY: f64 = 0.2;
+
+/// Generates `arbitrary_near_default`, a `proptest` strategy yielding the
+/// default with each field that has a specified default independently
+/// replaced by an arbitrary value some of the time.
+#[cfg(feature = "proptest")]
+fn impl_arbitrary_near_default(ast: &DeriveInput, options: &ContainerOptions) -> syn::Result<TokenStream2> {
+    let fields = local_struct_fields(ast, options, "arbitrary_near_default")?.into_iter()
+        .filter(|field| has_default_attr(field))
+        .collect::<Vec<_>>();
+
+    // Nested pairs, so that any number of fields fits in a tuple strategy
+    let strategies = fields.iter().rev().fold(quote! { specified_default::proptest::strategy::Just(()) }, |rest, field| {
+        let ty = &field.ty;
+        quote! {
+            (specified_default::proptest::option::weighted(
+                #PERTURBATION_PROBABILITY,
+                specified_default::proptest::arbitrary::any::<#ty>(),
+            ), #rest)
+        }
+    });
+    let idents = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+    let pattern = idents.iter().rev().fold(quote! { () }, |rest, ident| quote! { (#ident, #rest) });
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+    let mut bounds = vec![
+        quote! { #name #ty_generics: ::std::default::Default + ::std::fmt::Debug },
+    ];
+    bounds.extend(fields.iter()
+        .filter(|field| mentions_ty_params(&field.ty, &ast.generics))
+        .map(|field| {
+            let ty = &field.ty;
+            quote! { #ty: specified_default::proptest::arbitrary::Arbitrary }
+        }));
+    let where_clause = where_clause(&ast.generics, bounds);
+    let attrs = derived_attrs();
+    let runtime = runtime_crate();
+    Ok(msrv::scoped(&format!("ARBITRARY_{}", name), quote! {
+        #runtime
+
+        #attrs
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn arbitrary_near_default() -> impl specified_default::proptest::strategy::Strategy<Value = Self> {
+                specified_default::proptest::strategy::Strategy::prop_map(#strategies, |#pattern| {
+                    let mut value = <Self as ::std::default::Default>::default();
+                    #(
+                        if let ::std::option::Option::Some(#idents) = #idents {
+                            value.#idents = #idents;
+                        }
+                    )*
+                    value
+                })
+            }
+        }
+    }))
+}
+
+#[cfg(not(feature = "proptest"))]
+fn impl_arbitrary_near_default(ast: &DeriveInput, _: &ContainerOptions) -> syn::Result<TokenStream2> {
+    Err(syn::Error::new_spanned(&ast.ident, "`arbitrary_near_default` needs the `proptest` feature of `specified_default`"))
+}
+
+/// Generates a `wasm_bindgen` constructor, so that `new` called from
+/// JavaScript gives the default.
+#[cfg(feature = "wasm-bindgen")]
+fn impl_wasm_constructor(ast: &DeriveInput, options: &ContainerOptions) -> syn::Result<TokenStream2> {
+    let name = &ast.ident;
+    if options.remote.is_some() {
+        return Err(syn::Error::new_spanned(name, "`wasm_constructor` is only supported for local types"));
+    }
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&ast.generics, "`wasm_constructor` isn't supported for generic types"));
+    }
+    if !matches!(ast.data, Data::Struct(_)) {
+        return Err(syn::Error::new_spanned(name, "`wasm_constructor` is only supported for structs"));
+    }
+
+    let attrs = derived_attrs();
+    Ok(quote! {
+        #attrs
+        #[::wasm_bindgen::prelude::wasm_bindgen]
+        impl #name {
+            #[wasm_bindgen(constructor)]
+            pub fn new() -> #name {
+                <#name as ::std::default::Default>::default()
+            }
+        }
+    })
+}
+
+#[cfg(not(feature = "wasm-bindgen"))]
+fn impl_wasm_constructor(ast: &DeriveInput, _: &ContainerOptions) -> syn::Result<TokenStream2> {
+    Err(syn::Error::new_spanned(&ast.ident, "`wasm_constructor` needs the `wasm-bindgen` feature of `specified_default_derive`"))
+}
+
+/// Generates a `pyo3` constructor, so that the class called from Python
+/// gives the default.
+#[cfg(feature = "pyo3")]
+fn impl_py_new(ast: &DeriveInput, options: &ContainerOptions) -> syn::Result<TokenStream2> {
+    let name = &ast.ident;
+    if options.remote.is_some() {
+        return Err(syn::Error::new_spanned(name, "`py_new` is only supported for local types"));
+    }
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&ast.generics, "`py_new` isn't supported for generic types"));
+    }
+
+    let attrs = derived_attrs();
+    Ok(quote! {
+        #attrs
+        #[::pyo3::pymethods]
+        impl #name {
+            #[new]
+            pub fn py_new() -> #name {
+                <#name as ::std::default::Default>::default()
+            }
+        }
+    })
+}
+
+#[cfg(not(feature = "pyo3"))]
+fn impl_py_new(ast: &DeriveInput, _: &ContainerOptions) -> syn::Result<TokenStream2> {
+    Err(syn::Error::new_spanned(&ast.ident, "`py_new` needs the `pyo3` feature of `specified_default_derive`"))
+}
+
+/// Exports `<type>_default`, an `extern "C"` function returning the default,
+/// so that C callers start from the same defaults as Rust.
+#[cfg(feature = "ffi")]
+fn impl_ffi(ast: &DeriveInput, options: &ContainerOptions) -> syn::Result<TokenStream2> {
+    use syn::ext::IdentExt;
+
+    let name = &ast.ident;
+    if options.remote.is_some() {
+        return Err(syn::Error::new_spanned(name, "`ffi` is only supported for local types"));
+    }
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&ast.generics, "`ffi` isn't supported for generic types"));
+    }
+    let repr_c = ast.attrs.iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+        .filter_map(|attr| attr.parse_args_with(syn::punctuated::Punctuated::<syn::Path, Token![,]>::parse_terminated).ok())
+        .any(|reprs| reprs.iter().any(|repr| repr.is_ident("C") || repr.is_ident("transparent")));
+    if !repr_c {
+        return Err(syn::Error::new_spanned(name, "`ffi` needs a `#[repr(C)]` type, whose layout C agrees on"));
+    }
+    // Without `unsafe`, `no_mangle` is an error in the 2024 edition
+    msrv::require(82, "`ffi`, which exports its function with `#[unsafe(no_mangle)]`,", name.span())?;
+
+    let function = format_ident!("{}_default", snake_case(&name.unraw().to_string()));
+    let attrs = derived_attrs();
+    Ok(quote! {
+        #attrs
+        const _: () = {
+            #[unsafe(no_mangle)]
+            pub extern "C" fn #function() -> #name {
+                <#name as ::std::default::Default>::default()
+            }
+        };
+    })
+}
+
+#[cfg(not(feature = "ffi"))]
+fn impl_ffi(ast: &DeriveInput, _: &ContainerOptions) -> syn::Result<TokenStream2> {
+    Err(syn::Error::new_spanned(&ast.ident, "`ffi` needs the `ffi` feature of `specified_default_derive`"))
+}
+
+/// Generates an `apply_<group>_defaults` method for each group named by a
+/// field's `group` key, which resets that group's fields to their defaults.
+fn impl_groups(ast: &DeriveInput, options: &ContainerOptions, build: Build) -> syn::Result<Option<TokenStream2>> {
+    let fields = match ast.data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(ref fields), .. }) => &fields.named,
+        _ => return Ok(None),
+    };
+
+    let mut groups: Vec<(syn::Ident, Vec<TokenStream2>)> = Vec::new();
+    let mut bounds = Vec::new();
+    for field in fields {
+        let group = match FieldOptions::from_field(field)? {
+            Some(FieldOptions { group: Some(group), .. }) => group,
+            _ => continue,
+        };
+        if options.remote.is_some() {
+            return Err(syn::Error::new_spanned(group, "groups are only supported for local structs"));
+        }
+
+        let ident = &field.ident;
+        let value = field_default(field, &ast.generics, build)?;
+        bounds.extend(field_bounds(field, &ast.generics)?);
+
+        let assignment = quote! { self.#ident = #value; };
+        match groups.iter_mut().find(|&&mut (ref name, _)| *name == group) {
+            Some(&mut (_, ref mut assignments)) => assignments.push(assignment),
+            None => groups.push((group, vec![assignment])),
+        }
+    }
+
+    if groups.is_empty() {
+        return Ok(None);
+    }
+
+    let methods = groups.into_iter().map(|(group, assignments)| {
+        let method = syn::Ident::new(&format!("apply_{}_defaults", group), group.span());
+        quote! {
+            pub fn #method(&mut self) {
+                #(#assignments)*
+            }
+        }
+    });
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+    let where_clause = where_clause(&ast.generics, bounds);
+    let attrs = derived_attrs();
+    Ok(Some(quote! {
+        #attrs
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#methods)*
+        }
+    }))
+}
+
+/// Generates `default_for_version`, which builds the default as a release
+/// before each field's `since` did, when any field gives one.
+fn impl_versions(ast: &DeriveInput, options: &ContainerOptions, build: Build) -> syn::Result<Option<TokenStream2>> {
+    let fields = match ast.data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(ref fields), .. }) => &fields.named,
+        _ => return Ok(None),
+    };
+
+    let mut changes = Vec::new();
+    let mut bounds = Vec::new();
+    for field in fields {
+        let (since, previously) = match FieldOptions::from_field(field)? {
+            Some(FieldOptions { since: Some(since), previously: Some(previously), .. }) => (since, previously),
+            _ => continue,
+        };
+        if options.remote.is_some() {
+            return Err(syn::Error::new_spanned(since, "versioned defaults are only supported for local structs"));
+        }
+
+        let parts = version_parts(&since)?;
+        let ident = &field.ident;
+        let value = field_default(&previous_field(field, &previously)?, &ast.generics, build)?;
+        bounds.extend(field_bounds(field, &ast.generics)?);
+        changes.push(quote! {
+            if before(&[#(#parts),*]) {
+                default.#ident = #value;
+            }
+        });
+    }
+
+    if changes.is_empty() {
+        return Ok(None);
+    }
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+    bounds.push(quote! { #name #ty_generics: ::std::default::Default });
+    let where_clause = where_clause(&ast.generics, bounds);
+    let attrs = derived_attrs();
+    Ok(Some(quote! {
+        #attrs
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// The default as release `version` built it, with the fields
+            /// changed since then set to their previous defaults.
+            ///
+            /// Panics if `version` isn't made of numbers such as `2.0`.
+            pub fn default_for_version(version: &str) -> Self {
+                let requested: ::std::vec::Vec<u64> = version.split('.')
+                    .map(|part| part.trim().parse().unwrap_or_else(|_| panic!("`{}` isn't a version such as `2.0`", version)))
+                    .collect();
+                // Missing parts count as zero, so `2` is the same as `2.0`
+                let before = |since: &[u64]| {
+                    let len = ::std::cmp::max(requested.len(), since.len());
+                    let part = |parts: &[u64], i: usize| parts.get(i).cloned().unwrap_or(0);
+                    (0..len).map(|i| part(&requested, i)).lt((0..len).map(|i| part(since, i)))
+                };
+
+                let mut default = <Self as ::std::default::Default>::default();
+                #(#changes)*
+                default
+            }
+        }
+    }))
+}
+
+/// The numbers of a dotted version such as `2.0`.
+fn version_parts(version: &syn::LitStr) -> syn::Result<Vec<u64>> {
+    version.value().split('.')
+        .map(|part| part.trim().parse::<u64>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| syn::Error::new(version.span(), format!("`{}` isn't a version such as `2.0`", version.value())))
+}
+
+/// `field` with its `value` replaced by `previously`, for building it as a
+/// release before `since` would. Its other keys, such as conversions, stay.
+fn previous_field(field: &syn::Field, previously: &syn::LitStr) -> syn::Result<syn::Field> {
+    let mut previous = field.clone();
+    for attr in previous.attrs.iter_mut().filter(|attr| attr.path().is_ident(ATTRIBUTE_NAME)) {
+        let keys = attr.parse_args_with(syn::punctuated::Punctuated::<syn::Meta, Token![,]>::parse_terminated)?
+            .into_iter()
+            .filter(|meta| !meta.path().is_ident("since") && !meta.path().is_ident("previously"))
+            .map(|meta| match meta {
+                syn::Meta::NameValue(ref name_value) if name_value.path.is_ident("value") => parse_quote! { value = #previously },
+                meta => meta,
+            })
+            .collect::<Vec<syn::Meta>>();
+        *attr = parse_quote! { #[default(#(#keys),*)] };
+    }
+    Ok(previous)
+}
+
+/// Generates `describe_defaults`, which describes each field's default as
+/// a `specified_default::DefaultsNode`, and those of `nested` fields with
+/// their own type's tree.
+fn impl_describe(ast: &DeriveInput, options: &ContainerOptions) -> syn::Result<TokenStream2> {
+    let name = &ast.ident;
+    let type_name = name.to_string();
+    let (value, children) = match ast.data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(ref fields), .. }) => {
+            let children = all_fields(fields.named.iter().map(|field| {
+                let ident = field.ident.as_ref().map(|ident| ident.to_string()).unwrap_or_default();
+                let value = match FieldOptions::from_field(field)? {
+                    Some(FieldOptions { nested: true, .. }) => {
+                        let ty = &field.ty;
+                        return Ok(quote! {
+                            {
+                                let mut node = <#ty>::describe_defaults();
+                                node.name = #ident;
+                                node
+                            }
+                        });
+                    },
+                    Some(FieldOptions { value: Some(value), .. }) => quote! { Some(#value) },
+                    Some(FieldOptions { debug: Some(debug), release: Some(release), .. }) => quote! {
+                        if cfg!(debug_assertions) { Some(#debug) } else { Some(#release) }
+                    },
+                    Some(options) => {
+                        let described = options.describe();
+                        quote! { Some(#described) }
+                    },
+                    None => quote! { None },
+                };
+
+                Ok(quote! {
+                    specified_default::DefaultsNode {
+                        name: #ident,
+                        value: ::std::option::Option::<&str>::map(#value, ::std::string::ToString::to_string),
+                        children: ::std::vec::Vec::new(),
+                    }
+                })
+            }))?;
+            (quote! { None }, children)
+        },
+        Data::Enum(ref data) => {
+            let variant = default_variant(ast, data, options)?.ident.to_string();
+            (quote! { Some(#variant) }, Vec::new())
+        },
+        _ => return Err(syn::Error::new_spanned(name, "#[derive(SpecifiedDefault)] does not support other struct variants")),
+    };
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let attrs = derived_attrs();
+    let runtime = runtime_crate();
+    Ok(msrv::scoped(&format!("DESCRIBE_{}", name), quote! {
+        #runtime
+
+        #attrs
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn describe_defaults() -> specified_default::DefaultsNode {
+                specified_default::DefaultsNode {
+                    name: #type_name,
+                    value: ::std::option::Option::<&str>::map(#value, ::std::string::ToString::to_string),
+                    children: vec![#(#children),*],
+                }
+            }
+        }
+    }))
+}
+
+/// Generates `default_with`, which starts from the specified defaults and
+/// then clones the fields marked `from_ctx` out of a context.
+fn impl_default_with(ast: &DeriveInput, options: &ContainerOptions, context: &syn::Type) -> syn::Result<TokenStream2> {
+    let fields = local_struct_fields(ast, options, "context")?;
+    let assignments = all_fields(fields.iter().map(|field| {
+        let path = match FieldOptions::from_field(field)? {
+            Some(FieldOptions { from_ctx: Some(path), .. }) => path,
+            _ => return Ok(None),
+        };
+        let members = path.value().split('.')
+            .map(|member| syn::parse_str::<syn::Member>(member.trim()))
+            .collect::<syn::Result<Vec<_>>>()
+            .map_err(|_| syn::Error::new(path.span(), "expected a field of the context, such as `tenant.id`"))?;
+
+        let ident = &field.ident;
+        Ok(Some(quote_spanned! {path.span()=>
+            result.#ident = ::std::clone::Clone::clone(&ctx #(.#members)*);
+        }))
+    }))?;
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let attrs = derived_attrs();
+    Ok(quote! {
+        #attrs
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn default_with(ctx: &#context) -> #name #ty_generics {
+                let mut result = <#name #ty_generics as ::std::default::Default>::default();
+                #(#assignments)*
+                result
+            }
+        }
+    })
+}
+
+/// Reports `from_ctx` fields of a type that has no context to take them from.
+fn check_no_context(ast: &DeriveInput) -> syn::Result<()> {
+    if let Data::Struct(syn::DataStruct { fields: Fields::Named(ref fields), .. }) = ast.data {
+        for field in &fields.named {
+            if let Ok(Some(FieldOptions { from_ctx: Some(path), .. })) = FieldOptions::from_field(field) {
+                return Err(syn::Error::new(path.span(), format!(
+                    "`from_ctx` needs a context type, given with `#[{}(context = \"...\")]`", attr::CONTAINER_ATTRIBUTE_NAME,
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether any field of `ast` is taken from the Bevy world.
+fn takes_from_world(ast: &DeriveInput) -> bool {
+    match ast.data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(ref fields), .. }) => fields.named.iter().any(|field| {
+            matches!(FieldOptions::from_field(field), Ok(Some(FieldOptions { from_world: Some(_), .. })))
+        }),
+        _ => false,
+    }
+}
+
+/// The value of a `from_world` field, evaluated with the `world` given to
+/// `FromWorld::from_world` in scope.
+#[cfg(feature = "bevy")]
+fn world_value(field: &syn::Field) -> syn::Result<Option<TokenStream2>> {
+    let expr = match FieldOptions::from_field(field)? {
+        Some(FieldOptions { from_world: Some(expr), .. }) => expr,
+        _ => return Ok(None),
+    };
+    let ty = &field.ty;
+    let value = expr.parse::<syn::Expr>()?;
+    Ok(Some(quote_spanned! {expr.span()=> { let value: #ty = #value; value } }))
+}
+
+#[cfg(not(feature = "bevy"))]
+fn world_value(field: &syn::Field) -> syn::Result<Option<TokenStream2>> {
+    match FieldOptions::from_field(field)? {
+        Some(FieldOptions { from_world: Some(expr), .. }) => Err(syn::Error::new(expr.span(), "`from_world` defaults need the `bevy` feature")),
+        _ => Ok(None),
+    }
+}
+
+/// Generates `default_from_env`, which reads each field from a prefixed,
+/// upper-cased environment variable and falls back to its specified default.
+fn impl_env(ast: &DeriveInput, options: &ContainerOptions, prefix: &str) -> syn::Result<TokenStream2> {
+    let fields = local_struct_fields(ast, options, "env_prefix")?;
+    let reads = fields.iter()
+        .map(|field| {
+            let ident = &field.ident;
+            let var = format!("{}{}", prefix, ident.as_ref().map(|ident| ident.to_string().to_uppercase()).unwrap_or_default());
+            #[cfg(not(feature = "trace-defaults"))]
+            let trace = TokenStream2::new();
+            #[cfg(feature = "trace-defaults")]
+            let trace = trace::env_field(ast, ident.as_ref().unwrap(), &var);
+
+            let read = quote! {
+                #trace
+                result.#ident = value.parse().unwrap_or_else(|err| {
+                    panic!("Failed to parse {} from {}: {:?}", value, #var, err)
+                });
+            };
+            (ident, var, read)
+        })
+        .collect::<Vec<_>>();
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+    let where_clause = where_clause(&ast.generics, from_str_bounds(ast, fields));
+
+    let with_provenance = if options.provenance {
+        let provenance = provenance_ident(ast);
+        let runtime = runtime_path();
+        let reads = reads.iter().map(|(ident, var, read)| {
+            quote! {
+                if let Ok(value) = ::std::env::var(#var) {
+                    #read
+                    provenance.#ident = #runtime::Provenance::Environment(#var);
+                }
+            }
+        });
+        quote! {
+            pub fn default_from_env_with_provenance() -> (#name #ty_generics, #provenance) {
+                let mut result = <#name #ty_generics as ::std::default::Default>::default();
+                let mut provenance = <#provenance as ::std::default::Default>::default();
+                #(#reads)*
+                (result, provenance)
+            }
+        }
+    } else {
+        TokenStream2::new()
+    };
+    let reads = reads.iter().map(|(_, var, read)| {
+        quote! {
+            if let Ok(value) = ::std::env::var(#var) {
+                #read
+            }
+        }
+    });
+
+    let attrs = derived_attrs();
+    Ok(quote! {
+        #attrs
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn default_from_env() -> #name #ty_generics {
+                let mut result = <#name #ty_generics as ::std::default::Default>::default();
+                #(#reads)*
+                result
+            }
+
+            #with_provenance
+        }
+    })
+}
+
+/// The fields of a struct that implements `Default` itself, for the
+/// constructors that start from its defaults and then replace fields.
+fn local_struct_fields<'a>(ast: &'a DeriveInput, options: &ContainerOptions, option: &str) -> syn::Result<Vec<&'a syn::Field>> {
+    match ast.data {
+        Data::Struct(syn::DataStruct { fields: Fields::Named(ref fields), .. }) if options.remote.is_none() => {
+            Ok(fields.named.iter().collect())
+        },
+        _ => Err(syn::Error::new_spanned(&ast.ident, format!("`{}` is only supported for local structs", option))),
+    }
+}
+
+/// Bounds for constructors that parse every field from a string.
+fn from_str_bounds(ast: &DeriveInput, fields: Vec<&syn::Field>) -> Vec<TokenStream2> {
+    let name = &ast.ident;
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+
+    let mut bounds = vec![quote! { #name #ty_generics: ::std::default::Default }];
+    bounds.extend(fields.into_iter()
+        .filter(|field| mentions_ty_params(&field.ty, &ast.generics))
+        .flat_map(|field| {
+            let ty = &field.ty;
+            vec![
+                quote! { #ty: ::std::str::FromStr },
+                quote! { <#ty as ::std::str::FromStr>::Err: ::std::fmt::Debug },
+            ]
+        }));
+
+    bounds
+}
+
+/// The type's own where clause extended with `bounds`.
+fn where_clause(generics: &syn::Generics, bounds: Vec<TokenStream2>) -> TokenStream2 {
+    let mut predicates = generics.where_clause.iter()
+        .flat_map(|where_clause| where_clause.predicates.iter())
+        .map(|predicate| quote! { #predicate })
+        .collect::<Vec<_>>();
+    predicates.extend(bounds);
+
+    if predicates.is_empty() {
+        quote! {}
+    } else {
+        quote! { where #(#predicates),* }
+    }
+}
+
+/// Whether `ty` refers to any of the type parameters declared in `generics`.
+fn mentions_ty_params(ty: &syn::Type, generics: &syn::Generics) -> bool {
+    fn mentions(tokens: TokenStream2, generics: &syn::Generics) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => generics.type_params().any(|param| param.ident == ident),
+            proc_macro2::TokenTree::Group(group) => mentions(group.stream(), generics),
+            _ => false,
+        })
+    }
+
+    mentions(quote! { #ty }, generics)
+}
+
+/// The where predicates a field's default expression relies on: those of
+/// its `bound` key when it has one, and otherwise any its type needs when it
+/// mentions a type parameter.
+fn field_bounds(field: &syn::Field, generics: &syn::Generics) -> syn::Result<Vec<TokenStream2>> {
+    let ty = &field.ty;
+    let options = match FieldOptions::from_field(field)? {
+        Some(FieldOptions { bound: Some(bound), .. }) => return Ok(bound.iter().map(|predicate| quote! { #predicate }).collect()),
+        _ if !mentions_ty_params(ty, generics) => return Ok(Vec::new()),
+        Some(options) => options,
+        None if unset_pointer(ty).is_some() => return Ok(Vec::new()),
+        None => return Ok(vec![quote! { #ty: ::std::default::Default }]),
+    };
+
+    if let Some(ref try_via) = options.try_via {
+        return Ok(vec![
+            quote! { #ty: ::std::convert::TryFrom<#try_via> },
+            quote! { <#ty as ::std::convert::TryFrom<#try_via>>::Error: ::std::fmt::Debug },
+        ]);
+    }
+
+    let parses_value = options.parses_value();
+    let nested = options.nested;
+    Ok(match (options.via, options.ty) {
+        (Some(mut via), _) => {
+            // The `&str` form passes the attribute's own `'static` literal.
+            if let syn::Type::Reference(ref mut reference) = via {
+                if reference.lifetime.is_none() {
+                    reference.lifetime = Some(syn::Lifetime::new("'static", reference.span()));
+                }
+            }
+            vec![quote! { #via: ::std::convert::Into<#ty> }]
+        },
+        // A type hint names the concrete type, so there is nothing to bound.
+        (None, Some(_)) => Vec::new(),
+        (None, None) if nested => vec![quote! { #ty: ::std::default::Default }],
+        (None, None) if !parses_value => Vec::new(),
+        (None, None) => {
+            let mut parsed = Vec::new();
+            for value in options.value.iter().chain(&options.debug).chain(&options.release) {
+                for ty in parsed_types(ty, &value.value()) {
+                    if mentions_ty_params(ty, generics) && !parsed.contains(&ty) {
+                        parsed.push(ty);
+                    }
+                }
+            }
+
+            parsed.into_iter()
+                .flat_map(|ty| vec![
+                    quote! { #ty: ::std::str::FromStr },
+                    quote! { <#ty as ::std::str::FromStr>::Err: ::std::fmt::Debug },
+                ])
+                .collect()
+        },
+    })
+}
+
+/// Builds an array or `Vec` of type `ty` repeating the element `repeat`
+/// gives, as in `"0xFF; 64"`, with `[elem; count]` or `vec![elem; count]`.
+/// An array's count defaults to its length, and must match it when given.
+fn repeat_value(ty: &syn::Type, repeat: &syn::LitStr, build: Build) -> syn::Result<TokenStream2> {
+    let value = repeat.value();
+    let (element, count) = match value.rsplit_once(';') {
+        Some((element, count)) => (element, Some(syn::LitStr::new(count, repeat.span()).parse::<syn::Expr>()
+            .map_err(|_| syn::Error::new(repeat.span(), format!("`{}` isn't a valid count", count.trim())))?)),
+        None => (&*value, None),
+    };
+    let literal_count = |count: &syn::Expr| match *count {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(ref int), .. }) => int.base10_parse::<usize>().ok(),
+        _ => None,
+    };
+
+    match *ty {
+        syn::Type::Array(ref array) => {
+            if let (Some(count), Some(len)) = (count.as_ref().and_then(literal_count), literal_count(&array.len)) {
+                if count != len {
+                    return Err(syn::Error::new(repeat.span(), format!(
+                        "this repeats the element {} times, but the array holds {}", count, len,
+                    )));
+                }
+            }
+
+            let element = build(&array.elem, element, repeat.span())?;
+            let len = &array.len;
+            Ok(quote! { [#element; #len] })
+        },
+        syn::Type::Paren(ref paren) => repeat_value(&paren.elem, repeat, build),
+        _ => {
+            let elem = generic_arg(ty, "Vec")
+                .ok_or_else(|| syn::Error::new(repeat.span(), "`repeat` defaults need an array or `Vec` field"))?;
+            let count = count
+                .ok_or_else(|| syn::Error::new(repeat.span(), "a repeated `Vec` element needs a count, as in `0; 64`"))?;
+
+            let element = build(elem, element, repeat.span())?;
+            Ok(quote! { vec![#element; #count] })
+        },
+    }
+}
+
+/// Whether a field's default is specified, counting malformed attributes so
+/// that their errors are still reported.
+fn has_default_attr(field: &syn::Field) -> bool {
+    !matches!(FieldOptions::from_field(field), Ok(None))
+}
+
+fn is_default_variant(variant: &syn::Variant) -> bool {
+    #[cfg(feature = "derivative")]
+    {
+        if ::derivative::is_default_variant(variant) {
+            return true;
+        }
+    }
+
+    variant.attrs.iter().any(|attr| attr.path().is_ident(ATTRIBUTE_NAME) && matches!(attr.meta, syn::Meta::Path(_)))
+}
+
+/// Builds the expression used to initialise a single struct field.
+/// Values given as strings are built with `build`.
+fn field_default(field: &syn::Field, generics: &syn::Generics, build: Build) -> syn::Result<TokenStream2> {
+    let options = match FieldOptions::from_field(field)? {
+        Some(options) => options,
+        // Raw pointers don't implement `Default`, so they start out null.
+        // `Weak` pointers start out empty with `new`, which is const
+        None => return Ok(unset_pointer(&field.ty).unwrap_or_else(|| quote! { ::std::default::Default::default() })),
+    };
+
+    // Shared values are built as the `T` of the field's `Arc<T>`
+    let ty = if options.shared { shared_ty(field, generics)? } else { &field.ty };
+
+    // Secrets build the `T` inside them
+    #[cfg(feature = "secrecy")]
+    let build_secret = build;
+    let build_repeated = build;
+    let build = |value: &syn::LitStr| match (options.via.as_ref(), options.try_via.as_ref(), options.ty.as_ref()) {
+        (Some(via), _, _) => convert_via(via, value, build),
+        (None, Some(try_via), _) => try_convert_via(try_via, ty, value, build),
+        (None, None, Some(ty)) => {
+            let value = build(ty, &value.value(), value.span())?;
+            Ok(quote! { { let value: #ty = #value; value } })
+        },
+        (None, None, None) => build(ty, &value.value(), value.span()),
+    };
+
+    let value = match (options.value.as_ref(), options.debug.as_ref(), options.release.as_ref()) {
+        (Some(value), _, _) => build(value)?,
+        (None, Some(debug), Some(release)) => {
+            let debug = build(debug)?;
+            let release = build(release)?;
+            quote! { if cfg!(debug_assertions) { #debug } else { #release } }
+        },
+        _ => match (options.variant.as_ref(), options.path.as_ref()) {
+            (Some(variant), _) => {
+                let variant = variant.parse::<syn::Expr>()?;
+                quote! { #variant }
+            },
+            (None, Some(path)) => fn_path(ty, path)?,
+            (None, None) if options.empty => quote! { <#ty>::new() },
+            (None, None) if options.repeat.is_some() => {
+                repeat_value(options.ty.as_ref().unwrap_or(ty), options.repeat.as_ref().unwrap(), build_repeated)?
+            },
+            (None, None) if options.capacity.is_some() => {
+                let capacity = options.capacity.as_ref().unwrap();
+                let expr = capacity.parse::<syn::Expr>()?;
+                let invalid = match expr {
+                    syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(ref int), .. }) => int.base10_parse::<usize>().is_err(),
+                    syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), ref expr, .. }) => matches!(**expr, syn::Expr::Lit(_)),
+                    _ => false,
+                };
+                if invalid {
+                    return Err(syn::Error::new(capacity.span(), format!("`{}` isn't a valid capacity", capacity.value())));
+                }
+                quote_spanned! {capacity.span()=> <#ty>::with_capacity(#expr) }
+            },
+            (None, None) if options.null => unset_pointer(ty)
+                .ok_or_else(|| syn::Error::new_spanned(ty, "`null` defaults need a raw pointer or `Weak` field"))?,
+            (None, None) if options.zeroed => {
+                msrv::require(36, "`zeroed`, which uses `std::mem::MaybeUninit`,", field.span())?;
+                zeroed::zeroed_value(ty)?
+            },
+            #[cfg(any(feature = "glam", feature = "nalgebra"))]
+            (None, None) if options.identity => vectors::identity(options.ty.as_ref().unwrap_or(ty))?,
+            #[cfg(not(any(feature = "glam", feature = "nalgebra")))]
+            (None, None) if options.identity => {
+                return Err(syn::Error::new_spanned(&field.ty, "`identity` defaults need the `glam` or `nalgebra` feature"));
+            },
+            (None, None) if options.expr.is_some() => {
+                let expr = &options.expr;
+                quote! { #expr }
+            },
+            (None, None) if options.base64.is_some() => {
+                let encoded = options.base64.as_ref().unwrap();
+                let ty = options.ty.as_ref().unwrap_or(ty);
+                let decoded = bytes::decode_base64(encoded)?;
+                bytes::check_len(ty, decoded.len(), encoded.span())?;
+                let decoded = syn::LitByteStr::new(&decoded, encoded.span());
+                bytes::bytes_value(ty, quote! { #decoded })
+            },
+            (None, None) if options.include_bytes.is_some() => {
+                let path = options.include_bytes.as_ref().unwrap();
+                let included = quote_spanned! {path.span()=> include_bytes!(#path) };
+                bytes::bytes_value(options.ty.as_ref().unwrap_or(ty), included)
+            },
+            (None, None) if options.include_str.is_some() => {
+                let path = options.include_str.as_ref().unwrap();
+                let included = quote_spanned! {path.span()=> include_str!(#path) };
+                match *options.ty.as_ref().unwrap_or(ty) {
+                    syn::Type::Reference(_) => included,
+                    _ => quote! { ::std::convert::From::from(#included) },
+                }
+            },
+            #[cfg(feature = "regex")]
+            (None, None) if options.regex.is_some() => regex::regex_value(options.ty.as_ref().unwrap_or(ty), options.regex.as_ref().unwrap())?,
+            #[cfg(not(feature = "regex"))]
+            (None, None) if options.regex.is_some() => {
+                return Err(syn::Error::new_spanned(&field.ty, "`regex` defaults need the `regex` feature"));
+            },
+            #[cfg(feature = "bitflags")]
+            (None, None) if options.flags.is_some() => bitflags::flags_value(options.ty.as_ref().unwrap_or(ty), options.flags.as_ref().unwrap())?,
+            #[cfg(not(feature = "bitflags"))]
+            (None, None) if options.flags.is_some() => {
+                return Err(syn::Error::new_spanned(&field.ty, "`flags` defaults need the `bitflags` feature"));
+            },
+            #[cfg(feature = "secrecy")]
+            (None, None) if options.secret.is_some() || options.secret_env.is_some() => secrecy::secret_value(ty, &options, build_secret)?,
+            #[cfg(not(feature = "secrecy"))]
+            (None, None) if options.secret.is_some() || options.secret_env.is_some() => {
+                return Err(syn::Error::new_spanned(&field.ty, "`secret` defaults need the `secrecy` feature"));
+            },
+            #[cfg(feature = "system-defaults")]
+            (None, None) if options.system.is_some() => {
+                if let Some(System::NumCpus) = options.system {
+                    msrv::require(59, "`num_cpus`, which uses `std::thread::available_parallelism`,", field.span())?;
+                }
+                options.system.unwrap().value_for(options.ty.as_ref().unwrap_or(ty))
+            },
+            #[cfg(not(feature = "system-defaults"))]
+            (None, None) if options.system.is_some() => {
+                return Err(syn::Error::new_spanned(&field.ty, format!(
+                    "`{}` defaults need the `system-defaults` feature of `specified_default`", options.system.unwrap().key(),
+                )));
+            },
+            (None, None) if options.serialized.is_some() => {
+                let (ref format, ref snippet) = *options.serialized.as_ref().unwrap();
+                match format.check(snippet)? {
+                    Some(scalar) => build(&syn::LitStr::new(&scalar, snippet.span()))?,
+                    None => format.value_for(options.ty.as_ref().unwrap_or(ty), snippet),
+                }
+            },
+            (None, None) if options.from_ctx.is_some() || options.from_world.is_some() || options.group.is_some() || options.nested => {
+                quote! { ::std::default::Default::default() }
+            },
+            (None, None) => unreachable!("field options always have a value"),
+        },
+    };
+
+    if !options.shared {
+        return Ok(value);
+    }
+    msrv::require(70, "`shared`, which keeps its value in a `std::sync::OnceLock`,", field.span())?;
+
+    let field_ty = &field.ty;
+    Ok(quote! {
+        {
+            static SHARED: ::std::sync::OnceLock<#field_ty> = ::std::sync::OnceLock::new();
+            ::std::clone::Clone::clone(SHARED.get_or_init(|| ::std::sync::Arc::new(#value)))
+        }
+    })
+}
+
+/// A null pointer for raw pointer types, or a `Weak` pointing to nothing,
+/// made with the const `Weak::new`.
+fn unset_pointer(ty: &syn::Type) -> Option<TokenStream2> {
+    match *ty {
+        syn::Type::Ptr(ref ptr) if ptr.mutability.is_some() => Some(quote! { ::std::ptr::null_mut() }),
+        syn::Type::Ptr(_) => Some(quote! { ::std::ptr::null() }),
+        syn::Type::Paren(ref paren) => unset_pointer(&paren.elem),
+        _ if generic_arg(ty, "Weak").is_some() => Some(quote! { <#ty>::new() }),
+        _ => None,
+    }
+}
+
+/// Names the function given by `path`. Function items only coerce to
+/// pointers on their own, so an `Option` of one needs an explicit cast.
+fn fn_path(ty: &syn::Type, path: &syn::LitStr) -> syn::Result<TokenStream2> {
+    let path = path.parse::<syn::Path>()?;
+    Ok(match generic_arg(ty, "Option") {
+        Some(inner) => quote! { ::std::option::Option::Some(#path as #inner) },
+        None => quote! { #path },
+    })
+}
+
+/// The `T` of a shared field's `Arc<T>`. The value lives in a `static`, so
+/// it can't depend on the type's generic parameters.
+fn shared_ty<'a>(field: &'a syn::Field, generics: &syn::Generics) -> syn::Result<&'a syn::Type> {
+    let ty = generic_arg(&field.ty, "Arc")
+        .ok_or_else(|| syn::Error::new_spanned(&field.ty, "`shared` defaults need a field of type `Arc<T>`"))?;
+
+    if mentions_ty_params(ty, generics) {
+        return Err(syn::Error::new_spanned(&field.ty, "`shared` defaults can't depend on type parameters"));
+    }
+
+    Ok(ty)
+}
+
+/// Creates `value` as the intermediate type `via`, then converts it into the
+/// field's type with `Into`. A `via` of `&str` skips parsing entirely.
+fn convert_via(via: &syn::Type, value: &syn::LitStr, build: Build) -> syn::Result<TokenStream2> {
+    let span = value.span();
+    match *via {
+        syn::Type::Reference(ref reference) if is_str(&reference.elem) => {
+            Ok(quote_spanned! { span=> ::std::convert::Into::into(#value) })
+        },
+        _ => {
+            let parsed = build(via, &value.value(), span)?;
+            Ok(quote_spanned! { span=> ::std::convert::Into::into({ let via: #via = #parsed; via }) })
+        },
+    }
+}
+
+/// Makes sure that a `try_via` conversion is known not to fail, and that
+/// nothing is deserialized at runtime, for types that are built with
+/// `no_panic`.
+fn check_conversion(field: &syn::Field) -> syn::Result<()> {
+    let options = match FieldOptions::from_field(field)? {
+        Some(options) => options,
+        None => return Ok(()),
+    };
+    if let Some((ref format, ref snippet)) = options.serialized {
+        if format.check(snippet)?.is_none() {
+            return Err(syn::Error::new(snippet.span(), format!("this `{}` default is deserialized at runtime, which can panic", format.key())));
+        }
+    }
+    if options.system == Some(System::NumCpus) && !value::is_path(options.ty.as_ref().unwrap_or(&field.ty), "usize") {
+        return Err(syn::Error::new_spanned(&field.ty, "the number of CPUs may not fit in this type, which can panic"));
+    }
+    let try_via = match options.try_via {
+        Some(ref try_via) => try_via,
+        None => return Ok(()),
+    };
+
+    for value in options.value.iter().chain(&options.debug).chain(&options.release) {
+        let checked = value::integer_fits(try_via, &value.value()).is_some()
+            && value::integer_fits(&field.ty, &value.value()).is_some();
+        if !checked {
+            return Err(syn::Error::new(value.span(), "this `try_via` conversion can't be checked at compile time, so it can panic"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates `value` as the intermediate type `try_via`, then converts it into
+/// the field's type `ty` with `TryFrom`. Integer literals converted between
+/// integer types are checked here, so that a value out of the field's range
+/// is a compile error rather than a panic.
+fn try_convert_via(try_via: &syn::Type, ty: &syn::Type, value: &syn::LitStr, build: Build) -> syn::Result<TokenStream2> {
+    let span = value.span();
+    msrv::require(34, "`try_via`, which uses `TryFrom`,", span)?;
+    let parsed = build(try_via, &value.value(), span)?;
+
+    if value::integer_fits(try_via, &value.value()).unwrap_or(true) && !value::integer_fits(ty, &value.value()).unwrap_or(true) {
+        return Err(syn::Error::new(span, format!("`{}` is out of range for the field's type", value.value())));
+    }
+
+    let value = value.value();
+    Ok(quote_spanned! { span=>
+        <#ty as ::std::convert::TryFrom<#try_via>>::try_from({ let via: #try_via = #parsed; via })
+            .expect(&format!("Failed to convert {}", #value))
+    })
+}
+
+fn is_str(ty: &syn::Type) -> bool {
+    match *ty {
+        syn::Type::Path(ref path) => path.qself.is_none() && path.path.is_ident("str"),
+        _ => false,
+    }
+}
+
+/// The path written out without any spacing, such as `other::Window`.
+#[cfg(feature = "inventory")]
+fn path_name(path: &syn::Path) -> String {
+    path.segments.iter().map(|segment| segment.ident.to_string()).collect::<Vec<_>>().join("::")
 }
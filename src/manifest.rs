@@ -0,0 +1,107 @@
+//! `#[specified_defaults(file = "...")]` on a module, which gives the fields
+//! of the structs inside it defaults from a TOML manifest.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use proc_macro2::TokenStream;
+use syn;
+
+use attr::ATTRIBUTE_NAME;
+
+/// Adds a `#[default]` to each field of the structs in `module` that the
+/// manifest named by `args` has a value for, under a `[Struct]` table or as
+/// a `Struct.field` key. The path is relative to the crate's manifest
+/// directory, as proc macros can't know the file they were called from.
+pub fn apply(args: TokenStream, mut module: syn::ItemMod) -> syn::Result<TokenStream> {
+    let mut file: Option<syn::LitStr> = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("file") {
+            file = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unknown key, expected `file`"))
+        }
+    });
+    syn::parse::Parser::parse2(parser, args)?;
+    let file = file.ok_or_else(|| syn::Error::new_spanned(&module.ident, "expected `#[specified_defaults(file = \"...\")]`"))?;
+
+    let items = match module.content {
+        Some((_, ref mut items)) => items,
+        None => return Err(syn::Error::new_spanned(&module, "`#[specified_defaults]` is only supported on modules written inline")),
+    };
+
+    let dir = env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_owned());
+    let path = Path::new(&dir).join(file.value());
+    let manifest = fs::read_to_string(&path)
+        .map_err(|err| syn::Error::new(file.span(), format!("couldn't read `{}`: {}", path.display(), err)))?
+        .parse::<::toml::Table>()
+        .map_err(|err| syn::Error::new(file.span(), format!("invalid TOML in `{}`: {}", file.value(), err)))?;
+
+    for (name, defaults) in &manifest {
+        let defaults = match *defaults {
+            ::toml::Value::Table(ref defaults) => defaults,
+            _ => return Err(syn::Error::new(file.span(), format!("`{}` in `{}` should be a table of field defaults", name, file.value()))),
+        };
+        let strukt = items.iter_mut()
+            .filter_map(|item| match *item {
+                syn::Item::Struct(ref mut strukt) if strukt.ident == name => Some(strukt),
+                _ => None,
+            })
+            .next()
+            .ok_or_else(|| syn::Error::new(file.span(), format!("`{}` sets defaults for `{}`, which isn't a struct in this module", file.value(), name)))?;
+        apply_defaults(strukt, defaults, &file)?;
+    }
+
+    // Rebuilds whenever the manifest changes, as the compiler tracks
+    // included files
+    let path = path.to_string_lossy().into_owned();
+    items.push(parse_quote! {
+        const _: &str = include_str!(#path);
+    });
+
+    Ok(quote! { #module })
+}
+
+fn apply_defaults(strukt: &mut syn::ItemStruct, defaults: &::toml::Table, file: &syn::LitStr) -> syn::Result<()> {
+    let name = strukt.ident.to_string();
+    if !derives_defaults(&strukt.attrs) {
+        return Err(syn::Error::new_spanned(&strukt.ident, format!("`{}` has defaults in `{}`, but doesn't derive `SpecifiedDefault`", name, file.value())));
+    }
+
+    for (key, value) in defaults {
+        let field = strukt.fields.iter_mut()
+            .find(|field| field.ident.as_ref().is_some_and(|ident| ident == key))
+            .ok_or_else(|| syn::Error::new(file.span(), format!("`{}` sets `{}.{}`, which isn't a field of `{}`", file.value(), name, key, name)))?;
+        if field.attrs.iter().any(|attr| attr.path().is_ident(ATTRIBUTE_NAME)) {
+            return Err(syn::Error::new_spanned(
+                &field.ident,
+                format!("`{}.{}` has a default in `{}` as well as a `#[default]`", name, key, file.value()),
+            ));
+        }
+
+        let value = match *value {
+            ::toml::Value::String(ref value) => value.clone(),
+            ::toml::Value::Table(_) => {
+                return Err(syn::Error::new(file.span(), format!("`{}.{}` in `{}` is a table, which can't be a field default", name, key, file.value())));
+            },
+            ref value => value.to_string(),
+        };
+        field.attrs.push(parse_quote! { #[default = #value] });
+    }
+    Ok(())
+}
+
+/// Whether `attrs` derive `SpecifiedDefault`, or use its attribute form.
+fn derives_defaults(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        let is_last = |path: &syn::Path| path.segments.last().is_some_and(|segment| segment.ident == "SpecifiedDefault" || segment.ident == "specified_default");
+        if is_last(attr.path()) {
+            return true;
+        }
+        attr.path().is_ident("derive") && attr
+            .parse_args_with(syn::punctuated::Punctuated::<syn::Path, Token![,]>::parse_terminated)
+            .is_ok_and(|derives| derives.iter().any(is_last))
+    })
+}
@@ -0,0 +1,75 @@
+//! The `msrv` key, which holds generated code to what the oldest compiler a
+//! type must build on supports.
+
+use std::cell::RefCell;
+
+use proc_macro2::{Span, TokenStream};
+use syn;
+
+/// A Rust version such as `1.36`, given by the `msrv` key.
+#[derive(Clone, Copy)]
+pub struct Msrv {
+    minor: u32,
+}
+
+impl Msrv {
+    /// Parses a `1.minor` or `1.minor.patch` version.
+    pub fn parse(version: &syn::LitStr) -> syn::Result<Msrv> {
+        let value = version.value();
+        let mut parts = value.trim().split('.');
+        let minor = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some("1"), Some(minor), patch, None) if patch.is_none_or(|patch| patch.parse::<u32>().is_ok()) => minor.parse().ok(),
+            _ => None,
+        };
+
+        minor
+            .map(|minor| Msrv { minor })
+            .ok_or_else(|| syn::Error::new(version.span(), format!("`{}` isn't a Rust version such as `1.36`", value)))
+    }
+}
+
+thread_local! {
+    /// The `msrv` key of the type being derived, which the code generating
+    /// each default checks against.
+    static MSRV: RefCell<Option<Msrv>> = const { RefCell::new(None) };
+}
+
+/// Sets the version the type being derived must build on.
+pub fn set(msrv: Option<Msrv>) {
+    MSRV.with(|current| *current.borrow_mut() = msrv);
+}
+
+/// Fails at `span` when `what` needs Rust `1.minor`, which is newer than the
+/// type's `msrv`.
+pub fn require(minor: u32, what: &str, span: Span) -> syn::Result<()> {
+    match MSRV.with(|msrv| *msrv.borrow()) {
+        Some(msrv) if msrv.minor < minor => Err(syn::Error::new(span, format!(
+            "{} needs Rust 1.{}, but the `msrv` is 1.{}", what, minor, msrv.minor,
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Whether the generated code may use what Rust `1.minor` brought, which
+/// it always can without an `msrv`.
+pub fn at_least(minor: u32) -> bool {
+    MSRV.with(|msrv| msrv.borrow().is_none_or(|msrv| msrv.minor >= minor))
+}
+
+/// Wraps `items` in a `const _: () = { ... };` block, which keeps the names
+/// they bring in out of the surrounding scope. Compilers before 1.37 need
+/// the constant to be named, so it is named after `unique` on those.
+pub fn scoped(unique: &str, items: TokenStream) -> TokenStream {
+    if at_least(37) {
+        return quote! { const _: () = { #items }; };
+    }
+
+    let unique = unique.chars().map(|c| if c.is_alphanumeric() { c } else { '_' }).collect::<String>();
+    let name = syn::Ident::new(&format!("__SPECIFIED_DEFAULT_{}", unique), Span::call_site());
+    // Recent compilers flag impls in a named constant inside a function
+    // body, where an anonymous one is allowed
+    quote! {
+        #[allow(unknown_lints, non_upper_case_globals, non_local_definitions)]
+        const #name: () = { #items };
+    }
+}
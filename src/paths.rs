@@ -0,0 +1,30 @@
+//! `camino` paths, which are UTF-8 like the strings they're given as.
+
+use proc_macro2::{Span, TokenStream};
+use syn;
+
+/// Builds a `Utf8PathBuf` from a value such as `"target/debug"` with
+/// `From<&str>`, or borrows it as a `&'static Utf8Path` with
+/// `Utf8Path::new`. Neither can fail, so there is nothing to parse.
+///
+/// Returns `None` when the field is neither.
+pub fn path_value(ty: &syn::Type, value: &str, span: Span) -> Option<TokenStream> {
+    let literal = syn::LitStr::new(value, span);
+    match *ty {
+        syn::Type::Reference(ref reference) if is_camino(&reference.elem, "Utf8Path") => {
+            let path = &reference.elem;
+            Some(quote! { <#path>::new(#literal) })
+        },
+        _ if is_camino(ty, "Utf8PathBuf") => Some(quote! { <#ty>::from(#literal) }),
+        _ => None,
+    }
+}
+
+fn is_camino(ty: &syn::Type, name: &str) -> bool {
+    match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path.segments.last().is_some_and(|segment| {
+            segment.ident == name && segment.arguments.is_empty()
+        }),
+        _ => false,
+    }
+}
@@ -0,0 +1,117 @@
+//! The `redundant_defaults` key, which reports specified defaults that are
+//! the same as their type's own `Default`.
+
+use proc_macro2::{Span, TokenStream};
+use syn;
+use syn::spanned::Spanned;
+
+use attr::{ATTRIBUTE_NAME, FieldOptions};
+use value::{INTEGERS, generic_arg, integer_literal, is_path};
+
+/// How redundant defaults are reported.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Level {
+    Warn,
+    Deny,
+}
+
+impl Level {
+    pub fn parse(level: &syn::LitStr) -> syn::Result<Level> {
+        match &*level.value() {
+            "warn" => Ok(Level::Warn),
+            "deny" => Ok(Level::Deny),
+            other => Err(syn::Error::new(level.span(), format!("unknown level `{}`, expected `warn` or `deny`", other))),
+        }
+    }
+}
+
+/// Reports each field of `fields`, those of the type `name`, whose `value`
+/// or `empty` default builds what its type's `Default` would. Warnings are
+/// uses of a deprecated constant, the only way to warn from a derive on
+/// stable, while denied defaults are errors.
+pub fn check<'a, I>(name: &syn::Ident, fields: I, level: Level) -> syn::Result<TokenStream>
+    where I: IntoIterator<Item = &'a syn::Field>
+{
+    let mut warnings = Vec::new();
+    let mut errors: Option<syn::Error> = None;
+
+    for field in fields {
+        let options = match FieldOptions::from_field(field)? {
+            Some(options) => options,
+            None => continue,
+        };
+        let ty = options.ty.as_ref().unwrap_or(&field.ty);
+        if options.via.is_some() || options.try_via.is_some() || options.shared {
+            continue;
+        }
+
+        let attr = field.attrs.iter().find(|attr| attr.path().is_ident(ATTRIBUTE_NAME));
+        let (tokens, default) = match (options.value.as_ref(), options.empty) {
+            (Some(value), _) if is_default_value(ty, &value.value()) => (quote!(#value), format!("`{}`", value.value())),
+            (None, true) if is_std_container(ty) => (quote!(#attr), "`empty`".to_owned()),
+            _ => continue,
+        };
+
+        let message = format!(
+            "{} is already the default of `{}`, so the `#[default]` can be removed",
+            default, quote!(#ty).to_string().replace(' ', ""),
+        );
+        match level {
+            Level::Warn => warnings.push(warning(&format!("REDUNDANT_{}_{}", name, warnings.len()), tokens.span(), &message)),
+            Level::Deny => {
+                let err = syn::Error::new_spanned(tokens, message);
+                match errors {
+                    Some(ref mut errors) => errors.combine(err),
+                    None => errors = Some(err),
+                }
+            },
+        }
+    }
+
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(quote! { #(#warnings)* }),
+    }
+}
+
+/// Whether `value` builds the same `ty` as `Default::default`.
+fn is_default_value(ty: &syn::Type, value: &str) -> bool {
+    let value = value.trim();
+    if INTEGERS.iter().any(|integer| is_path(ty, integer)) {
+        return matches!(integer_literal(value), Some((_, Some(0), _)));
+    }
+    if is_path(ty, "f32") || is_path(ty, "f64") {
+        // `-0.0` is a different value, whose sign is kept
+        return !value.starts_with('-') && value.parse::<f64>() == Ok(0.0);
+    }
+    if is_path(ty, "bool") {
+        return value == "false";
+    }
+    if is_path(ty, "String") {
+        return value.is_empty();
+    }
+    if generic_arg(ty, "Vec").is_some() {
+        return value.is_empty() || value == "[]";
+    }
+    match *ty {
+        syn::Type::Paren(ref paren) => is_default_value(&paren.elem, value),
+        syn::Type::Reference(ref reference) if is_path(&reference.elem, "str") => value.is_empty(),
+        _ => false,
+    }
+}
+
+/// Whether `ty` is a standard container, whose `new` is its `Default`.
+fn is_std_container(ty: &syn::Type) -> bool {
+    is_path(ty, "String") || ["Vec", "VecDeque", "HashMap", "HashSet", "BTreeMap", "BTreeSet", "BinaryHeap"]
+        .iter()
+        .any(|name| generic_arg(ty, name).is_some())
+}
+
+fn warning(unique: &str, span: Span, message: &str) -> TokenStream {
+    ::msrv::scoped(unique, quote_spanned! {span=>
+        #[deprecated(note = #message)]
+        #[allow(non_upper_case_globals)]
+        const redundant_default: () = ();
+        redundant_default
+    })
+}
@@ -0,0 +1,23 @@
+//! `regex::Regex` defaults, checked while compiling and built lazily.
+
+use proc_macro2::TokenStream;
+use syn;
+
+/// Checks `pattern` and builds it into a regex the first time the default
+/// is built. Every later default clones that regex, which shares its
+/// compiled program.
+pub fn regex_value(ty: &syn::Type, pattern: &syn::LitStr) -> syn::Result<TokenStream> {
+    if let Err(err) = ::regex_syntax::Parser::new().parse(&pattern.value()) {
+        return Err(syn::Error::new(pattern.span(), format!("invalid regex: {}", err)));
+    }
+    ::msrv::require(70, "`regex` defaults, which keep their regex in a `std::sync::OnceLock`,", pattern.span())?;
+
+    Ok(quote_spanned! {pattern.span()=>
+        {
+            static REGEX: ::std::sync::OnceLock<#ty> = ::std::sync::OnceLock::new();
+            ::std::clone::Clone::clone(REGEX.get_or_init(|| {
+                <#ty>::new(#pattern).expect("regexes are checked while compiling")
+            }))
+        }
+    })
+}
@@ -0,0 +1,158 @@
+//! `#[specified_default]` as an attribute macro, which implements the
+//! defaults like the derive and then rewrites the item itself.
+
+use proc_macro2::{TokenStream, TokenTree};
+use syn;
+use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
+
+use attr::{self, ATTRIBUTE_NAME, CONTAINER_ATTRIBUTE_NAME, ContainerOptions};
+use value::{Build, unparsed_value_for, value_for};
+
+/// Implements the defaults of `ast` as `#[derive(SpecifiedDefault)]` would,
+/// with `args` taken as the container's options, and returns the item with
+/// its `#[default]` and `#[specified_default]` attributes removed. The
+/// `serde` option also gives every field with a default a matching
+/// `#[serde(default = "...")]`.
+pub fn rewrite(args: TokenStream, mut ast: syn::DeriveInput) -> syn::Result<TokenStream> {
+    check_cfg_attrs(&ast)?;
+
+    let mut serde = None;
+    let options = syn::parse::Parser::parse2(Punctuated::<syn::Meta, Token![,]>::parse_terminated, args)?
+        .into_iter()
+        .filter(|meta| match *meta {
+            syn::Meta::Path(ref path) if path.is_ident("serde") => {
+                serde = Some(path.clone());
+                false
+            },
+            _ => true,
+        })
+        .collect::<Punctuated<syn::Meta, Token![,]>>();
+    if !options.is_empty() {
+        ast.attrs.push(parse_quote! { #[specified_default(#options)] });
+    }
+
+    let mut items = vec![::impl_specified_defaults(&ast)?];
+    if let Some(serde) = serde {
+        items.push(serde_defaults(&serde, &mut ast)?);
+    }
+
+    strip_helpers(&mut ast);
+    Ok(quote! {
+        #ast
+        #(#items)*
+    })
+}
+
+/// Rejects helper attributes behind `cfg_attr`. The compiler only expands
+/// those once the attribute macro has run, so they'd be left unexpanded and
+/// unknown without the derive there to claim them.
+fn check_cfg_attrs(ast: &syn::DeriveInput) -> syn::Result<()> {
+    let fields: Vec<&syn::Field> = match ast.data {
+        syn::Data::Struct(ref data) => data.fields.iter().collect(),
+        syn::Data::Enum(ref data) => data.variants.iter().flat_map(|variant| &variant.fields).collect(),
+        syn::Data::Union(ref data) => data.fields.named.iter().collect(),
+    };
+    let variants = match ast.data {
+        syn::Data::Enum(ref data) => data.variants.iter().collect(),
+        _ => Vec::new(),
+    };
+
+    let attrs = ast.attrs.iter()
+        .chain(variants.into_iter().flat_map(|variant| &variant.attrs))
+        .chain(fields.into_iter().flat_map(|field| &field.attrs));
+    for attr in attrs.filter(|attr| attr.path().is_ident("cfg_attr")) {
+        let tokens = match attr.meta.require_list() {
+            Ok(list) => list.tokens.clone(),
+            Err(_) => continue,
+        };
+        let helper = tokens.into_iter()
+            .skip_while(|token| !matches!(*token, TokenTree::Punct(ref punct) if punct.as_char() == ','))
+            .any(|token| matches!(token, TokenTree::Ident(ref ident) if ident == ATTRIBUTE_NAME || ident == CONTAINER_ATTRIBUTE_NAME));
+        if helper {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "`#[specified_default]` can't see attributes behind `cfg_attr`; use `#[derive(SpecifiedDefault)]` for conditional defaults",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Builds a hidden function returning each field's default and points the
+/// field's `#[serde(default)]` at it, so that fields missing when
+/// deserializing get the same value `default` gives them. Fields that
+/// already name a serde default keep it.
+fn serde_defaults(serde: &syn::Path, ast: &mut syn::DeriveInput) -> syn::Result<TokenStream> {
+    let options = ContainerOptions::from_attrs(&ast.attrs)?;
+    let documented = if options.from_docs { attr::doc_defaults(ast)? } else { ast.clone() };
+    let build: Build = if options.no_panic { unparsed_value_for } else { value_for };
+
+    let name = ast.ident.clone();
+    let documented_generics = documented.generics.clone();
+    let fields = match (&mut ast.data, &documented.data) {
+        (&mut syn::Data::Struct(syn::DataStruct { fields: syn::Fields::Named(ref mut fields), .. }),
+         &syn::Data::Struct(syn::DataStruct { fields: syn::Fields::Named(ref documented), .. })) => {
+            fields.named.iter_mut().zip(&documented.named)
+        },
+        _ => return Err(syn::Error::new_spanned(serde, "`serde` defaults are only supported for structs with named fields")),
+    };
+
+    let mut bounds = Vec::new();
+    let functions = ::all_fields(fields.filter(|&(_, documented)| ::has_default_attr(documented)).map(|(field, documented)| {
+        let ident = field.ident.as_ref().expect("named fields have idents");
+        let function = format_ident!("__specified_default_{}", ident.unraw());
+        let path = format!("{}::{}", name, function);
+        let ty = &field.ty;
+        let value = ::field_default(documented, &documented_generics, build)?;
+        bounds.extend(::field_bounds(documented, &documented_generics)?);
+
+        if !has_serde_default(field) {
+            field.attrs.push(parse_quote! { #[serde(default = #path)] });
+        }
+
+        Ok(quote! {
+            #[doc(hidden)]
+            fn #function() -> #ty {
+                #value
+            }
+        })
+    }))?;
+
+    let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+    let where_clause = ::where_clause(&ast.generics, bounds);
+    let attrs = ::derived_attrs();
+    Ok(quote! {
+        #attrs
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#functions)*
+        }
+    })
+}
+
+fn has_serde_default(field: &syn::Field) -> bool {
+    field.attrs.iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .filter_map(|attr| attr.meta.require_list().ok())
+        .any(|list| list.tokens.clone().into_iter().any(|token| matches!(token, TokenTree::Ident(ref ident) if ident == "default")))
+}
+
+/// Removes the attributes only the derive understands, which would otherwise
+/// be unknown to the compiler once the item is emitted without it.
+fn strip_helpers(ast: &mut syn::DeriveInput) {
+    ast.attrs.retain(|attr| !attr.path().is_ident(CONTAINER_ATTRIBUTE_NAME));
+
+    let fields: Vec<&mut syn::Field> = match ast.data {
+        syn::Data::Struct(ref mut data) => data.fields.iter_mut().collect(),
+        syn::Data::Enum(ref mut data) => data.variants.iter_mut()
+            .flat_map(|variant| {
+                variant.attrs.retain(|attr| !attr.path().is_ident(ATTRIBUTE_NAME));
+                variant.fields.iter_mut()
+            })
+            .collect(),
+        syn::Data::Union(ref mut data) => data.fields.named.iter_mut().collect(),
+    };
+    for field in fields {
+        field.attrs.retain(|attr| !attr.path().is_ident(ATTRIBUTE_NAME));
+    }
+}
@@ -0,0 +1,43 @@
+//! Defaults for `secrecy` secrets, which are wrapped as they are built so
+//! that they never show up in `Debug` output or the registry.
+
+use proc_macro2::TokenStream;
+use syn;
+
+use attr::FieldOptions;
+use value::{Build, generic_arg};
+
+/// Builds a `Secret<T>` field from its `secret` value, or from the
+/// environment variable named by `secret_env` when that is set.
+pub fn secret_value(ty: &syn::Type, options: &FieldOptions, build: Build) -> syn::Result<TokenStream> {
+    let string = parse_quote!(::std::string::String);
+    let inner = match generic_arg(ty, "Secret") {
+        Some(inner) => inner,
+        None if is_secret_string(ty) => &string,
+        None => return Err(syn::Error::new_spanned(ty, "`secret` defaults need a field of type `Secret<T>` or `SecretString`")),
+    };
+
+    let fallback = match options.secret {
+        Some(ref secret) => build(inner, &secret.value(), secret.span())?,
+        None => quote! { ::std::default::Default::default() },
+    };
+    let value = match options.secret_env {
+        // The message names the variable but not its value.
+        Some(ref var) => quote_spanned! {var.span()=>
+            match ::std::env::var(#var) {
+                ::std::result::Result::Ok(value) => value.parse().unwrap_or_else(|_| panic!("Failed to parse ${}", #var)),
+                ::std::result::Result::Err(_) => #fallback,
+            }
+        },
+        None => fallback,
+    };
+
+    Ok(quote! { <#ty>::new(#value) })
+}
+
+fn is_secret_string(ty: &syn::Type) -> bool {
+    match *ty {
+        syn::Type::Path(ref path) => path.qself.is_none() && path.path.segments.last().is_some_and(|segment| segment.ident == "SecretString"),
+        _ => false,
+    }
+}
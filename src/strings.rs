@@ -0,0 +1,29 @@
+//! String types that can hold short strings inline, built from `'static`
+//! literals without allocating.
+
+use proc_macro2::{Span, TokenStream};
+use syn;
+
+/// Builds a `CompactString` with the const `CompactString::const_new` or a
+/// `SmolStr` with the const `SmolStr::new_static`. Either stores a short
+/// literal inline and borrows a longer one, so neither allocates.
+///
+/// Returns `None` when the field is neither, or its feature isn't enabled.
+pub fn string_value(ty: &syn::Type, value: &str, span: Span) -> Option<TokenStream> {
+    let segment = match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path.segments.last()?,
+        _ => return None,
+    };
+    if !segment.arguments.is_empty() {
+        return None;
+    }
+
+    let literal = syn::LitStr::new(value, span);
+    match &*segment.ident.to_string() {
+        #[cfg(feature = "compact_str")]
+        "CompactString" => Some(quote! { <#ty>::const_new(#literal) }),
+        #[cfg(feature = "smol_str")]
+        "SmolStr" => Some(quote! { <#ty>::new_static(#literal) }),
+        _ => None,
+    }
+}
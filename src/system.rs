@@ -0,0 +1,69 @@
+//! Defaults read from the machine the program runs on, such as
+//! `#[default(num_cpus)]`.
+
+#[cfg(feature = "system-defaults")]
+use proc_macro2::TokenStream;
+use syn;
+
+/// A property of the machine a default can be read from.
+#[derive(Clone, Copy, PartialEq)]
+pub enum System {
+    NumCpus,
+    Hostname,
+    TempDir,
+}
+
+impl System {
+    /// The property named by a `#[default]` key.
+    pub fn from_key(path: &syn::Path) -> Option<System> {
+        if path.is_ident("num_cpus") {
+            Some(System::NumCpus)
+        } else if path.is_ident("hostname") {
+            Some(System::Hostname)
+        } else if path.is_ident("temp_dir") {
+            Some(System::TempDir)
+        } else {
+            None
+        }
+    }
+
+    /// The `#[default]` key the property is given with.
+    pub fn key(&self) -> &'static str {
+        match *self {
+            System::NumCpus => "num_cpus",
+            System::Hostname => "hostname",
+            System::TempDir => "temp_dir",
+        }
+    }
+
+    /// Reads the property as a `ty` when the default is built. The number of
+    /// CPUs falls back to 1 and the hostname to `localhost` when they can't
+    /// be read.
+    #[cfg(feature = "system-defaults")]
+    pub fn value_for(&self, ty: &syn::Type) -> TokenStream {
+        match *self {
+            System::NumCpus => quote! {
+                {
+                    let cpus = ::std::thread::available_parallelism().map_or(1, ::std::num::NonZeroUsize::get);
+                    <#ty as ::std::convert::TryFrom<usize>>::try_from(cpus)
+                        .unwrap_or_else(|_| panic!("{} CPUs don't fit in a {}", cpus, stringify!(#ty)))
+                }
+            },
+            System::Hostname => {
+                let runtime = ::runtime_crate();
+                quote! {
+                    {
+                        #runtime
+                        let hostname = specified_default::hostname::get().ok()
+                            .and_then(|hostname| hostname.into_string().ok())
+                            .unwrap_or_else(|| ::std::string::String::from("localhost"));
+                        <#ty as ::std::convert::From<::std::string::String>>::from(hostname)
+                    }
+                }
+            },
+            System::TempDir => quote! {
+                <#ty as ::std::convert::From<::std::path::PathBuf>>::from(::std::env::temp_dir())
+            },
+        }
+    }
+}
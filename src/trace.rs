@@ -0,0 +1,57 @@
+//! `log::trace!` calls noting where defaults come from, for working out
+//! which configuration layer produced a value.
+
+use proc_macro2::TokenStream;
+use syn;
+
+use attr::{ContainerOptions, FieldOptions};
+
+/// Logs that `default` is building the type, naming any fields included
+/// from files and the environment variable an enum's variant is read from.
+pub fn default_built(ast: &syn::DeriveInput, options: &ContainerOptions) -> syn::Result<TokenStream> {
+    let type_name = ast.ident.to_string();
+
+    if let Some(ref env) = options.env {
+        return Ok(trace(quote! {
+            "building the default {} from ${} = {:?}",
+            concat!(module_path!(), "::", #type_name), #env, ::std::env::var(#env).ok()
+        }));
+    }
+
+    let mut sources = Vec::new();
+    if let syn::Data::Struct(syn::DataStruct { fields: syn::Fields::Named(ref fields), .. }) = ast.data {
+        for field in &fields.named {
+            let file = match FieldOptions::from_field(field)? {
+                Some(FieldOptions { include_bytes: Some(path), .. }) |
+                Some(FieldOptions { include_str: Some(path), .. }) => path.value(),
+                _ => continue,
+            };
+            let ident = field.ident.as_ref().map(|ident| ident.to_string()).unwrap_or_default();
+            sources.push(format!("; `{}` is included from {}", ident, file));
+        }
+    }
+
+    let sources = sources.concat();
+    Ok(trace(quote! {
+        "building the default {}{}", concat!(module_path!(), "::", #type_name), #sources
+    }))
+}
+
+/// Logs that `default_from_env` took a field from the variable `var`.
+pub fn env_field(ast: &syn::DeriveInput, field: &syn::Ident, var: &str) -> TokenStream {
+    let type_name = ast.ident.to_string();
+    let field = field.to_string();
+    trace(quote! {
+        "taking {}.{} from ${}", concat!(module_path!(), "::", #type_name), #field, #var
+    })
+}
+
+fn trace(args: TokenStream) -> TokenStream {
+    let runtime = ::runtime_crate();
+    quote! {
+        {
+            #runtime
+            specified_default::log::trace!(#args);
+        }
+    }
+}
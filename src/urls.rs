@@ -0,0 +1,36 @@
+//! Compile-time validation of `url` URLs.
+
+use proc_macro2::{Span, TokenStream};
+use syn;
+
+/// Checks a value such as `"https://api.example.com/v1"` for a `Url` field.
+/// The URL is still parsed when the default is built, but as it has already
+/// been checked here that can't fail.
+///
+/// Returns `None` when the field isn't a `url::Url`.
+pub fn url_value(ty: &syn::Type, value: &str, span: Span) -> Option<syn::Result<TokenStream>> {
+    if !is_url(ty) {
+        return None;
+    }
+
+    Some(match ::url::Url::parse(value) {
+        Ok(url) => {
+            // The normalised form, which parses to the same `Url`
+            let url = url.as_str();
+            Ok(quote_spanned! {span=> <#ty>::parse(#url).expect("URLs are checked while compiling") })
+        },
+        Err(err) => Err(syn::Error::new(span, format!("`{}` isn't a valid URL: {}", value, err))),
+    })
+}
+
+/// Whether `ty` is written as `url::Url`. Other types of the same name,
+/// such as a type of the crate's own, are built with their `FromStr`.
+fn is_url(ty: &syn::Type) -> bool {
+    match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => {
+            let segments = path.segments.iter().map(|segment| (segment.ident.to_string(), segment.arguments.is_empty())).collect::<Vec<_>>();
+            segments == [("url".to_owned(), true), ("Url".to_owned(), true)]
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,885 @@
+//! Building field values from the strings given in attributes.
+//!
+//! Types that are known to be made up of other values, such as arrays,
+//! tuples and `Vec`, have each of their elements built separately so that
+//! only the innermost types need to implement `FromStr`.
+
+use proc_macro2::{Span, TokenStream};
+use syn;
+
+/// Builds the expression for `value` as the type `ty`. Errors are reported
+/// at `span`, which is that of the attribute the value came from.
+pub fn value_for(ty: &syn::Type, value: &str, span: Span) -> syn::Result<TokenStream> {
+    build_value(ty, value, span, true)
+}
+
+/// Builds the expression for `value` as the type `ty` like `value_for`, but
+/// reports an error rather than parsing any part of it at runtime, where the
+/// parse could panic.
+pub fn unparsed_value_for(ty: &syn::Type, value: &str, span: Span) -> syn::Result<TokenStream> {
+    build_value(ty, value, span, false)
+}
+
+fn build_value(ty: &syn::Type, value: &str, span: Span, parse: bool) -> syn::Result<TokenStream> {
+    let recurse: Build = if parse { value_for } else { unparsed_value_for };
+
+    #[cfg(feature = "bytes")]
+    {
+        if let Some(bytes) = ::bytes::string_bytes_value(ty, value, span, false) {
+            return Ok(bytes);
+        }
+    }
+
+    #[cfg(any(feature = "compact_str", feature = "smol_str"))]
+    {
+        if let Some(string) = ::strings::string_value(ty, value, span) {
+            return Ok(string);
+        }
+    }
+
+    if let Some(color) = ::color::color_value(ty, value, span) {
+        return color;
+    }
+
+    #[cfg(any(feature = "glam", feature = "nalgebra"))]
+    {
+        if let Some(vector) = ::vectors::vector_value(ty, value, span, recurse) {
+            return vector;
+        }
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    {
+        if let Some(decimal) = ::decimal::decimal_value(ty, value, span) {
+            return decimal;
+        }
+    }
+
+    #[cfg(feature = "num-bigint")]
+    {
+        if let Some(integer) = ::bigint::bigint_value(ty, value, span) {
+            return integer;
+        }
+    }
+
+    #[cfg(feature = "semver")]
+    {
+        if let Some(version) = ::version::version_value(ty, value, span) {
+            return version;
+        }
+    }
+
+    #[cfg(feature = "url")]
+    {
+        if let Some(url) = ::urls::url_value(ty, value, span) {
+            return url;
+        }
+    }
+
+    #[cfg(feature = "camino")]
+    {
+        if let Some(path) = ::paths::path_value(ty, value, span) {
+            return Ok(path);
+        }
+    }
+
+    match *ty {
+        syn::Type::Array(ref array) if delimited(value, '[', ']').is_some() => {
+            array_value(&array.elem, &array.len, value, span, recurse)
+        },
+        syn::Type::Tuple(ref tuple) if !tuple.elems.is_empty() => {
+            tuple_value(tuple.elems.iter(), value, span, recurse)
+        },
+        syn::Type::Paren(ref paren) => recurse(&paren.elem, value, span),
+        syn::Type::Reference(ref reference) if is_borrowed_literal(&reference.elem) => {
+            if is_path(&reference.elem, "str") {
+                let value = syn::LitStr::new(value, span);
+                Ok(quote! { #value })
+            } else {
+                let value = syn::LitByteStr::new(value.as_bytes(), span);
+                Ok(quote! { &#value[..] })
+            }
+        },
+        _ => {
+            if let Some(inner) = generic_arg(ty, "Option") {
+                let inner = recurse(inner, value, span)?;
+                Ok(quote! { ::std::option::Option::Some(#inner) })
+            } else if let Some(elem) = generic_arg(ty, "Vec") {
+                let list = delimited(value, '[', ']').unwrap_or(value);
+                let elements = split_elements(list).into_iter()
+                    .map(|element| recurse(elem, &element, span))
+                    .collect::<syn::Result<Vec<_>>>()?;
+
+                Ok(quote! { vec![#(#elements),*] })
+            } else if let Some((key, entry)) = map(ty) {
+                map_value(key, entry, value, span, recurse)
+            } else if let Some(collection) = fixed_capacity(ty) {
+                fixed_capacity_value(ty, collection, value, span, parse)
+            } else if let Some(elem) = slice_pointer(ty) {
+                let list = delimited(value, '[', ']').unwrap_or(value);
+                let elements = split_elements(list).into_iter()
+                    .map(|element| recurse(elem, &element, span))
+                    .collect::<syn::Result<Vec<_>>>()?;
+
+                Ok(quote! { ::std::convert::From::from(vec![#(#elements),*]) })
+            } else if is_str_pointer(ty) {
+                let value = syn::LitStr::new(value, span);
+                Ok(quote! { ::std::convert::From::from(#value) })
+            } else if let Some((pointer, inner)) = pointer(ty) {
+                let inner = recurse(inner, value, span)?;
+                Ok(quote! { #pointer::new(#inner) })
+            } else if let Some(integer) = non_zero(ty) {
+                check_non_zero(value, span)?;
+                let integer = recurse(&integer, value, span)?;
+                Ok(quote! {
+                    <#ty>::new(#integer).expect(&format!("Failed to parse {} as non-zero", #value))
+                })
+            } else if let Some(Ok(integer)) = integer_value(ty, value, span) {
+                Ok(integer)
+            } else if parse {
+                Ok(parse_value(value, span))
+            } else if let Some(literal) = literal_value(ty, value, span) {
+                literal
+            } else {
+                Err(syn::Error::new(span, format!(
+                    "`{}` would be parsed from `{}` at runtime, which can panic",
+                    type_name(ty),
+                    value,
+                )))
+            }
+        },
+    }
+}
+
+/// Builds the expression for `value` as the type `ty` without any runtime
+/// parsing, so that it can be used in a `const fn`. Only literals of the
+/// primitive types, and the arrays, tuples and other wrappers made from
+/// them, can be built this way.
+pub fn const_value_for(ty: &syn::Type, value: &str, span: Span) -> syn::Result<TokenStream> {
+    if let Some(color) = ::color::color_value(ty, value, span) {
+        return color;
+    }
+
+    #[cfg(any(feature = "glam", feature = "nalgebra"))]
+    {
+        if let Some(vector) = ::vectors::vector_value(ty, value, span, const_value_for) {
+            return vector;
+        }
+    }
+
+    #[cfg(feature = "bytes")]
+    {
+        if let Some(bytes) = ::bytes::string_bytes_value(ty, value, span, true) {
+            return Ok(bytes);
+        }
+    }
+
+    #[cfg(any(feature = "compact_str", feature = "smol_str"))]
+    {
+        if let Some(string) = ::strings::string_value(ty, value, span) {
+            return Ok(string);
+        }
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    {
+        if let Some(decimal) = ::decimal::decimal_value(ty, value, span) {
+            return decimal;
+        }
+    }
+
+    #[cfg(feature = "semver")]
+    {
+        if let Some(version) = ::version::version_value(ty, value, span) {
+            return version;
+        }
+    }
+
+    match *ty {
+        syn::Type::Array(ref array) if delimited(value, '[', ']').is_some() => {
+            array_value(&array.elem, &array.len, value, span, const_value_for)
+        },
+        syn::Type::Tuple(ref tuple) if !tuple.elems.is_empty() => {
+            tuple_value(tuple.elems.iter(), value, span, const_value_for)
+        },
+        syn::Type::Paren(ref paren) => const_value_for(&paren.elem, value, span),
+        syn::Type::Reference(ref reference) if is_borrowed_literal(&reference.elem) => value_for(ty, value, span),
+        _ => {
+            if let Some(inner) = generic_arg(ty, "Option") {
+                let inner = const_value_for(inner, value, span)?;
+                Ok(quote! { ::std::option::Option::Some(#inner) })
+            } else if let Some(integer) = non_zero(ty) {
+                check_non_zero(value, span)?;
+                let integer = const_value_for(&integer, value, span)?;
+                Ok(quote! {
+                    match <#ty>::new(#integer) {
+                        ::std::option::Option::Some(value) => value,
+                        ::std::option::Option::None => panic!("non-zero default is zero"),
+                    }
+                })
+            } else if let Some(literal) = literal_value(ty, value, span) {
+                literal
+            } else {
+                Err(syn::Error::new(span, format!(
+                    "`{}` can't be built from `{}` in a const fn",
+                    type_name(ty),
+                    value,
+                )))
+            }
+        },
+    }
+}
+
+/// The literal for `value` when `ty` is a primitive, such as `8080` for a
+/// `u16` or `'x'` for a `char`.
+fn literal_value(ty: &syn::Type, value: &str, span: Span) -> Option<syn::Result<TokenStream>> {
+    let path = match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path.get_ident()?.to_string(),
+        _ => return None,
+    };
+    let invalid = || syn::Error::new(span, format!("`{}` isn't a valid `{}`", value, path));
+    let trimmed = value.trim();
+
+    Some(match &*path {
+        integer if INTEGERS.contains(&integer) => return integer_value(ty, value, span),
+        "f32" | "f64" => {
+            trimmed.parse::<f64>().ok()
+                .filter(|float| float.is_finite() && (path == "f64" || float.abs() <= f64::from(f32::MAX)))
+                .map(|float| {
+                    let literal = syn::LitFloat::new(&format!("{:?}{}", float.abs(), path), span);
+                    if float.is_sign_negative() { quote! { -#literal } } else { quote! { #literal } }
+                })
+                .ok_or_else(invalid)
+        },
+        "bool" => match trimmed {
+            "true" => Ok(quote_spanned! { span=> true }),
+            "false" => Ok(quote_spanned! { span=> false }),
+            _ => Err(invalid()),
+        },
+        "char" => {
+            let mut chars = value.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => {
+                    let literal = syn::LitChar::new(c, span);
+                    Ok(quote! { #literal })
+                },
+                _ => Err(invalid()),
+            }
+        },
+        _ => return None,
+    })
+}
+
+/// Builds an integer literal of the primitive type `ty`, which may also be
+/// written with that type's suffix, checking that it's in range.
+fn integer_value(ty: &syn::Type, value: &str, span: Span) -> Option<syn::Result<TokenStream>> {
+    let integer = match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path.get_ident()?.to_string(),
+        _ => return None,
+    };
+    if !INTEGERS.contains(&&*integer) {
+        return None;
+    }
+
+    let out_of_range = || syn::Error::new(span, format!("`{}` is out of range for `{}`", value, integer));
+    let (negative, magnitude) = match integer_literal(value) {
+        Some((negative, Some(magnitude), ref suffix)) if suffix.is_empty() || *suffix == integer => (negative, magnitude),
+        Some((_, None, ref suffix)) if suffix.is_empty() || *suffix == integer => return Some(Err(out_of_range())),
+        _ => return Some(Err(syn::Error::new(span, format!("`{}` isn't a valid `{}`", value, integer)))),
+    };
+
+    // The width of `usize` is the target's, so only values that can't fit
+    // in the widest are caught here
+    let fits = match &*integer {
+        "usize" => (!negative || magnitude == 0) && magnitude <= u128::from(u64::MAX),
+        "isize" if negative => magnitude <= 1 << 63,
+        "isize" => magnitude < 1 << 63,
+        _ => integer_fits(ty, value) == Some(true),
+    };
+    if !fits {
+        return Some(Err(out_of_range()));
+    }
+
+    // Negating the literal keeps the most negative value of each type
+    // expressible, as its magnitude alone doesn't fit
+    let literal = syn::LitInt::new(&format!("{}{}", magnitude, integer), span);
+    Some(Ok(if negative && magnitude != 0 { quote! { -#literal } } else { quote! { #literal } }))
+}
+
+/// Whether the integer `value` fits in `ty`, when `ty` is an integer type of
+/// a fixed width and `value` is an integer literal.
+pub fn integer_fits(ty: &syn::Type, value: &str) -> Option<bool> {
+    let name = match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path.get_ident()?.to_string(),
+        _ => return None,
+    };
+    let bits = match &name[1..] {
+        "8" => 8,
+        "16" => 16,
+        "32" => 32,
+        "64" => 64,
+        "128" => 128,
+        _ => return None,
+    };
+
+    let (negative, magnitude, suffix) = integer_literal(value)?;
+    let magnitude = match magnitude {
+        Some(magnitude) if suffix.is_empty() || suffix == name => magnitude,
+        _ => return Some(false),
+    };
+
+    Some(match (name.starts_with('i'), negative) {
+        (false, true) => magnitude == 0,
+        (false, false) => bits == 128 || magnitude < 1 << bits,
+        (true, false) => magnitude < 1 << (bits - 1),
+        (true, true) => magnitude <= 1 << (bits - 1),
+    })
+}
+
+/// The sign, magnitude and suffix of an integer literal such as `-0x10`,
+/// `1_000` or `7u8`. The magnitude is `None` when it's too large for any
+/// integer type.
+pub fn integer_literal(value: &str) -> Option<(bool, Option<u128>, String)> {
+    let digits = value.trim().replace('_', "");
+    let (negative, digits) = match digits.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, digits.strip_prefix('+').unwrap_or(&digits)),
+    };
+
+    let literal = syn::parse_str::<syn::LitInt>(digits).ok()?;
+    Some((negative, literal.base10_parse().ok(), literal.suffix().to_owned()))
+}
+
+/// The types that `value_for` parses from strings to build `value`, which
+/// are the ones that need to implement `FromStr`.
+pub fn parsed_types<'a>(ty: &'a syn::Type, value: &str) -> Vec<&'a syn::Type> {
+    match *ty {
+        syn::Type::Array(ref array) if delimited(value, '[', ']').is_some() => {
+            let list = delimited(value, '[', ']').unwrap_or(value);
+            split_elements(list).iter().flat_map(|element| parsed_types(&array.elem, element)).collect()
+        },
+        syn::Type::Tuple(ref tuple) if !tuple.elems.is_empty() => {
+            let list = delimited(value, '(', ')').unwrap_or(value);
+            tuple.elems.iter().zip(split_elements(list))
+                .flat_map(|(elem, element)| parsed_types(elem, &element))
+                .collect()
+        },
+        syn::Type::Paren(ref paren) => parsed_types(&paren.elem, value),
+        syn::Type::Reference(ref reference) if is_borrowed_literal(&reference.elem) => Vec::new(),
+        _ => {
+            if let Some(inner) = generic_arg(ty, "Option") {
+                parsed_types(inner, value)
+            } else if let Some(elem) = generic_arg(ty, "Vec").or_else(|| slice_pointer(ty)) {
+                let list = delimited(value, '[', ']').unwrap_or(value);
+                split_elements(list).iter().flat_map(|element| parsed_types(elem, element)).collect()
+            } else if let Some((key, entry)) = map(ty) {
+                let list = delimited(value, '{', '}').or_else(|| delimited(value, '[', ']')).unwrap_or(value);
+                let mut parsed = Vec::new();
+                for element in if entry.is_some() { split_list(list) } else { split_elements(list) } {
+                    match (entry, split_entry(&element)) {
+                        (Some(entry), Some((ref k, ref v))) => {
+                            parsed.extend(parsed_types(key, k));
+                            parsed.extend(parsed_types(entry, v));
+                        },
+                        (None, _) => parsed.extend(parsed_types(key, &element)),
+                        _ => {},
+                    }
+                }
+                parsed
+            } else if let Some(FixedCapacity { elem, .. }) = fixed_capacity(ty) {
+                let list = delimited(value, '[', ']').unwrap_or(value);
+                elem.map_or_else(Vec::new, |elem| split_elements(list).iter().flat_map(|element| parsed_types(elem, element)).collect())
+            } else if let Some((_, inner)) = pointer(ty) {
+                parsed_types(inner, value)
+            } else if let Some(integer) = generic_arg(ty, "NonZero") {
+                vec![integer]
+            } else {
+                vec![ty]
+            }
+        },
+    }
+}
+
+/// Parses `value` at runtime into the type inferred from its context. The
+/// tokens are spanned to the attribute's literal, so that a type which can't
+/// be parsed is reported there rather than at the derive.
+pub fn parse_value(value: &str, span: Span) -> TokenStream {
+    let value = syn::LitStr::new(value, span);
+    quote_spanned! { span=> #value.parse().expect(&format!("Failed to parse {}", #value)) }
+}
+
+/// A function like `value_for` that builds a value from its string.
+pub type Build = fn(&syn::Type, &str, Span) -> syn::Result<TokenStream>;
+
+/// Builds an array literal with each element of `value` built individually.
+fn array_value(elem: &syn::Type, len: &syn::Expr, value: &str, span: Span, build: Build) -> syn::Result<TokenStream> {
+    let elements = split_elements(delimited(value, '[', ']').unwrap_or(value));
+
+    if let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(ref len), .. }) = *len {
+        let len = len.base10_parse::<usize>()?;
+        if elements.len() != len {
+            return Err(syn::Error::new(span, format!(
+                "expected {} array elements but found {} in `{}`", len, elements.len(), value,
+            )));
+        }
+    }
+
+    let elements = elements.iter()
+        .map(|element| build(elem, element, span))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! { [#(#elements),*] })
+}
+
+/// Builds a tuple with each element of `value` built as its own type.
+fn tuple_value<'a, I>(elems: I, value: &str, span: Span, build: Build) -> syn::Result<TokenStream>
+    where I: ExactSizeIterator<Item = &'a syn::Type>
+{
+    let elements = split_elements(delimited(value, '(', ')').unwrap_or(value));
+
+    if elements.len() != elems.len() {
+        return Err(syn::Error::new(span, format!(
+            "expected {} tuple elements but found {} in `{}`", elems.len(), elements.len(), value,
+        )));
+    }
+
+    let elements = elems.zip(&elements)
+        .map(|(elem, element)| build(elem, element, span))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! { (#(#elements,)*) })
+}
+
+/// The single type argument of `ty` when it's the generic type `name`, such
+/// as `T` in `Option<T>` or `std::option::Option<T>`.
+pub fn generic_arg<'a>(ty: &'a syn::Type, name: &str) -> Option<&'a syn::Type> {
+    let path = match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path,
+        _ => return None,
+    };
+
+    let segment = path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+
+    match segment.arguments {
+        syn::PathArguments::AngleBracketed(ref data) if data.args.len() == 1 => {
+            match data.args.first() {
+                Some(syn::GenericArgument::Type(ty)) => Some(ty),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// The smart pointer `ty` is, along with the type it points to, for the
+/// pointers to sized values that are built with `new`.
+fn pointer(ty: &syn::Type) -> Option<(TokenStream, &syn::Type)> {
+    let pointers = [
+        ("Box", quote!(::std::boxed::Box)),
+        ("Rc", quote!(::std::rc::Rc)),
+        ("Arc", quote!(::std::sync::Arc)),
+    ];
+
+    pointers.iter()
+        .find_map(|&(name, ref path)| generic_arg(ty, name).map(|inner| (path.clone(), inner)))
+        .filter(|&(_, inner)| match *inner {
+            syn::Type::Slice(_) | syn::Type::TraitObject(_) => false,
+            _ => !is_path(inner, "str"),
+        })
+}
+
+/// The key and value types of a map, or the element type of a set with no
+/// value type, for the maps and sets that are collected from their entries.
+/// `IndexMap` and `IndexSet` are included with the `indexmap` feature.
+fn map(ty: &syn::Type) -> Option<(&syn::Type, Option<&syn::Type>)> {
+    let segment = match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path.segments.last()?,
+        _ => return None,
+    };
+
+    let maps: &[&str] = &["HashMap", "BTreeMap", #[cfg(feature = "indexmap")] "IndexMap"];
+    let sets: &[&str] = &["HashSet", "BTreeSet", #[cfg(feature = "indexmap")] "IndexSet"];
+    let is_map = maps.iter().any(|name| segment.ident == name);
+    if !is_map && !sets.iter().any(|name| segment.ident == name) {
+        return None;
+    }
+
+    let mut args = match segment.arguments {
+        syn::PathArguments::AngleBracketed(ref data) => data.args.iter().filter_map(|arg| match *arg {
+            syn::GenericArgument::Type(ref ty) => Some(ty),
+            _ => None,
+        }),
+        _ => return None,
+    };
+    let key = args.next()?;
+    if is_map {
+        args.next().map(|entry| (key, Some(entry)))
+    } else {
+        Some((key, None))
+    }
+}
+
+/// Collects a map from entries such as `{a = 1, b = 2}`, or a set from a
+/// list such as `[a, b]`, in the order they're given. The brackets can be
+/// left out.
+fn map_value(key: &syn::Type, entry: Option<&syn::Type>, value: &str, span: Span, build: Build) -> syn::Result<TokenStream> {
+    let (open, close) = if entry.is_some() { ('{', '}') } else { ('[', ']') };
+    let list = delimited(value, open, close).unwrap_or(value);
+
+    let split = if entry.is_some() { split_list(list) } else { split_elements(list) };
+    let mut keys = Vec::new();
+    let mut elements = Vec::new();
+    for element in split {
+        let (k, v) = match entry {
+            Some(_) => split_entry(&element)
+                .ok_or_else(|| syn::Error::new(span, format!("expected an entry such as `key = value`, found `{}`", element)))?,
+            None => (element, String::new()),
+        };
+        if keys.contains(&k) {
+            return Err(syn::Error::new(span, format!("`{}` is given more than once", k)));
+        }
+
+        let built = build(key, &k, span)?;
+        elements.push(match entry {
+            Some(entry) => {
+                let v = build(entry, &v, span)?;
+                quote! { (#built, #v) }
+            },
+            None => built,
+        });
+        keys.push(k);
+    }
+
+    Ok(quote! { ::std::iter::FromIterator::from_iter(vec![#(#elements),*]) })
+}
+
+/// The key and value of a map entry such as `a = 1`, split at the first `=`
+/// outside of quotes and brackets, so that either can be a nested value.
+/// Quotes around either are removed.
+fn split_entry(element: &str) -> Option<(String, String)> {
+    let mut depth = 0;
+    let mut quoted = false;
+    let mut escaped = false;
+
+    for (i, c) in element.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '[' | '(' | '{' if !quoted => depth += 1,
+            ']' | ')' | '}' if !quoted => depth -= 1,
+            '=' if !quoted && depth == 0 => return Some((unquote(&element[..i]), unquote(&element[i + 1..]))),
+            _ => {},
+        }
+    }
+
+    None
+}
+
+/// A collection with a fixed capacity, which is filled without allocating.
+struct FixedCapacity<'a> {
+    /// The element type, or `None` for strings.
+    elem: Option<&'a syn::Type>,
+    /// The capacity, when it's a literal rather than a const parameter.
+    capacity: Option<usize>,
+    /// How elements are added, which doesn't panic once they're known to
+    /// fit.
+    push: Push,
+}
+
+enum Push {
+    /// `push` and `push_str`, which return an error when full.
+    Heapless,
+    /// `try_push` and `try_push_str`, as `push` panics when full.
+    #[cfg(feature = "arrayvec")]
+    ArrayVec,
+    /// `push`, which moves the elements to the heap when full, so the
+    /// capacity is only that of its inline storage.
+    #[cfg(feature = "smallvec")]
+    SmallVec,
+}
+
+/// A `heapless::Vec<T, N>` or `heapless::String<N>`, told apart from the
+/// `std` types by their capacity argument, or with the `arrayvec` and
+/// `smallvec` features an `ArrayVec<T, N>`, `ArrayString<N>` or
+/// `SmallVec<[T; N]>`.
+fn fixed_capacity(ty: &syn::Type) -> Option<FixedCapacity<'_>> {
+    let segment = match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path.segments.last()?,
+        _ => return None,
+    };
+    let args = match segment.arguments {
+        syn::PathArguments::AngleBracketed(ref data) => data.args.iter().collect::<Vec<_>>(),
+        _ => return None,
+    };
+
+    let capacity = |capacity: &syn::Expr| match *capacity {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(ref capacity), .. }) => capacity.base10_parse().ok(),
+        _ => None,
+    };
+    let argument = |argument: &syn::GenericArgument| match *argument {
+        syn::GenericArgument::Const(ref expr) => Some(capacity(expr)),
+        // A const parameter on its own parses as a type
+        syn::GenericArgument::Type(_) => Some(None),
+        _ => None,
+    };
+
+    let (elem, capacity, push) = match (segment.ident.to_string().as_str(), args.as_slice()) {
+        ("Vec", &[syn::GenericArgument::Type(elem), capacity]) => (Some(elem), argument(capacity)?, Push::Heapless),
+        ("String", &[capacity]) => (None, argument(capacity)?, Push::Heapless),
+        #[cfg(feature = "arrayvec")]
+        ("ArrayVec", &[syn::GenericArgument::Type(elem), capacity]) => (Some(elem), argument(capacity)?, Push::ArrayVec),
+        #[cfg(feature = "arrayvec")]
+        ("ArrayString", &[capacity]) => (None, argument(capacity)?, Push::ArrayVec),
+        #[cfg(feature = "smallvec")]
+        ("SmallVec", &[syn::GenericArgument::Type(syn::Type::Array(ref array))]) => (Some(&*array.elem), capacity(&array.len), Push::SmallVec),
+        _ => return None,
+    };
+
+    Some(FixedCapacity { elem, capacity, push })
+}
+
+/// Builds a fixed-capacity collection by pushing each element, after
+/// checking that they fit. A capacity that is a const parameter can only be
+/// checked as the default is built, which panics if they don't fit, so
+/// without `parse` that's an error instead.
+fn fixed_capacity_value(ty: &syn::Type, collection: FixedCapacity, value: &str, span: Span, parse: bool) -> syn::Result<TokenStream> {
+    let build: Build = if parse { value_for } else { unparsed_value_for };
+    let pushed = |push: TokenStream| match collection.capacity {
+        Some(_) => quote! { let _ = #push; },
+        None => {
+            let message = format!("the default doesn't fit in the capacity of `{}`", type_name(ty));
+            quote! {
+                if #push.is_err() {
+                    panic!(#message);
+                }
+            }
+        },
+    };
+
+    let (len, pushes) = match collection.elem {
+        Some(elem) => {
+            let list = delimited(value, '[', ']').unwrap_or(value);
+            let elements = split_elements(list).into_iter()
+                .map(|element| build(elem, &element, span))
+                .collect::<syn::Result<Vec<_>>>()?;
+            let pushes = elements.iter()
+                .map(|element| match collection.push {
+                    Push::Heapless => pushed(quote! { value.push(#element) }),
+                    #[cfg(feature = "arrayvec")]
+                    Push::ArrayVec => pushed(quote! { value.try_push(#element) }),
+                    #[cfg(feature = "smallvec")]
+                    Push::SmallVec => quote! { value.push(#element); },
+                })
+                .collect::<TokenStream>();
+            (elements.len(), pushes)
+        },
+        None => {
+            let pushes = match collection.push {
+                #[cfg(feature = "arrayvec")]
+                Push::ArrayVec => pushed(quote! { value.try_push_str(#value) }),
+                _ => pushed(quote! { value.push_str(#value) }),
+            };
+            (value.len(), pushes)
+        },
+    };
+
+    match collection.capacity {
+        Some(capacity) if len > capacity => {
+            let unit = if collection.elem.is_some() { "elements" } else { "bytes" };
+            let storage = match collection.push {
+                #[cfg(feature = "smallvec")]
+                Push::SmallVec => " inline",
+                _ => "",
+            };
+            return Err(syn::Error::new(span, format!(
+                "this has {} {}, but the `{}` holds at most {}{}", len, unit, type_name(ty), capacity, storage,
+            )));
+        },
+        None if !parse && len > 0 && !is_smallvec(&collection) => {
+            return Err(syn::Error::new(span, format!(
+                "the capacity of `{}` is a const parameter, so the default is only known to fit when it's built, which can panic", type_name(ty),
+            )));
+        },
+        _ => {},
+    }
+
+    Ok(quote! {
+        {
+            let mut value = <#ty>::new();
+            #pushes
+            value
+        }
+    })
+}
+
+/// Whether `collection` is a `SmallVec`, which never runs out of room.
+fn is_smallvec(collection: &FixedCapacity) -> bool {
+    match collection.push {
+        #[cfg(feature = "smallvec")]
+        Push::SmallVec => true,
+        _ => false,
+    }
+}
+
+/// The element type of a boxed or reference-counted slice, which is built
+/// from a `Vec` of the elements.
+fn slice_pointer(ty: &syn::Type) -> Option<&syn::Type> {
+    ["Box", "Rc", "Arc"].iter()
+        .find_map(|name| generic_arg(ty, name))
+        .and_then(|inner| match *inner {
+            syn::Type::Slice(ref slice) => Some(&*slice.elem),
+            _ => None,
+        })
+}
+
+/// Whether `ty` is a boxed or reference-counted `str`, which is converted
+/// from the literal.
+fn is_str_pointer(ty: &syn::Type) -> bool {
+    ["Box", "Rc", "Arc"].iter()
+        .find_map(|name| generic_arg(ty, name))
+        .is_some_and(|inner| is_path(inner, "str"))
+}
+
+fn check_non_zero(value: &str, span: Span) -> syn::Result<()> {
+    if value.trim().trim_start_matches(['+', '-']).trim_start_matches('0').is_empty() {
+        return Err(syn::Error::new(span, "a `NonZero` default can't be zero"));
+    }
+
+    Ok(())
+}
+
+/// The integer type wrapped by a `NonZero` type, such as `u32` for either
+/// `NonZeroU32` or `NonZero<u32>`.
+fn non_zero(ty: &syn::Type) -> Option<syn::Type> {
+    if let Some(integer) = generic_arg(ty, "NonZero") {
+        return Some(integer.clone());
+    }
+
+    let path = match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path,
+        _ => return None,
+    };
+
+    let segment = path.segments.last()?;
+    let integer = segment.ident.to_string().strip_prefix("NonZero")?.to_lowercase();
+    if !segment.arguments.is_empty() || !INTEGERS.contains(&&*integer) {
+        return None;
+    }
+
+    Some(syn::Type::Path(syn::TypePath {
+        qself: None,
+        path: syn::Ident::new(&integer, segment.ident.span()).into(),
+    }))
+}
+
+pub const INTEGERS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize",
+    "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// The type written out without any spacing, for error messages.
+fn type_name(ty: &syn::Type) -> String {
+    quote!(#ty).to_string().replace(' ', "")
+}
+
+/// Whether a reference to `ty` can borrow the attribute's literal itself,
+/// as `&'static str` and `&'static [u8]` can.
+fn is_borrowed_literal(ty: &syn::Type) -> bool {
+    match *ty {
+        syn::Type::Slice(ref slice) => is_path(&slice.elem, "u8"),
+        _ => is_path(ty, "str"),
+    }
+}
+
+/// Whether `ty` is the single-segment path `name`, as primitive types are.
+pub fn is_path(ty: &syn::Type, name: &str) -> bool {
+    match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path.is_ident(name),
+        _ => false,
+    }
+}
+
+/// The contents of `value` when the whole of it is wrapped in `open` and
+/// `close`. `(a, 1), (b, 2)` isn't delimited by parentheses, for example.
+pub fn delimited(value: &str, open: char, close: char) -> Option<&str> {
+    let value = value.trim();
+    if !value.starts_with(open) || !value.ends_with(close) {
+        return None;
+    }
+
+    let inner = &value[open.len_utf8()..value.len() - close.len_utf8()];
+    let mut depth = 0;
+    let mut quoted = false;
+    let mut escaped = false;
+
+    for c in inner.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '[' | '(' | '{' if !quoted => depth += 1,
+            ']' | ')' | '}' if !quoted => {
+                depth -= 1;
+                if depth < 0 {
+                    return None;
+                }
+            },
+            _ => {},
+        }
+    }
+
+    Some(inner)
+}
+
+/// Splits a comma-separated list into its elements, leaving any nested
+/// lists, tuples and quoted strings intact. Quotes around an element are
+/// removed.
+pub fn split_elements(list: &str) -> Vec<String> {
+    split_list(list).iter().map(|element| unquote(element)).collect()
+}
+
+/// Splits a list like `split_elements`, but leaves any quotes in place.
+fn split_list(list: &str) -> Vec<String> {
+    let mut elements = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut quoted = false;
+    let mut escaped = false;
+
+    for c in list.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '[' | '(' | '{' if !quoted => depth += 1,
+            ']' | ')' | '}' if !quoted => depth -= 1,
+            ',' if !quoted && depth == 0 => {
+                elements.push(current.trim().to_owned());
+                current.clear();
+                continue;
+            },
+            _ => {},
+        }
+
+        current.push(c);
+    }
+
+    if !current.trim().is_empty() {
+        elements.push(current.trim().to_owned());
+    }
+
+    elements
+}
+
+fn unquote(element: &str) -> String {
+    let element = element.trim();
+    if element.len() >= 2 && element.starts_with('"') && element.ends_with('"') {
+        element[1..element.len() - 1].replace("\\\"", "\"").replace("\\\\", "\\")
+    } else {
+        element.to_string()
+    }
+}
@@ -0,0 +1,92 @@
+//! Vectors and transforms from `glam` and `nalgebra`.
+
+use proc_macro2::{Span, TokenStream};
+use syn;
+
+use value::{Build, const_value_for, delimited, split_elements};
+#[cfg(feature = "nalgebra")]
+use value::generic_arg;
+
+/// The prefixes of `glam`'s vector types, such as the `D` of `DVec3`, with
+/// the type of their components.
+#[cfg(feature = "glam")]
+const GLAM_PREFIXES: &[(&str, &str)] = &[
+    ("", "f32"), ("D", "f64"), ("I", "i32"), ("U", "u32"), ("I64", "i64"), ("U64", "u64"),
+];
+
+/// Builds a vector or point from a list of its components, such as
+/// `"1.0, 0.0, 0.0"`, with `new`. The components are literals where they can
+/// be, so that the vector can also be used in a const fn.
+///
+/// Returns `None` when the field isn't a vector.
+pub fn vector_value(ty: &syn::Type, value: &str, span: Span, build: Build) -> Option<syn::Result<TokenStream>> {
+    let (elem, len) = vector(ty)?;
+
+    let list = delimited(value, '[', ']').or_else(|| delimited(value, '(', ')')).unwrap_or(value);
+    let components = split_elements(list);
+    if components.len() != len {
+        return Some(Err(syn::Error::new(span, format!(
+            "`{}` has {} components, but {} were given", quote!(#ty).to_string().replace(' ', ""), len, components.len(),
+        ))));
+    }
+
+    Some(components.iter()
+        .map(|component| const_value_for(&elem, component, span).or_else(|_| build(&elem, component, span)))
+        .collect::<syn::Result<Vec<_>>>()
+        .map(|components| quote! { <#ty>::new(#(#components),*) }))
+}
+
+/// The identity of a matrix, quaternion or other transform. `glam`'s types
+/// aren't generic and have an `IDENTITY` constant, while `nalgebra`'s take
+/// their scalar as a type argument and have an `identity` function.
+pub fn identity(ty: &syn::Type) -> syn::Result<TokenStream> {
+    let segment = match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path.segments.last(),
+        _ => None,
+    };
+
+    match segment {
+        #[cfg(feature = "glam")]
+        Some(segment) if segment.arguments.is_empty() => Ok(quote! { <#ty>::IDENTITY }),
+        #[cfg(feature = "nalgebra")]
+        Some(segment) if !segment.arguments.is_empty() => Ok(quote! { <#ty>::identity() }),
+        _ => Err(syn::Error::new_spanned(ty, "`identity` defaults need a matrix, quaternion or transform type")),
+    }
+}
+
+/// The component type and number of components of a vector type.
+fn vector(ty: &syn::Type) -> Option<(syn::Type, usize)> {
+    let segment = match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => path.segments.last()?,
+        _ => return None,
+    };
+    let name = segment.ident.to_string();
+
+    #[cfg(feature = "glam")]
+    {
+        if segment.arguments.is_empty() {
+            for &(prefix, elem) in GLAM_PREFIXES {
+                let len = match name.strip_prefix(prefix).and_then(|name| name.strip_prefix("Vec")) {
+                    Some("2") => 2,
+                    Some("3") => 3,
+                    Some("3A") if prefix.is_empty() => 3,
+                    Some("4") => 4,
+                    _ => continue,
+                };
+                return Some((syn::parse_str(elem).expect("component types are valid"), len));
+            }
+        }
+    }
+
+    #[cfg(feature = "nalgebra")]
+    {
+        let len = name.strip_prefix("Vector").or_else(|| name.strip_prefix("Point"))
+            .and_then(|len| len.parse::<usize>().ok())
+            .filter(|len| (1..=6).contains(len));
+        if let (Some(len), Some(elem)) = (len, generic_arg(ty, &name)) {
+            return Some((elem.clone(), len));
+        }
+    }
+
+    None
+}
@@ -0,0 +1,40 @@
+//! Compile-time validation of `semver` versions.
+
+use proc_macro2::{Span, TokenStream};
+use syn;
+
+/// Checks a value such as `"1.2.3"` for a `Version` field. Plain versions are
+/// built with the const `Version::new`, while those with pre-release or
+/// build metadata are parsed, which can't fail once they've been checked.
+///
+/// Returns `None` when the field isn't a `semver::Version`.
+pub fn version_value(ty: &syn::Type, value: &str, span: Span) -> Option<syn::Result<TokenStream>> {
+    if !is_version(ty) {
+        return None;
+    }
+
+    let version = match ::semver::Version::parse(value.trim()) {
+        Ok(version) => version,
+        Err(err) => return Some(Err(syn::Error::new(span, format!("`{}` isn't a valid version: {}", value, err)))),
+    };
+
+    Some(Ok(if version.pre.is_empty() && version.build.is_empty() {
+        let (major, minor, patch) = (version.major, version.minor, version.patch);
+        quote! { <#ty>::new(#major, #minor, #patch) }
+    } else {
+        let value = value.trim();
+        quote_spanned! {span=> <#ty>::parse(#value).expect("versions are checked while compiling") }
+    }))
+}
+
+/// Whether `ty` is written as `semver::Version`. Other types of the same name,
+/// such as a type of the crate's own, are built with their `FromStr`.
+fn is_version(ty: &syn::Type) -> bool {
+    match *ty {
+        syn::Type::Path(syn::TypePath { qself: None, ref path }) => {
+            let segments = path.segments.iter().map(|segment| (segment.ident.to_string(), segment.arguments.is_empty())).collect::<Vec<_>>();
+            segments == [("semver".to_owned(), true), ("Version".to_owned(), true)]
+        },
+        _ => false,
+    }
+}
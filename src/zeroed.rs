@@ -0,0 +1,81 @@
+//! Defaults filled with zero bytes, for large plain-old-data buffers that
+//! would be slow to build element by element.
+
+use proc_macro2::TokenStream;
+use syn;
+
+use value::{INTEGERS, generic_arg, is_path};
+
+/// Builds `ty` from zeroed memory. A `Box<T>` is allocated zeroed on the
+/// heap, so that even a large `T` never passes through the stack.
+///
+/// Only types that are valid as all zero bytes are accepted: integers,
+/// floats, `bool`, `char`, and arrays and tuples of them, or with the
+/// `bytemuck` feature any type implementing `bytemuck::Zeroable`.
+pub fn zeroed_value(ty: &syn::Type) -> syn::Result<TokenStream> {
+    let (boxed, inner) = match generic_arg(ty, "Box") {
+        Some(inner) => (true, inner),
+        None => (false, ty),
+    };
+
+    let checked = zero_valid(inner);
+    if !checked && !cfg!(feature = "bytemuck") {
+        return Err(syn::Error::new_spanned(
+            inner,
+            "`zeroed` defaults need integers, floats, `bool`, `char`, or arrays and tuples of them; \
+             other types need the `bytemuck` feature and a `Zeroable` implementation",
+        ));
+    }
+
+    // Otherwise `Zeroable` is what makes zeroed memory valid. Arrays of
+    // `Zeroable` elements are, whatever their length
+    let assert_zeroable = if checked {
+        quote! {}
+    } else {
+        let mut elem = inner;
+        while let syn::Type::Array(ref array) = *elem {
+            elem = &array.elem;
+        }
+        quote! {
+            fn assert_zeroable<T: ::bytemuck::Zeroable>() {}
+            assert_zeroable::<#elem>();
+        }
+    };
+
+    if !boxed {
+        return Ok(quote! {
+            {
+                #assert_zeroable
+                unsafe { ::std::mem::MaybeUninit::<#inner>::zeroed().assume_init() }
+            }
+        });
+    }
+
+    // Only a pointer to the value is ever on the stack
+    Ok(quote! {
+        {
+            #assert_zeroable
+            let layout = ::std::alloc::Layout::new::<#inner>();
+            let ptr = if layout.size() == 0 {
+                ::std::ptr::NonNull::<#inner>::dangling().as_ptr()
+            } else {
+                let ptr = unsafe { ::std::alloc::alloc_zeroed(layout) } as *mut #inner;
+                if ptr.is_null() {
+                    ::std::alloc::handle_alloc_error(layout);
+                }
+                ptr
+            };
+            unsafe { <#ty>::from_raw(ptr) }
+        }
+    })
+}
+
+/// Whether a value of `ty` whose bytes are all zero is valid.
+fn zero_valid(ty: &syn::Type) -> bool {
+    match *ty {
+        syn::Type::Array(ref array) => zero_valid(&array.elem),
+        syn::Type::Tuple(ref tuple) => tuple.elems.iter().all(zero_valid),
+        syn::Type::Paren(ref paren) => zero_valid(&paren.elem),
+        _ => INTEGERS.iter().chain(&["f32", "f64", "bool", "char"]).any(|name| is_path(ty, name)),
+    }
+}
@@ -0,0 +1,99 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[test]
+fn numeric_array() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "[1, 2, 4, 8]"]
+        bar: [u32; 4],
+    }
+
+    assert_eq!(Foo::default().bar, [1, 2, 4, 8]);
+}
+
+#[test]
+fn float_array() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "[0.25, 0.5, 0.25]"]
+        bar: [f32; 3],
+    }
+
+    assert_eq!(Foo::default().bar, [0.25, 0.5, 0.25]);
+}
+
+#[test]
+fn string_array() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = r#"["north", "south, east", "west"]"#]
+        bar: [String; 3],
+    }
+
+    assert_eq!(Foo::default().bar, ["north", "south, east", "west"]);
+}
+
+#[test]
+fn nested_array() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "[[1, 0], [0, 1]]"]
+        bar: [[i8; 2]; 2],
+    }
+
+    assert_eq!(Foo::default().bar, [[1, 0], [0, 1]]);
+}
+
+#[test]
+fn repeated_elements() {
+    const LEN: usize = 4;
+
+    #[derive(SpecifiedDefault)]
+    #[specified_default(no_panic)]
+    struct Foo {
+        #[default(repeat = "0xFF; 64")]
+        bar: [u8; 64],
+        #[default(repeat = "-1")]
+        baz: [i16; LEN],
+        #[default(repeat = "0; 16")]
+        qux: Vec<u8>,
+        #[default(repeat = "pad; LEN * 2")]
+        quux: Vec<&'static str>,
+        #[default(repeat = "[1, 2]")]
+        corge: [[u8; 2]; 3],
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar, [0xFF; 64]);
+    assert_eq!(foo.baz, [-1; LEN]);
+    assert_eq!(foo.qux, vec![0; 16]);
+    assert_eq!(foo.quux, vec!["pad"; 8]);
+    assert_eq!(foo.corge, [[1, 2]; 3]);
+}
+
+#[test]
+fn repeated_elements_in_const_default() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(default_const)]
+    struct Foo {
+        #[default(repeat = "7; 3")]
+        bar: [u32; 3],
+    }
+
+    const FOO: Foo = Foo::default_const();
+    assert_eq!(FOO.bar, [7; 3]);
+}
+
+#[test]
+#[should_panic(expected = "Failed to parse")]
+fn fail_parsing_element() {
+    #[allow(dead_code)]
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "[1, two]"]
+        bar: [u32; 2],
+    }
+
+    Foo::default();
+}
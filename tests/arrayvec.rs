@@ -0,0 +1,52 @@
+#![cfg(feature = "arrayvec")]
+
+extern crate arrayvec;
+#[macro_use]
+extern crate specified_default_derive;
+
+use arrayvec::{ArrayString, ArrayVec};
+
+#[derive(SpecifiedDefault)]
+#[specified_default(no_panic)]
+struct Route {
+    #[default = "[80, 443]"]
+    ports: ArrayVec<u16, 4>,
+    #[default = "[1, 2, 3, 4]"]
+    full: ArrayVec<u8, 4>,
+    #[default = "edge-01"]
+    name: ArrayString<8>,
+    #[default = "[]"]
+    empty: arrayvec::ArrayVec<u8, 2>,
+}
+
+#[test]
+fn collections_are_filled() {
+    let route = Route::default();
+    assert_eq!(&route.ports[..], &[80, 443]);
+    assert!(route.full.is_full());
+    assert_eq!(route.name.as_str(), "edge-01");
+    assert!(route.empty.is_empty());
+}
+
+#[test]
+fn const_capacities() {
+    #[derive(SpecifiedDefault)]
+    struct Buffer<const N: usize> {
+        #[default = "[7, 8]"]
+        bytes: ArrayVec<u8, N>,
+    }
+
+    assert_eq!(&Buffer::<4>::default().bytes[..], &[7, 8]);
+}
+
+#[test]
+#[should_panic(expected = "the default doesn't fit in the capacity of `ArrayVec<u8,N>`")]
+fn const_capacities_too_small() {
+    #[derive(SpecifiedDefault)]
+    struct Buffer<const N: usize> {
+        #[default = "[7, 8, 9]"]
+        bytes: ArrayVec<u8, N>,
+    }
+
+    let _ = Buffer::<2>::default().bytes;
+}
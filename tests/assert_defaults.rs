@@ -0,0 +1,41 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::panic;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(assert_defaults)]
+struct Server {
+    #[default = "localhost"]
+    host: String,
+    #[default = "8080"]
+    port: u16,
+    #[default = "[1, 2]"]
+    workers: Vec<u8>,
+}
+
+#[test]
+fn defaults_pass() {
+    Server::default().assert_specified_defaults();
+}
+
+#[test]
+fn reports_the_first_differing_field() {
+    let server = Server { port: 9090, workers: vec![3], ..Server::default() };
+    let message = panic::catch_unwind(|| server.assert_specified_defaults()).unwrap_err();
+    assert_eq!(message.downcast_ref::<String>().unwrap(), "`port` differs from its default\n  value: 9090\ndefault: 8080");
+    assert_eq!(server.host, "localhost");
+}
+
+#[test]
+fn generic_fields() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(assert_defaults)]
+    struct Slot<T> {
+        #[default = "5"]
+        value: T,
+    }
+
+    Slot::<i32>::default().assert_specified_defaults();
+    assert!(panic::catch_unwind(|| Slot { value: 6 }.assert_specified_defaults()).is_err());
+}
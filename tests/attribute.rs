@@ -0,0 +1,72 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[test]
+fn attribute_struct() {
+    #[specified_default]
+    #[derive(Debug, PartialEq)]
+    struct Foo {
+        #[default = "42"]
+        bar: u32,
+        #[default = "baz"]
+        baz: String,
+        qux: Vec<u8>,
+    }
+
+    assert_eq!(Foo::default(), Foo { bar: 42, baz: "baz".to_owned(), qux: vec![] });
+}
+
+#[test]
+fn attribute_enum() {
+    #[specified_default]
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq)]
+    enum Foo {
+        Bar,
+        #[default]
+        Baz,
+    }
+
+    assert_eq!(Foo::default(), Foo::Baz);
+}
+
+#[test]
+fn attribute_options() {
+    #[specified_default(default_ref, default_const)]
+    struct Foo {
+        #[default = "8080"]
+        port: u16,
+    }
+
+    const FOO: Foo = Foo::default_const();
+    assert_eq!(FOO.port, 8080);
+    assert_eq!(Foo::default_ref().port, 8080);
+    assert!(std::ptr::eq(Foo::default_ref(), Foo::default_ref()));
+}
+
+#[test]
+fn attribute_options_alongside_container_attribute() {
+    #[specified_default(default_ref)]
+    #[specified_default(default_const)]
+    struct Foo {
+        #[default = "8080"]
+        port: u16,
+    }
+
+    assert_eq!(Foo::default_const().port, 8080);
+    assert_eq!(Foo::default_ref().port, 8080);
+}
+
+#[test]
+fn attribute_generics() {
+    #[specified_default]
+    struct Foo<T> {
+        #[default = "3"]
+        count: usize,
+        items: Vec<T>,
+    }
+
+    let foo: Foo<String> = Foo::default();
+    assert_eq!(foo.count, 3);
+    assert!(foo.items.is_empty());
+}
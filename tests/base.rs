@@ -0,0 +1,57 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(Clone, SpecifiedDefault)]
+#[specified_default(base = "crate::PRESETS.balanced")]
+pub struct Tuning {
+    threads: u32,
+    queue: &'static str,
+
+    #[default = "64"]
+    batch_size: u32,
+}
+
+pub struct Presets {
+    balanced: Tuning,
+}
+
+pub static PRESETS: Presets = Presets {
+    balanced: Tuning {
+        threads: 4,
+        queue: "balanced",
+        batch_size: 16,
+    },
+};
+
+#[test]
+fn overrides_applied_to_base() {
+    let result = Tuning::default();
+    assert_eq!(result.threads, 4);
+    assert_eq!(result.queue, "balanced");
+    assert_eq!(result.batch_size, 64);
+}
+
+#[test]
+fn base_from_function() {
+    #[derive(Clone, SpecifiedDefault)]
+    #[specified_default(base = "Foo::preset()")]
+    struct Foo {
+        bar: Vec<u32>,
+
+        #[default = "baz"]
+        baz: String,
+    }
+
+    impl Foo {
+        fn preset() -> Foo {
+            Foo {
+                bar: vec![1, 2, 3],
+                baz: "preset".to_string(),
+            }
+        }
+    }
+
+    let result = Foo::default();
+    assert_eq!(result.bar, vec![1, 2, 3]);
+    assert_eq!(result.baz, "baz");
+}
@@ -0,0 +1,29 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::sync::Arc;
+
+#[derive(SpecifiedDefault)]
+struct Keys {
+    #[default(base64 = "AAEC")]
+    seed: Vec<u8>,
+    #[default(base64 = "iVBORw==")]
+    magic: [u8; 4],
+    #[default(base64 = "aGVsbG8=")]
+    greeting: &'static [u8],
+    #[default(base64 = "aGk")]
+    unpadded: Arc<[u8]>,
+}
+
+#[test]
+fn values_are_decoded() {
+    let keys = Keys::default();
+    assert_eq!(keys.seed, vec![0, 1, 2]);
+    assert_eq!(keys.magic, [0x89, b'P', b'N', b'G']);
+    assert_eq!(keys.greeting, b"hello");
+}
+
+#[test]
+fn padding_is_optional() {
+    assert_eq!(&*Keys::default().unpadded, b"hi");
+}
@@ -112,6 +112,7 @@ fn struct_defaults() {
 
 #[test]
 #[should_panic(expected = "Failed to parse")]
+#[allow(clippy::unnecessary_operation)]
 fn fail_parsing() {
     #[derive(SpecifiedDefault)]
     struct Foo {
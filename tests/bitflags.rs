@@ -0,0 +1,91 @@
+#![cfg(feature = "bitflags")]
+
+#[macro_use]
+extern crate bitflags;
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::path::PathBuf;
+use std::str::FromStr;
+
+bitflags! {
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Permissions: u8 {
+        const READ = 0b001;
+        const WRITE = 0b010;
+        const EXECUTE = 0b100;
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Level {
+    Info,
+    Warn,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Level, String> {
+        match s {
+            "INFO" => Ok(Level::Info),
+            "WARN" => Ok(Level::Warn),
+            _ => Err(format!("unknown level {}", s)),
+        }
+    }
+}
+
+#[test]
+fn combined_flags() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(flags = "READ | WRITE")]
+        bar: Permissions,
+    }
+
+    assert_eq!(Foo::default().bar, Permissions::READ | Permissions::WRITE);
+}
+
+#[test]
+fn single_flag() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(flags = "EXECUTE")]
+        bar: Permissions,
+    }
+
+    assert_eq!(Foo::default().bar, Permissions::EXECUTE);
+}
+
+#[test]
+fn strings_are_not_flags() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "READ | WRITE"]
+        bar: String,
+    }
+
+    assert_eq!(Foo::default().bar, "READ | WRITE");
+}
+
+#[test]
+fn capitalized_values_are_not_flags() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "A"]
+        letter: char,
+        #[default = "README"]
+        path: PathBuf,
+        #[default = "INFO"]
+        level: Level,
+        #[default = "NONE"]
+        name: Option<String>,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.letter, 'A');
+    assert_eq!(foo.path, PathBuf::from("README"));
+    assert_eq!(foo.level, Level::Info);
+    assert_eq!(foo.name.as_deref(), Some("NONE"));
+    let _ = Level::Warn;
+}
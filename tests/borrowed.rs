@@ -0,0 +1,42 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[test]
+fn borrowed_str() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "hello"]
+        bar: &'static str,
+        #[default = "world"]
+        baz: Option<&'static str>,
+        #[default = "[a, b]"]
+        qux: Vec<&'static str>,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar, "hello");
+    assert_eq!(foo.baz, Some("world"));
+    assert_eq!(foo.qux, vec!["a", "b"]);
+}
+
+#[test]
+fn borrowed_bytes() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "\x7fELF"]
+        bar: &'static [u8],
+    }
+
+    assert_eq!(Foo::default().bar, b"\x7fELF");
+}
+
+#[test]
+fn borrowed_with_lifetime_parameter() {
+    #[derive(SpecifiedDefault)]
+    struct Foo<'a> {
+        #[default = "hello"]
+        bar: &'a str,
+    }
+
+    assert_eq!(Foo::default().bar, "hello");
+}
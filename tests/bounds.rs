@@ -0,0 +1,44 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+trait Limits {
+    const DEFAULT_CAPACITY: usize;
+    const NAME: &'static str;
+}
+
+struct Packet(u64);
+
+impl Limits for Packet {
+    const DEFAULT_CAPACITY: usize = 32;
+    const NAME: &'static str = "packet";
+}
+
+#[derive(SpecifiedDefault)]
+struct Buffer<T> {
+    #[default(expr = "Vec::with_capacity(T::DEFAULT_CAPACITY)", bound = "T: Limits")]
+    items: Vec<T>,
+    #[default(expr = "T::DEFAULT_CAPACITY", bound = "T: Limits")]
+    capacity: usize,
+    #[default(expr = "<T as Limits>::NAME", bound = "T: Limits")]
+    label: &'static str,
+}
+
+#[test]
+fn associated_consts_of_type_params() {
+    let buffer = Buffer::<Packet>::default();
+    assert!(buffer.items.capacity() >= 32);
+    assert_eq!(buffer.capacity, 32);
+    assert_eq!(buffer.label, "packet");
+    assert_eq!(Packet(1).0, 1);
+}
+
+#[test]
+fn bounds_replace_inferred_ones() {
+    #[derive(SpecifiedDefault)]
+    struct Wrapper<T> {
+        #[default(value = "7", bound = "T: std::str::FromStr, T::Err: std::fmt::Debug")]
+        inner: T,
+    }
+
+    assert_eq!(Wrapper::<u8>::default().inner, 7);
+}
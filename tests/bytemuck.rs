@@ -0,0 +1,29 @@
+#![cfg(feature = "bytemuck")]
+
+#[macro_use]
+extern crate bytemuck;
+#[macro_use]
+extern crate specified_default_derive;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Zeroable)]
+struct Vertex {
+    position: [f32; 3],
+    color: u32,
+}
+
+#[derive(SpecifiedDefault)]
+struct Mesh {
+    #[default(zeroed)]
+    origin: Vertex,
+    #[default(zeroed)]
+    vertices: Box<[Vertex; 65536]>,
+}
+
+#[test]
+fn zeroable_types() {
+    let mesh = Mesh::default();
+    let zero = Vertex { position: [0.0; 3], color: 0 };
+    assert_eq!(mesh.origin, zero);
+    assert!(mesh.vertices.iter().all(|&vertex| vertex == zero));
+}
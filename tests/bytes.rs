@@ -0,0 +1,48 @@
+#![cfg(feature = "bytes")]
+
+extern crate bytes;
+#[macro_use]
+extern crate specified_default_derive;
+
+use bytes::{Bytes, BytesMut};
+
+#[derive(SpecifiedDefault)]
+#[specified_default(no_panic)]
+struct Handshake {
+    #[default = "HELLO"]
+    greeting: Bytes,
+    #[default(base64 = "AAEC")]
+    nonce: BytesMut,
+    #[default = "buffer"]
+    scratch: bytes::BytesMut,
+    #[default(include_bytes = "assets/header.bin")]
+    icon: Bytes,
+    #[default = "[ping, pong]"]
+    frames: Vec<Bytes>,
+}
+
+#[test]
+fn bytes_from_strings_and_encodings() {
+    let handshake = Handshake::default();
+    assert_eq!(handshake.greeting, Bytes::from_static(b"HELLO"));
+    assert_eq!(&handshake.nonce[..], &[0, 1, 2]);
+    assert_eq!(&handshake.scratch[..], b"buffer");
+    assert_eq!(&handshake.icon[..], include_bytes!("assets/header.bin"));
+    assert_eq!(handshake.frames, [Bytes::from_static(b"ping"), Bytes::from_static(b"pong")]);
+}
+
+#[test]
+fn bytes_in_const_default() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(default_const)]
+    struct Magic {
+        #[default = "\u{89}PNG"]
+        header: Bytes,
+        #[default(base64 = "DQoaCg==")]
+        trailer: Bytes,
+    }
+
+    let magic = Magic::default_const();
+    assert_eq!(magic.header, "\u{89}PNG".as_bytes());
+    assert_eq!(&magic.trailer[..], b"\r\n\x1a\n");
+}
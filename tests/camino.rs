@@ -0,0 +1,29 @@
+#![cfg(feature = "camino")]
+
+extern crate camino;
+#[macro_use]
+extern crate specified_default_derive;
+
+use camino::{Utf8Path, Utf8PathBuf};
+
+#[derive(SpecifiedDefault)]
+#[specified_default(no_panic)]
+struct Build {
+    #[default = "target/debug"]
+    out_dir: Utf8PathBuf,
+    #[default = "Cargo.toml"]
+    manifest: &'static Utf8Path,
+    #[default = "[src, tests]"]
+    watch: Vec<camino::Utf8PathBuf>,
+    #[default = "/tmp"]
+    scratch: Option<Utf8PathBuf>,
+}
+
+#[test]
+fn utf8_paths() {
+    let build = Build::default();
+    assert_eq!(build.out_dir, Utf8PathBuf::from("target/debug"));
+    assert_eq!(build.manifest.extension(), Some("toml"));
+    assert_eq!(build.watch, [Utf8PathBuf::from("src"), Utf8PathBuf::from("tests")]);
+    assert_eq!(build.scratch.as_deref(), Some(Utf8Path::new("/tmp")));
+}
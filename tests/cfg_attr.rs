@@ -0,0 +1,44 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[test]
+fn cfg_attr_name_value() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[cfg_attr(debug_assertions, default = "1")]
+        #[cfg_attr(not(debug_assertions), default = "2")]
+        bar: u32,
+        #[cfg_attr(any(), default = "3")]
+        baz: u32,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar, if cfg!(debug_assertions) { 1 } else { 2 });
+    assert_eq!(foo.baz, 0);
+}
+
+#[test]
+fn cfg_attr_feature_gated() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[cfg_attr(feature = "bitflags", default(value = "4096"))]
+        #[cfg_attr(not(feature = "bitflags"), default(value = "512"))]
+        buffer_size: usize,
+    }
+
+    let expected = if cfg!(feature = "bitflags") { 4096 } else { 512 };
+    assert_eq!(Foo::default().buffer_size, expected);
+}
+
+#[test]
+fn cfg_attr_container() {
+    #[derive(SpecifiedDefault)]
+    #[cfg_attr(all(), specified_default(overrides))]
+    struct Foo {
+        #[default = "1"]
+        bar: u32,
+    }
+
+    let overrides = vec![("bar", "2")].into_iter().collect();
+    assert_eq!(Foo::default_with_overrides(&overrides).unwrap().bar, 2);
+}
@@ -0,0 +1,32 @@
+#[macro_use]
+extern crate specified_default_derive;
+extern crate rgb;
+
+use rgb::{RGB8, RGBA8};
+
+#[derive(SpecifiedDefault)]
+#[specified_default(no_panic, default_const)]
+struct Theme {
+    #[default = "#ff8800"]
+    accent: [u8; 3],
+    #[default = "#00000080"]
+    shadow: [u8; 4],
+    #[default = "#FFFFFF"]
+    background: RGBA8,
+    #[default = "#102030"]
+    text: RGB8,
+}
+
+#[test]
+fn arrays_are_decoded() {
+    let theme = Theme::default();
+    assert_eq!(theme.accent, [0xff, 0x88, 0x00]);
+    assert_eq!(theme.shadow, [0, 0, 0, 0x80]);
+}
+
+#[test]
+fn color_types_are_decoded() {
+    const THEME: Theme = Theme::default_const();
+    assert_eq!(THEME.background, RGBA8::new(255, 255, 255, 255));
+    assert_eq!(THEME.text, RGB8::new(0x10, 0x20, 0x30));
+}
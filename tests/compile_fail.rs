@@ -0,0 +1,13 @@
+extern crate trybuild;
+
+#[test]
+fn compile_fail() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+
+    // These expand fully, and with the `inventory` feature the expansion
+    // refers to a runtime crate that trybuild builds without it.
+    if cfg!(not(feature = "inventory")) {
+        cases.compile_fail("tests/ui/expanded/*.rs");
+    }
+}
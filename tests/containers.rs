@@ -0,0 +1,79 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[test]
+fn option_defaults() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "42"]
+        bar: Option<u32>,
+
+        baz: Option<u32>,
+    }
+
+    let result = Foo::default();
+    assert_eq!(result.bar, Some(42));
+    assert_eq!(result.baz, None);
+}
+
+#[test]
+fn vec_defaults() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "[1, 2, 3]"]
+        bar: Vec<u8>,
+        #[default = "a, b"]
+        baz: Vec<String>,
+        #[default = "[]"]
+        quux: Vec<u8>,
+    }
+
+    let result = Foo::default();
+    assert_eq!(result.bar, vec![1, 2, 3]);
+    assert_eq!(result.baz, vec!["a".to_string(), "b".to_string()]);
+    assert!(result.quux.is_empty());
+}
+
+#[test]
+fn nested_containers() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "[80, 443]"]
+        bar: Option<Vec<u16>>,
+        #[default = "[[1, 2], [3]]"]
+        baz: Vec<Vec<u8>>,
+    }
+
+    let result = Foo::default();
+    assert_eq!(result.bar, Some(vec![80, 443]));
+    assert_eq!(result.baz, vec![vec![1, 2], vec![3]]);
+}
+
+#[test]
+fn tuple_defaults() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "(origin, 0)"]
+        bar: (String, u32),
+        #[default = r#"[("a, b", 1), ("c", 2)]"#]
+        baz: Vec<(String, u32)>,
+        #[default = "(1, 2), (3, 4)"]
+        quux: Vec<(u8, u8)>,
+    }
+
+    let result = Foo::default();
+    assert_eq!(result.bar, ("origin".to_string(), 0));
+    assert_eq!(result.baz, vec![("a, b".to_string(), 1), ("c".to_string(), 2)]);
+    assert_eq!(result.quux, vec![(1, 2), (3, 4)]);
+}
+
+#[test]
+fn strings_are_not_split() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "a, b"]
+        bar: String,
+    }
+
+    assert_eq!(Foo::default().bar, "a, b");
+}
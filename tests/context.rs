@@ -0,0 +1,47 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+struct Tenant {
+    id: u64,
+    name: String,
+}
+
+struct Request {
+    tenant: Tenant,
+    region: &'static str,
+}
+
+#[derive(SpecifiedDefault)]
+#[specified_default(context = "Request")]
+struct Settings {
+    #[default(from_ctx = "tenant.id")]
+    tenant_id: u64,
+    #[default(from_ctx = "tenant.name", value = "anonymous")]
+    tenant_name: String,
+    #[default(from_ctx = "region")]
+    region: &'static str,
+    #[default = "25"]
+    page_size: u32,
+}
+
+#[test]
+fn fields_are_taken_from_the_context() {
+    let request = Request {
+        tenant: Tenant { id: 7, name: "acme".to_owned() },
+        region: "eu-west-1",
+    };
+
+    let settings = Settings::default_with(&request);
+    assert_eq!(settings.tenant_id, 7);
+    assert_eq!(settings.tenant_name, "acme");
+    assert_eq!(settings.region, "eu-west-1");
+    assert_eq!(settings.page_size, 25);
+}
+
+#[test]
+fn default_ignores_the_context() {
+    let settings = Settings::default();
+    assert_eq!(settings.tenant_id, 0);
+    assert_eq!(settings.tenant_name, "anonymous");
+    assert_eq!(settings.region, "");
+}
@@ -0,0 +1,90 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+struct Port(u16);
+
+impl From<u16> for Port {
+    fn from(port: u16) -> Port {
+        Port(port)
+    }
+}
+
+struct Name(String);
+
+impl<'a> From<&'a str> for Name {
+    fn from(name: &'a str) -> Name {
+        Name(name.to_string())
+    }
+}
+
+#[test]
+fn via_parsed_type() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(via = "u16", value = "80")]
+        port: Port,
+    }
+
+    assert_eq!(Foo::default().port.0, 80);
+}
+
+#[test]
+fn via_str() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(via = "&str", value = "localhost")]
+        name: Name,
+    }
+
+    assert_eq!(Foo::default().name.0, "localhost");
+}
+
+#[test]
+fn value_without_via() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(value = "42")]
+        bar: u32,
+    }
+
+    assert_eq!(Foo::default().bar, 42);
+}
+
+#[test]
+#[should_panic(expected = "Failed to parse")]
+fn via_fail_parsing() {
+    #[allow(dead_code)]
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(via = "u16", value = "70000")]
+        port: Port,
+    }
+
+    Foo::default();
+}
+
+#[test]
+fn type_hint() {
+    type Timeout = u64;
+
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(ty = "u64", value = "10")]
+        bar: Timeout,
+    }
+
+    assert_eq!(Foo::default().bar, 10);
+}
+
+#[test]
+fn type_hint_for_aliased_array() {
+    type Weights = [f32; 3];
+
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(ty = "[f32; 3]", value = "[0.25, 0.5, 0.25]")]
+        bar: Weights,
+    }
+
+    assert_eq!(Foo::default().bar, [0.25, 0.5, 0.25]);
+}
@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[test]
+fn debug_expand_generates_the_same_impl() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(debug_expand)]
+    struct Foo {
+        #[default = "42"]
+        bar: u32,
+    }
+
+    assert_eq!(Foo::default().bar, 42);
+}
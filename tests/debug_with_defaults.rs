@@ -0,0 +1,62 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[allow(dead_code)]
+#[derive(DebugWithDefaults, SpecifiedDefault)]
+struct Server {
+    #[default = "localhost"]
+    host: String,
+    #[default = "8080"]
+    port: u16,
+    workers: u32,
+}
+
+#[test]
+fn omits_default_fields() {
+    let server = Server { workers: 4, ..Server::default() };
+    assert_eq!(format!("{:?}", server), "Server { workers: 4, .. }");
+}
+
+#[test]
+fn default_shows_no_fields() {
+    assert_eq!(format!("{:?}", Server::default()), "Server { .. }");
+}
+
+#[test]
+fn customized_shows_every_field() {
+    let server = Server { host: "example.com".to_owned(), port: 443, workers: 4 };
+    assert_eq!(format!("{:?}", server), r#"Server { host: "example.com", port: 443, workers: 4 }"#);
+}
+
+#[test]
+fn pretty() {
+    let server = Server { port: 443, ..Server::default() };
+    assert_eq!(format!("{:#?}", server), "Server {\n    port: 443,\n    ..\n}");
+}
+
+#[test]
+fn generic() {
+    #[allow(dead_code)]
+    #[derive(DebugWithDefaults, SpecifiedDefault)]
+    struct Pool<T> {
+        #[default = "8"]
+        size: usize,
+        items: Vec<T>,
+    }
+
+    let pool = Pool { items: vec!["a"], ..Pool::default() };
+    assert_eq!(format!("{:?}", pool), r#"Pool { items: ["a"], .. }"#);
+}
+
+#[test]
+fn raw_field_names() {
+    #[allow(dead_code)]
+    #[derive(DebugWithDefaults, SpecifiedDefault)]
+    struct Token {
+        #[default = "ident"]
+        r#type: String,
+    }
+
+    let token = Token { r#type: "literal".to_owned() };
+    assert_eq!(format!("{:?}", token), r#"Token { type: "literal" }"#);
+}
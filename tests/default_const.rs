@@ -0,0 +1,111 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::num::NonZeroU16;
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq)]
+enum Level {
+    Info,
+    Warn,
+}
+
+#[allow(dead_code)]
+#[derive(SpecifiedDefault)]
+#[specified_default(default_const)]
+struct Config {
+    #[default = "8080"]
+    port: u16,
+    #[default = "-1.5"]
+    offset: f64,
+    #[default = "true"]
+    enabled: bool,
+    #[default = "x"]
+    marker: char,
+    #[default = "localhost"]
+    host: &'static str,
+    #[default = "[1, 2, 3]"]
+    weights: [u8; 3],
+    #[default = "(1, -2)"]
+    origin: (i32, i64),
+    #[default = "4"]
+    workers: Option<NonZeroU16>,
+    #[default(variant = "Level::Warn")]
+    level: Level,
+    #[default(empty)]
+    names: Vec<String>,
+    #[default(debug = "1", release = "3")]
+    retries: u8,
+}
+
+static CONFIG: Config = Config::default_const();
+
+#[test]
+fn const_default_struct() {
+    assert_eq!(CONFIG.port, 8080);
+    assert_eq!(CONFIG.offset, -1.5);
+    assert!(CONFIG.enabled);
+    assert_eq!(CONFIG.marker, 'x');
+    assert_eq!(CONFIG.host, "localhost");
+    assert_eq!(CONFIG.weights, [1, 2, 3]);
+    assert_eq!(CONFIG.origin, (1, -2));
+    assert_eq!(CONFIG.workers, NonZeroU16::new(4));
+    assert_eq!(CONFIG.level, Level::Warn);
+    assert!(CONFIG.names.is_empty());
+    assert_eq!(CONFIG.retries, if cfg!(debug_assertions) { 1 } else { 3 });
+}
+
+#[test]
+fn const_default_matches_default() {
+    let config = Config::default();
+    assert_eq!(config.port, CONFIG.port);
+    assert_eq!(config.weights, CONFIG.weights);
+    assert_eq!(config.level, CONFIG.level);
+}
+
+#[test]
+fn const_default_enum() {
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    #[specified_default(default_const)]
+    enum Foo {
+        Bar,
+        #[default]
+        Baz,
+    }
+
+    const FOO: Foo = Foo::default_const();
+    assert_eq!(FOO, Foo::Baz);
+}
+
+#[test]
+fn const_default_variant_fields() {
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    #[specified_default(variant_defaults, default_const)]
+    enum Transport {
+        #[default]
+        Tcp {
+            #[default = "80"]
+            port: u16,
+            #[default(empty)]
+            hosts: Vec<String>,
+        },
+        Unix(String),
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    #[specified_default(variant_defaults, default_const)]
+    enum Slot {
+        Empty,
+        #[default]
+        Full(#[default = "7"] u8, #[default = "(1, 2)"] (i32, i32)),
+    }
+
+    const TRANSPORT: Transport = Transport::default_const();
+    const SLOT: Slot = Slot::default_const();
+    assert_eq!(TRANSPORT, Transport::Tcp { port: 80, hosts: Vec::new() });
+    assert_eq!(TRANSPORT, Transport::default());
+    assert_eq!(SLOT, Slot::Full(7, (1, 2)));
+}
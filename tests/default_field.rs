@@ -0,0 +1,47 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::marker::PhantomData;
+
+#[allow(dead_code)]
+#[derive(SpecifiedDefault)]
+#[specified_default(default_field)]
+struct Window {
+    #[default = "640"]
+    width: u32,
+    #[default = "Untitled"]
+    title: String,
+    tags: Vec<String>,
+}
+
+#[test]
+fn fields_are_looked_up_by_name() {
+    let width = Window::default_field("width").unwrap();
+    assert_eq!(width.downcast_ref::<u32>(), Some(&640));
+
+    let title = Window::default_field("title").unwrap();
+    assert_eq!(title.downcast_ref::<String>().map(String::as_str), Some("Untitled"));
+
+    let tags = Window::default_field("tags").unwrap();
+    assert!(tags.downcast_ref::<Vec<String>>().unwrap().is_empty());
+}
+
+#[test]
+fn unknown_fields_are_none() {
+    assert!(Window::default_field("height").is_none());
+}
+
+#[test]
+fn generic_fields() {
+    #[allow(dead_code)]
+    #[derive(SpecifiedDefault)]
+    #[specified_default(default_field)]
+    struct Slot<T> {
+        #[default = "3"]
+        value: T,
+        marker: PhantomData<T>,
+    }
+
+    let value = Slot::<u64>::default_field("value").unwrap();
+    assert_eq!(value.downcast_ref::<u64>(), Some(&3));
+}
@@ -0,0 +1,36 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[test]
+fn default_ref_struct() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(default_ref)]
+    struct Foo {
+        #[default = "42"]
+        bar: u32,
+        #[default = "baz"]
+        baz: String,
+    }
+
+    let first = Foo::default_ref();
+    let second = Foo::default_ref();
+
+    assert_eq!(first.bar, 42);
+    assert_eq!(first.baz, "baz");
+    assert!(std::ptr::eq(first, second));
+}
+
+#[test]
+fn default_ref_enum() {
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    #[specified_default(default_ref)]
+    enum Foo {
+        Bar,
+
+        #[default]
+        Baz,
+    }
+
+    assert_eq!(*Foo::default_ref(), Foo::Baz);
+}
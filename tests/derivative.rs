@@ -0,0 +1,49 @@
+#![cfg(feature = "derivative")]
+
+#[macro_use]
+extern crate specified_default_derive;
+
+#[test]
+fn derivative_field_values() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[derivative(Default(value = "21 * 2"), Debug = "ignore")]
+        bar: u32,
+        #[derivative(Default(value = "String::from(\"baz\")"))]
+        baz: String,
+        #[default = "qux"]
+        qux: String,
+        quux: Vec<u8>,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar, 42);
+    assert_eq!(foo.baz, "baz");
+    assert_eq!(foo.qux, "qux");
+    assert!(foo.quux.is_empty());
+}
+
+#[test]
+fn specified_default_takes_precedence() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "1"]
+        #[derivative(Default(value = "2"))]
+        bar: u32,
+    }
+
+    assert_eq!(Foo::default().bar, 1);
+}
+
+#[test]
+fn derivative_default_variant() {
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    enum Foo {
+        Bar,
+        #[derivative(Default)]
+        Baz,
+    }
+
+    assert_eq!(Foo::default(), Foo::Baz);
+}
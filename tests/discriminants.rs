@@ -0,0 +1,45 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[test]
+fn explicit_discriminant() {
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    #[specified_default(discriminant = 0x10)]
+    enum Foo {
+        Bar = 0x01,
+        Baz = 0x10,
+        Qux = 0x20,
+    }
+
+    assert_eq!(Foo::default(), Foo::Baz);
+}
+
+#[test]
+fn implicit_discriminant() {
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    #[specified_default(discriminant = 7)]
+    enum Foo {
+        Bar = 5,
+        Baz,
+        Qux,
+    }
+
+    assert_eq!(Foo::default(), Foo::Qux);
+}
+
+#[test]
+fn negative_discriminant() {
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    #[specified_default(discriminant = -1)]
+    #[repr(i8)]
+    enum Foo {
+        Bar = -2,
+        Baz,
+        Qux,
+    }
+
+    assert_eq!(Foo::default(), Foo::Baz);
+}
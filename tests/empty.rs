@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+#[test]
+fn empty_containers() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(empty)]
+        bar: Vec<u32>,
+        #[default(empty)]
+        baz: String,
+        #[default(empty)]
+        qux: HashMap<String, u32>,
+        #[default(empty)]
+        quux: BTreeSet<u8>,
+        #[default(empty)]
+        corge: VecDeque<String>,
+    }
+
+    let foo = Foo::default();
+    assert!(foo.bar.is_empty());
+    assert!(foo.baz.is_empty());
+    assert!(foo.qux.is_empty());
+    assert!(foo.quux.is_empty());
+    assert!(foo.corge.is_empty());
+}
+
+#[test]
+fn empty_generic_container() {
+    #[derive(SpecifiedDefault)]
+    struct Foo<T> {
+        #[default(empty)]
+        bar: Vec<T>,
+    }
+
+    struct NoDefault;
+
+    assert!(Foo::<NoDefault>::default().bar.is_empty());
+}
+
+#[test]
+fn preallocated_containers() {
+    const QUEUE_LENGTH: usize = 32;
+
+    #[derive(SpecifiedDefault)]
+    struct Foo<T> {
+        #[default(capacity = "1024")]
+        bar: Vec<T>,
+        #[default(capacity = "64")]
+        baz: String,
+        #[default(capacity = "16")]
+        qux: HashMap<String, u32>,
+        #[default(capacity = "QUEUE_LENGTH * 2")]
+        corge: VecDeque<String>,
+    }
+
+    struct NoDefault;
+
+    let foo = Foo::<NoDefault>::default();
+    assert!(foo.bar.is_empty() && foo.bar.capacity() >= 1024);
+    assert!(foo.baz.capacity() >= 64);
+    assert!(foo.qux.capacity() >= 16);
+    assert!(foo.corge.capacity() >= 64);
+}
@@ -0,0 +1,45 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::env;
+
+#[test]
+fn env_prefix() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(env_prefix = "SPECIFIED_DEFAULT_ENV_PREFIX_")]
+    struct Window {
+        #[default = "640"]
+        width: u32,
+        #[default = "480"]
+        height: u32,
+
+        title: String,
+    }
+
+    env::set_var("SPECIFIED_DEFAULT_ENV_PREFIX_HEIGHT", "720");
+    env::set_var("SPECIFIED_DEFAULT_ENV_PREFIX_TITLE", "main");
+
+    let result = Window::default_from_env();
+    assert_eq!(result.width, 640);
+    assert_eq!(result.height, 720);
+    assert_eq!(result.title, "main");
+
+    let result = Window::default();
+    assert_eq!(result.height, 480);
+}
+
+#[test]
+#[should_panic(expected = "Failed to parse wide from SPECIFIED_DEFAULT_ENV_INVALID_WIDTH")]
+fn env_invalid_value() {
+    #[allow(dead_code)]
+    #[derive(SpecifiedDefault)]
+    #[specified_default(env_prefix = "SPECIFIED_DEFAULT_ENV_INVALID_")]
+    struct Window {
+        #[default = "640"]
+        width: u32,
+    }
+
+    env::set_var("SPECIFIED_DEFAULT_ENV_INVALID_WIDTH", "wide");
+
+    Window::default_from_env();
+}
@@ -0,0 +1,71 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::env;
+
+#[test]
+fn env_selects_variant() {
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    #[specified_default(env = "SPECIFIED_DEFAULT_ENV_VARIANT_MODE")]
+    enum Mode {
+        Development,
+        #[default]
+        Production,
+        Test,
+    }
+
+    env::set_var("SPECIFIED_DEFAULT_ENV_VARIANT_MODE", "development");
+    assert_eq!(Mode::default(), Mode::Development);
+
+    env::set_var("SPECIFIED_DEFAULT_ENV_VARIANT_MODE", "TEST");
+    assert_eq!(Mode::default(), Mode::Test);
+
+    env::set_var("SPECIFIED_DEFAULT_ENV_VARIANT_MODE", "staging");
+    assert_eq!(Mode::default(), Mode::Production);
+}
+
+#[test]
+fn env_unset_falls_back() {
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    #[specified_default(env = "SPECIFIED_DEFAULT_ENV_VARIANT_UNSET")]
+    enum Mode {
+        #[default]
+        Fast,
+        Safe,
+        Custom(u32),
+    }
+
+    env::remove_var("SPECIFIED_DEFAULT_ENV_VARIANT_UNSET");
+    assert_eq!(Mode::default(), Mode::Fast);
+
+    // Variants with fields can't be named by the variable
+    env::set_var("SPECIFIED_DEFAULT_ENV_VARIANT_UNSET", "custom");
+    assert_eq!(Mode::default(), Mode::Fast);
+}
+
+#[test]
+fn env_selects_variant_with_defaults() {
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    #[specified_default(env = "SPECIFIED_DEFAULT_ENV_VARIANT_TRANSPORT", variant_defaults)]
+    enum Transport {
+        #[default]
+        Tcp {
+            #[default = "80"]
+            port: u16,
+        },
+        Unix(#[default = "/tmp/app.sock"] String),
+        InMemory,
+    }
+
+    env::remove_var("SPECIFIED_DEFAULT_ENV_VARIANT_TRANSPORT");
+    assert_eq!(Transport::default(), Transport::Tcp { port: 80 });
+
+    env::set_var("SPECIFIED_DEFAULT_ENV_VARIANT_TRANSPORT", "unix");
+    assert_eq!(Transport::default(), Transport::Unix("/tmp/app.sock".to_owned()));
+
+    env::set_var("SPECIFIED_DEFAULT_ENV_VARIANT_TRANSPORT", "InMemory");
+    assert_eq!(Transport::default(), Transport::InMemory);
+}
@@ -0,0 +1,52 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+const LIMIT: u32 = 10;
+
+#[test]
+fn call_expressions() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(Vec::with_capacity(16))]
+        bar: Vec<u8>,
+        #[default(String::from("baz"))]
+        baz: String,
+    }
+
+    let foo = Foo::default();
+    assert!(foo.bar.capacity() >= 16);
+    assert_eq!(foo.baz, "baz");
+}
+
+#[test]
+fn operator_expressions() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(1 + 2)]
+        bar: u32,
+        #[default(LIMIT * 2)]
+        baz: u32,
+        #[default(-1)]
+        qux: i32,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar, 3);
+    assert_eq!(foo.baz, 20);
+    assert_eq!(foo.qux, -1);
+}
+
+#[test]
+fn path_expressions() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(LIMIT)]
+        bar: u32,
+        #[default(None)]
+        baz: Option<String>,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar, LIMIT);
+    assert_eq!(foo.baz, None);
+}
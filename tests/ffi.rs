@@ -0,0 +1,38 @@
+#![cfg(feature = "ffi")]
+
+#[macro_use]
+extern crate specified_default_derive;
+
+#[repr(C)]
+#[derive(Debug, PartialEq, SpecifiedDefault)]
+#[specified_default(ffi)]
+pub struct EncoderConfig {
+    #[default = "44100"]
+    pub sample_rate: u32,
+    #[default = "2"]
+    pub channels: u8,
+    #[default = "0.8"]
+    pub quality: f32,
+}
+
+#[repr(transparent)]
+#[derive(Debug, PartialEq, SpecifiedDefault)]
+#[specified_default(ffi)]
+pub struct Timeout {
+    #[default = "30"]
+    pub seconds: u64,
+}
+
+// What a C caller links against, declared as its header would
+extern "C" {
+    fn encoder_config_default() -> EncoderConfig;
+    fn timeout_default() -> Timeout;
+}
+
+#[test]
+fn exported_functions_give_the_default() {
+    let config = unsafe { encoder_config_default() };
+    assert_eq!(config, EncoderConfig::default());
+    assert_eq!(config.sample_rate, 44100);
+    assert_eq!(unsafe { timeout_default() }, Timeout { seconds: 30 });
+}
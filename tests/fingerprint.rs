@@ -0,0 +1,52 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[allow(dead_code)]
+#[derive(SpecifiedDefault)]
+#[specified_default(fingerprint)]
+struct Window {
+    #[default = "640"]
+    width: u32,
+    title: String,
+}
+
+mod changed {
+    #[allow(dead_code)]
+    #[derive(SpecifiedDefault)]
+    #[specified_default(fingerprint)]
+    pub struct Window {
+        #[default = "800"]
+        width: u32,
+        title: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(SpecifiedDefault)]
+    #[specified_default(fingerprint)]
+    pub struct Renamed {
+        #[default = "640"]
+        width: u32,
+        title: String,
+    }
+}
+
+#[allow(dead_code)]
+#[derive(SpecifiedDefault)]
+#[specified_default(fingerprint)]
+enum Level {
+    Debug,
+    #[default]
+    Info,
+}
+
+#[test]
+fn fingerprint_is_stable() {
+    assert_eq!(Window::DEFAULTS_FINGERPRINT, changed::Renamed::DEFAULTS_FINGERPRINT);
+    assert_eq!(Window::DEFAULTS_FINGERPRINT, 0xa990_47f4_a0b4_e2ca);
+}
+
+#[test]
+fn fingerprint_changes_with_the_defaults() {
+    assert_ne!(Window::DEFAULTS_FINGERPRINT, changed::Window::DEFAULTS_FINGERPRINT);
+    assert_ne!(Window::DEFAULTS_FINGERPRINT, Level::DEFAULTS_FINGERPRINT);
+}
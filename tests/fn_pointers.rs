@@ -0,0 +1,37 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+mod handlers {
+    pub fn noop(request: &str) -> String {
+        request.to_string()
+    }
+
+    pub fn shout(request: &str) -> String {
+        request.to_uppercase()
+    }
+}
+
+#[test]
+fn fn_pointer_from_path() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(path = "handlers::shout")]
+        bar: fn(&str) -> String,
+    }
+
+    assert_eq!((Foo::default().bar)("baz"), "BAZ");
+}
+
+#[test]
+fn optional_fn_pointer_from_path() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(path = "handlers::noop")]
+        bar: Option<fn(&str) -> String>,
+        baz: Option<fn(&str) -> String>,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar.map(|bar| bar("qux")), Some("qux".to_string()));
+    assert!(foo.baz.is_none());
+}
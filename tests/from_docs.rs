@@ -0,0 +1,51 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[test]
+fn defaults_from_doc_comments() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(from_docs)]
+    struct Window {
+        /// The width of the window in pixels.
+        ///
+        /// Default: 640
+        width: u32,
+        /// Default: `480`
+        height: u32,
+        /// The title, which has no documented default.
+        title: String,
+    }
+
+    let window = Window::default();
+    assert_eq!(window.width, 640);
+    assert_eq!(window.height, 480);
+    assert_eq!(window.title, "");
+}
+
+#[test]
+fn matching_attribute_and_docs() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(from_docs)]
+    struct Foo {
+        /// Default: 8080
+        #[default = "8080"]
+        port: u16,
+        /** Default: localhost */
+        host: String,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.port, 8080);
+    assert_eq!(foo.host, "localhost");
+}
+
+#[test]
+fn docs_ignored_without_from_docs() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        /// Default: 640
+        width: u32,
+    }
+
+    assert_eq!(Foo::default().width, 0);
+}
@@ -0,0 +1,63 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(Debug, FromStrWithDefaults, PartialEq, SpecifiedDefault)]
+struct Server {
+    #[default = "localhost"]
+    host: String,
+    #[default = "8080"]
+    port: u16,
+    verbose: bool,
+}
+
+#[test]
+fn backfills_missing_keys() {
+    let server: Server = "port=9090".parse().unwrap();
+    assert_eq!(server, Server { host: "localhost".to_owned(), port: 9090, verbose: false });
+}
+
+#[test]
+fn parses_every_key() {
+    let server: Server = " host = example.com, port=443 ,verbose=true ".parse().unwrap();
+    assert_eq!(server, Server { host: "example.com".to_owned(), port: 443, verbose: true });
+}
+
+#[test]
+fn empty_is_default() {
+    assert_eq!("".parse::<Server>(), Ok(Server::default()));
+    assert_eq!(",".parse::<Server>(), Ok(Server::default()));
+}
+
+#[test]
+fn errors() {
+    assert_eq!("timeout=5".parse::<Server>(), Err("unknown key `timeout`".to_owned()));
+    assert_eq!("verbose".parse::<Server>(), Err("expected `key=value`, found `verbose`".to_owned()));
+    assert_eq!("port=1,port=2".parse::<Server>(), Err("`port` is given more than once".to_owned()));
+    assert_eq!(
+        "port=http".parse::<Server>(),
+        Err("invalid value for `port`: invalid digit found in string".to_owned()),
+    );
+}
+
+#[test]
+fn generic() {
+    #[derive(Debug, FromStrWithDefaults, PartialEq, SpecifiedDefault)]
+    struct Range<T> {
+        #[default = "0"]
+        start: u32,
+        end: T,
+    }
+
+    assert_eq!("end=2.5".parse::<Range<f64>>(), Ok(Range { start: 0, end: 2.5 }));
+}
+
+#[test]
+fn raw_keys() {
+    #[derive(Debug, FromStrWithDefaults, PartialEq, SpecifiedDefault)]
+    struct Token {
+        #[default = "ident"]
+        r#type: String,
+    }
+
+    assert_eq!("type=literal".parse::<Token>(), Ok(Token { r#type: "literal".to_owned() }));
+}
@@ -0,0 +1,34 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::net::SocketAddr;
+
+#[allow(dead_code)]
+#[derive(SpecifiedDefault)]
+#[specified_default(generate_tests)]
+struct Server {
+    #[default = "127.0.0.1:8080"]
+    address: SocketAddr,
+    #[default(debug = "1", release = "8")]
+    workers: u32,
+    name: String,
+}
+
+#[allow(dead_code)]
+#[derive(SpecifiedDefault)]
+#[specified_default(generate_tests)]
+enum Mode {
+    Fast,
+
+    #[default]
+    Safe,
+}
+
+// The generated modules add `constructs_default` and `parses_*` tests
+// alongside this one.
+#[test]
+fn defaults_are_unchanged() {
+    let server = Server::default();
+    assert_eq!(server.address, "127.0.0.1:8080".parse::<SocketAddr>().unwrap());
+    assert_eq!(server.name, "");
+}
@@ -0,0 +1,100 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[test]
+fn default_type_parameter() {
+    #[derive(SpecifiedDefault)]
+    struct Grid<T = f32> {
+        #[default = "0.5"]
+        cell: T,
+    }
+
+    let grid: Grid = Grid::default();
+    assert_eq!(grid.cell, 0.5);
+
+    let grid = Grid::<f64>::default();
+    assert_eq!(grid.cell, 0.5);
+}
+
+#[test]
+fn generic_without_default() {
+    #[derive(SpecifiedDefault)]
+    struct Foo<T> {
+        bar: Vec<T>,
+
+        #[default = "42"]
+        baz: u32,
+    }
+
+    struct NotDefault;
+
+    let result = Foo::<NotDefault>::default();
+    assert!(result.bar.is_empty());
+    assert_eq!(result.baz, 42);
+}
+
+#[test]
+fn lifetimes_and_where_clauses() {
+    #[derive(SpecifiedDefault)]
+    struct Foo<'a, T>
+    where
+        T: Copy,
+    {
+        bar: Option<&'a T>,
+
+        #[default = "7"]
+        baz: T,
+    }
+
+    let result = Foo::<u8>::default();
+    assert!(result.bar.is_none());
+    assert_eq!(result.baz, 7);
+}
+
+#[test]
+fn generic_via() {
+    struct Port(u16);
+
+    impl From<u16> for Port {
+        fn from(port: u16) -> Port {
+            Port(port)
+        }
+    }
+
+    #[derive(SpecifiedDefault)]
+    struct Foo<T> {
+        #[default(via = "u16", value = "80")]
+        port: T,
+    }
+
+    assert_eq!(Foo::<Port>::default().port.0, 80);
+}
+
+#[test]
+fn generic_enum() {
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    enum Foo<T> {
+        Bar(T),
+
+        #[default]
+        Baz,
+    }
+
+    assert_eq!(Foo::<u32>::default(), Foo::Baz);
+}
+
+#[test]
+fn generic_containers() {
+    #[derive(SpecifiedDefault)]
+    struct Foo<T> {
+        #[default = "[1, 2]"]
+        bar: Option<Vec<T>>,
+        #[default = "(3, 4)"]
+        baz: (T, u8),
+    }
+
+    let result = Foo::<u16>::default();
+    assert_eq!(result.bar, Some(vec![1, 2]));
+    assert_eq!(result.baz, (3, 4));
+}
@@ -0,0 +1,48 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+struct Config {
+    #[default(group = "network", value = "localhost")]
+    host: String,
+    #[default(group = "network", value = "8080")]
+    port: u16,
+    #[default(group = "logging", value = "info")]
+    level: String,
+    #[default(group = "logging")]
+    targets: Vec<String>,
+    #[default = "4"]
+    workers: usize,
+}
+
+fn changed() -> Config {
+    Config {
+        host: "example.com".to_owned(),
+        port: 1,
+        level: "trace".to_owned(),
+        targets: vec!["stderr".to_owned()],
+        workers: 16,
+    }
+}
+
+#[test]
+fn groups_are_reset_independently() {
+    let mut config = changed();
+    config.apply_network_defaults();
+    assert_eq!(config.host, "localhost");
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.level, "trace");
+    assert_eq!(config.workers, 16);
+
+    config.apply_logging_defaults();
+    assert_eq!(config.level, "info");
+    assert!(config.targets.is_empty());
+    assert_eq!(config.workers, 16);
+}
+
+#[test]
+fn grouped_fields_keep_their_defaults() {
+    let config = Config::default();
+    assert_eq!(config.port, 8080);
+    assert!(config.targets.is_empty());
+}
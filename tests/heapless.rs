@@ -0,0 +1,57 @@
+#[macro_use]
+extern crate specified_default_derive;
+extern crate heapless;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(no_panic)]
+struct Firmware {
+    #[default = "[1, 2, 3]"]
+    pins: heapless::Vec<u8, 4>,
+    #[default = "sensor-01"]
+    name: heapless::String<16>,
+    #[default = "[]"]
+    empty: heapless::Vec<u16, 2>,
+}
+
+#[test]
+fn collections_are_filled() {
+    let firmware = Firmware::default();
+    assert_eq!(&firmware.pins[..], &[1, 2, 3]);
+    assert_eq!(firmware.name.as_str(), "sensor-01");
+    assert!(firmware.empty.is_empty());
+}
+
+#[test]
+fn const_capacities() {
+    #[derive(SpecifiedDefault)]
+    struct Buffer<const N: usize> {
+        #[default = "[7, 8]"]
+        bytes: heapless::Vec<u8, N>,
+    }
+
+    assert_eq!(&Buffer::<4>::default().bytes[..], &[7, 8]);
+}
+
+#[test]
+#[should_panic(expected = "the default doesn't fit in the capacity of `heapless::Vec<u8,N>`")]
+fn const_capacities_too_small() {
+    #[derive(SpecifiedDefault)]
+    struct Buffer<const N: usize> {
+        #[default = "[7, 8, 9]"]
+        bytes: heapless::Vec<u8, N>,
+    }
+
+    let _ = Buffer::<2>::default().bytes;
+}
+
+#[test]
+#[should_panic(expected = "the default doesn't fit in the capacity of `heapless::String<N>`")]
+fn const_string_capacities_too_small() {
+    #[derive(SpecifiedDefault)]
+    struct Label<const N: usize> {
+        #[default = "sensor-01"]
+        name: heapless::String<N>,
+    }
+
+    let _ = Label::<4>::default().name;
+}
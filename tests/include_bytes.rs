@@ -0,0 +1,29 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::borrow::Cow;
+
+#[derive(SpecifiedDefault)]
+struct Assets {
+    #[default(include_bytes = "assets/header.bin")]
+    header: Vec<u8>,
+    #[default(include_bytes = "assets/header.bin")]
+    magic: [u8; 6],
+    #[default(include_bytes = "assets/header.bin")]
+    borrowed: &'static [u8],
+    #[default(include_bytes = "assets/header.bin")]
+    cow: Cow<'static, [u8]>,
+}
+
+#[test]
+fn files_are_included() {
+    let assets = Assets::default();
+    assert_eq!(assets.header, b"GIF89a");
+    assert_eq!(&assets.magic, b"GIF89a");
+    assert_eq!(assets.borrowed, b"GIF89a");
+}
+
+#[test]
+fn included_bytes_are_borrowed_when_possible() {
+    assert!(matches!(Assets::default().cow, Cow::Borrowed(b"GIF89a")));
+}
@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::borrow::Cow;
+
+#[derive(SpecifiedDefault)]
+struct Mailer {
+    #[default(include_str = "assets/welcome.html")]
+    welcome: String,
+    #[default(include_str = "assets/welcome.html")]
+    borrowed: &'static str,
+    #[default(include_str = "assets/welcome.html")]
+    cow: Cow<'static, str>,
+}
+
+#[test]
+fn files_are_included() {
+    let mailer = Mailer::default();
+    assert_eq!(mailer.welcome, "<h1>Welcome</h1>\n");
+    assert_eq!(mailer.borrowed, "<h1>Welcome</h1>\n");
+}
+
+#[test]
+fn included_text_is_borrowed_when_possible() {
+    assert!(matches!(Mailer::default().cow, Cow::Borrowed("<h1>Welcome</h1>\n")));
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "indexmap")]
+
+#[macro_use]
+extern crate specified_default_derive;
+extern crate indexmap;
+
+use indexmap::{IndexMap, IndexSet};
+
+#[derive(SpecifiedDefault)]
+struct Routes {
+    #[default = "{/users = users, /health = health, /admin = admin}"]
+    handlers: IndexMap<String, String>,
+    #[default = "[zeta, alpha, mid]"]
+    tags: IndexSet<&'static str>,
+    #[default = "{3 = [c], 1 = [a, b]}"]
+    groups: indexmap::IndexMap<u8, Vec<char>>,
+}
+
+#[test]
+fn entries_keep_their_order() {
+    let routes = Routes::default();
+    assert_eq!(routes.handlers.keys().collect::<Vec<_>>(), ["/users", "/health", "/admin"]);
+    assert_eq!(routes.handlers["/health"], "health");
+    assert_eq!(routes.tags.iter().copied().collect::<Vec<_>>(), ["zeta", "alpha", "mid"]);
+    assert_eq!(routes.groups.get_index(0), Some((&3, &vec!['c'])));
+    assert_eq!(routes.groups[&1], ['a', 'b']);
+}
@@ -0,0 +1,40 @@
+#![cfg(all(feature = "compact_str", feature = "smol_str"))]
+
+extern crate compact_str;
+extern crate smol_str;
+#[macro_use]
+extern crate specified_default_derive;
+
+use compact_str::CompactString;
+use smol_str::SmolStr;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(no_panic, default_const)]
+struct Token {
+    #[default = "ident"]
+    kind: SmolStr,
+    #[default = "<anonymous>"]
+    name: CompactString,
+    #[default = "a description far too long to be held inline by either type"]
+    doc: smol_str::SmolStr,
+    #[default = "[let, fn]"]
+    keywords: [CompactString; 2],
+}
+
+#[test]
+fn inline_strings() {
+    let token = Token::default();
+    assert_eq!(token.kind, "ident");
+    assert!(!token.kind.is_heap_allocated());
+    assert_eq!(token.name, "<anonymous>");
+    assert!(!token.name.is_heap_allocated());
+    assert!(!token.doc.is_heap_allocated());
+    assert_eq!(token.keywords, ["let", "fn"]);
+}
+
+#[test]
+fn inline_strings_in_const_default() {
+    const TOKEN: Token = Token::default_const();
+    assert_eq!(TOKEN.kind, "ident");
+    assert_eq!(TOKEN.name, "<anonymous>");
+}
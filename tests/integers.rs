@@ -0,0 +1,58 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(default_const)]
+struct Extremes {
+    #[default = "340282366920938463463374607431768211455"]
+    u128_max: u128,
+    #[default = "-170141183460469231731687303715884105728"]
+    i128_min: i128,
+    #[default = "170141183460469231731687303715884105727"]
+    i128_max: i128,
+    #[default = "18446744073709551616"]
+    above_u64: u128,
+    #[default = "0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff"]
+    u128_hex: u128,
+    #[default = "-128"]
+    i8_min: i8,
+    #[default = "-0"]
+    negative_zero: u8,
+    #[default = "18446744073709551615"]
+    usize_max: usize,
+    #[default = "-9223372036854775808"]
+    isize_min: isize,
+    #[default = "7u8"]
+    suffixed: u8,
+    #[default = "-3_i64"]
+    suffixed_negative: i64,
+    #[default = "0b1010"]
+    binary: u16,
+}
+
+const EXTREMES: Extremes = Extremes::default_const();
+
+#[test]
+fn const_literals() {
+    check(&EXTREMES);
+}
+
+#[test]
+fn default_literals() {
+    check(&Extremes::default());
+}
+
+fn check(extremes: &Extremes) {
+    assert_eq!(extremes.u128_max, u128::MAX);
+    assert_eq!(extremes.i128_min, i128::MIN);
+    assert_eq!(extremes.i128_max, i128::MAX);
+    assert_eq!(extremes.above_u64, u128::from(u64::MAX) + 1);
+    assert_eq!(extremes.u128_hex, u128::MAX);
+    assert_eq!(extremes.i8_min, i8::MIN);
+    assert_eq!(extremes.negative_zero, 0);
+    assert_eq!(extremes.usize_max as u64, u64::MAX);
+    assert_eq!(extremes.isize_min as i64, i64::MIN);
+    assert_eq!(extremes.suffixed, 7);
+    assert_eq!(extremes.suffixed_negative, -3);
+    assert_eq!(extremes.binary, 10);
+}
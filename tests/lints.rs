@@ -0,0 +1,40 @@
+#![deny(warnings, unused_qualifications)]
+#![deny(clippy::pedantic)]
+
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(overrides, default_ref, env_prefix = "SPECIFIED_DEFAULT_LINTS_")]
+pub struct Server {
+    #[default = "8080"]
+    pub port: u16,
+    #[default = "localhost"]
+    pub host: String,
+    pub name: String,
+}
+
+#[derive(SpecifiedDefault)]
+#[specified_default(default_const)]
+pub struct Limits {
+    #[default = "localhost"]
+    pub host: &'static str,
+    #[default(empty)]
+    pub names: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, SpecifiedDefault)]
+#[specified_default(env = "SPECIFIED_DEFAULT_LINTS_MODE")]
+pub enum Mode {
+    #[default]
+    Fast,
+    Safe,
+}
+
+#[test]
+fn generated_code_passes_strict_lints() {
+    assert_eq!(Server::default().port, 8080);
+    assert_eq!(Server::default_ref().host, "localhost");
+    assert_eq!(Limits::default_const().host, "localhost");
+    assert_eq!(Mode::default(), Mode::Fast);
+}
@@ -0,0 +1,98 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+macro_rules! settings {
+    ($name:ident { $($field:ident: $ty:ty = $value:expr),* $(,)* }) => {
+        #[derive(SpecifiedDefault)]
+        struct $name {
+            $(
+                #[default = $value]
+                $field: $ty,
+            )*
+        }
+    };
+}
+
+macro_rules! literal_settings {
+    ($name:ident { $($field:ident: $ty:ty = $value:literal),* $(,)* }) => {
+        #[derive(SpecifiedDefault)]
+        struct $name {
+            $(
+                #[default(value = $value)]
+                $field: $ty,
+            )*
+        }
+    };
+}
+
+macro_rules! with_attrs {
+    ($(#[$attr:meta])* $name:ident { $($(#[$field_attr:meta])* $field:ident: $ty:ty),* $(,)* }) => {
+        #[derive(SpecifiedDefault)]
+        $(#[$attr])*
+        struct $name {
+            $(
+                $(#[$field_attr])*
+                $field: $ty,
+            )*
+        }
+    };
+}
+
+macro_rules! modes {
+    ($name:ident { $($(#[$attr:meta])* $variant:ident),* $(,)* }) => {
+        #[allow(dead_code)]
+        #[derive(Debug, PartialEq, SpecifiedDefault)]
+        enum $name {
+            $($(#[$attr])* $variant),*
+        }
+    };
+}
+
+settings!(Server {
+    port: u16 = "8080",
+    hosts: Vec<String> = "[localhost, example.com]",
+    ratio: Option<f32> = "0.5",
+    pair: (u8, char) = "(1, x)",
+});
+
+literal_settings!(Client {
+    retries: u8 = "3",
+    limits: [u32; 2] = "[10, 20]",
+});
+
+with_attrs!(
+    #[specified_default(no_panic, default_const)]
+    Worker {
+        #[default = "4"]
+        threads: usize,
+        #[default(value = "[1, 2]")]
+        shards: [u64; 2],
+    }
+);
+
+modes!(Mode { Fast, #[default] Safe });
+
+#[test]
+fn expr_fragments() {
+    let server = Server::default();
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.hosts, vec!["localhost".to_owned(), "example.com".to_owned()]);
+    assert_eq!(server.ratio, Some(0.5));
+    assert_eq!(server.pair, (1, 'x'));
+}
+
+#[test]
+fn literal_fragments() {
+    let client = Client::default();
+    assert_eq!(client.retries, 3);
+    assert_eq!(client.limits, [10, 20]);
+}
+
+#[test]
+fn meta_fragments() {
+    let worker = Worker::default();
+    assert_eq!(worker.threads, 4);
+    assert_eq!(worker.shards, [1, 2]);
+    assert_eq!(Worker::default_const().threads, 4);
+    assert_eq!(Mode::default(), Mode::Safe);
+}
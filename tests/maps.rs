@@ -0,0 +1,54 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+#[test]
+fn map_entries() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "{a = 1, b = 2}"]
+        bar: HashMap<&'static str, u8>,
+        #[default = "\"x = y\" = \"1, 2\", z = [3]"]
+        baz: BTreeMap<String, Vec<u16>>,
+        #[default = "{}"]
+        qux: HashMap<u8, u8>,
+        #[default = "{1 = {2 = c}}"]
+        nested: BTreeMap<u8, BTreeMap<u8, char>>,
+        #[default = "{port = 8080}"]
+        optional: Option<HashMap<&'static str, u16>>,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar, vec![("a", 1), ("b", 2)].into_iter().collect());
+    assert_eq!(foo.baz, vec![("x = y".to_owned(), vec![1, 2]), ("z".to_owned(), vec![3])].into_iter().collect());
+    assert!(foo.qux.is_empty());
+    assert_eq!(foo.nested[&1][&2], 'c');
+    assert_eq!(foo.optional.unwrap()["port"], 8080);
+}
+
+#[test]
+fn set_elements() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "[80, 443]"]
+        bar: HashSet<u16>,
+        #[default = "b, a"]
+        baz: BTreeSet<String>,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar, vec![80, 443].into_iter().collect());
+    assert_eq!(foo.baz.into_iter().collect::<Vec<_>>(), vec!["a".to_owned(), "b".to_owned()]);
+}
+
+#[test]
+fn generic_entries() {
+    #[derive(SpecifiedDefault)]
+    struct Foo<K: Ord, V> {
+        #[default = "{1 = 2}"]
+        bar: BTreeMap<K, V>,
+    }
+
+    assert_eq!(Foo::<u8, i64>::default().bar[&1], 2);
+}
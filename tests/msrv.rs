@@ -0,0 +1,61 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::sync::Arc;
+
+#[test]
+fn msrv_allows_older_features() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(msrv = "1.36", default_const)]
+    struct Limits {
+        #[default = "1024"]
+        max_connections: u32,
+    }
+
+    #[derive(SpecifiedDefault)]
+    #[specified_default(msrv = "1.36")]
+    struct Buffer {
+        #[default(zeroed)]
+        table: [u8; 16],
+    }
+
+    assert_eq!(Limits::default().max_connections, 1024);
+    assert_eq!(Limits::default_const().max_connections, 1024);
+    assert_eq!(Buffer::default().table, [0; 16]);
+}
+
+#[test]
+#[allow(deprecated)]
+fn msrv_before_anonymous_consts() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(msrv = "1.36", assert_defaults, redundant_defaults = "warn")]
+    struct Cache {
+        #[default = "0"]
+        hits: u64,
+        #[default = "64"]
+        capacity: usize,
+    }
+
+    #[derive(SpecifiedDefault)]
+    #[specified_default(msrv = "1.36", redundant_defaults = "warn")]
+    struct Queue {
+        #[default = "0"]
+        len: usize,
+    }
+
+    Cache::default().assert_specified_defaults();
+    assert_eq!(Cache::default().capacity, 64);
+    assert_eq!(Queue::default().len, 0);
+}
+
+#[test]
+fn msrv_allows_newer_features_on_newer_versions() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(msrv = "1.70.0", default_ref)]
+    struct Renderer {
+        #[default(shared, value = "[1, 2, 4]")]
+        table: Arc<Vec<u64>>,
+    }
+
+    assert_eq!(*Renderer::default_ref().table, [1, 2, 4]);
+}
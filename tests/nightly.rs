@@ -0,0 +1,9 @@
+#![cfg(feature = "nightly")]
+
+extern crate trybuild;
+
+#[test]
+fn warnings() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/nightly/*.rs");
+}
@@ -0,0 +1,50 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::num::NonZeroU32;
+
+#[test]
+fn literal_defaults() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(no_panic)]
+    struct Foo {
+        #[default = "8080"]
+        bar: u16,
+        #[default = "[0.5, 1.5]"]
+        baz: Vec<f32>,
+        #[default = "(true, x)"]
+        qux: (bool, char),
+        #[default = "4"]
+        quux: Option<Box<NonZeroU32>>,
+        #[default = "localhost"]
+        host: &'static str,
+        #[default(via = "&str", value = "name")]
+        name: String,
+        corge: String,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar, 8080);
+    assert_eq!(foo.baz, vec![0.5, 1.5]);
+    assert_eq!(foo.qux, (true, 'x'));
+    assert_eq!(foo.quux, NonZeroU32::new(4).map(Box::new));
+    assert_eq!(foo.host, "localhost");
+    assert_eq!(foo.name, "name");
+    assert_eq!(foo.corge, "");
+}
+
+#[test]
+fn literal_conversions() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(no_panic)]
+    struct Foo {
+        #[default(via = "u32", value = "7")]
+        bar: u64,
+        #[default(debug = "1", release = "2")]
+        baz: i8,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar, 7);
+    assert_eq!(foo.baz, if cfg!(debug_assertions) { 1 } else { 2 });
+}
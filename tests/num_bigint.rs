@@ -0,0 +1,38 @@
+#![cfg(feature = "num-bigint")]
+
+#[macro_use]
+extern crate specified_default_derive;
+extern crate num_bigint;
+
+use num_bigint::{BigInt, BigUint};
+
+#[derive(SpecifiedDefault)]
+#[specified_default(no_panic)]
+struct Group {
+    #[default = "123456789012345678901234567890"]
+    order: BigUint,
+    #[default = "0xffff_ffff_ffff_ffff_c90f_daa2_2168_c234"]
+    modulus: BigUint,
+    #[default = "-123456789012345678901234567890"]
+    offset: BigInt,
+    #[default = "128"]
+    positive: BigInt,
+    #[default = "-128"]
+    negative: BigInt,
+    #[default = "0"]
+    zero: num_bigint::BigInt,
+    #[default = "[0b101, 0o17]"]
+    small: [BigUint; 2],
+}
+
+#[test]
+fn big_integers_are_exact() {
+    let group = Group::default();
+    assert_eq!(group.order.to_string(), "123456789012345678901234567890");
+    assert_eq!(group.modulus, BigUint::parse_bytes(b"ffffffffffffffffc90fdaa22168c234", 16).unwrap());
+    assert_eq!(group.offset.to_string(), "-123456789012345678901234567890");
+    assert_eq!(group.positive, BigInt::from(128));
+    assert_eq!(group.negative, BigInt::from(-128));
+    assert_eq!(group.zero, BigInt::from(0));
+    assert_eq!(group.small, [BigUint::from(5u32), BigUint::from(15u32)]);
+}
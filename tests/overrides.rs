@@ -0,0 +1,68 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::collections::HashMap;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(overrides)]
+struct Window {
+    #[default = "640"]
+    width: u32,
+    #[default = "480"]
+    height: u32,
+
+    title: String,
+}
+
+#[test]
+fn no_overrides() {
+    let result = Window::default_with_overrides(&HashMap::new()).unwrap();
+    assert_eq!(result.width, 640);
+    assert_eq!(result.height, 480);
+    assert_eq!(result.title, "");
+}
+
+#[test]
+fn overrides_applied() {
+    let mut overrides = HashMap::new();
+    overrides.insert("height", "720");
+    overrides.insert("title", "main");
+
+    let result = Window::default_with_overrides(&overrides).unwrap();
+    assert_eq!(result.width, 640);
+    assert_eq!(result.height, 720);
+    assert_eq!(result.title, "main");
+}
+
+#[test]
+fn unknown_field() {
+    let mut overrides = HashMap::new();
+    overrides.insert("depth", "3");
+
+    let err = Window::default_with_overrides(&overrides).err().unwrap();
+    assert_eq!(err, "Unknown field depth");
+}
+
+#[test]
+fn invalid_value() {
+    let mut overrides = HashMap::new();
+    overrides.insert("width", "wide");
+
+    let err = Window::default_with_overrides(&overrides).err().unwrap();
+    assert!(err.starts_with("Failed to parse wide for width"));
+}
+
+#[test]
+fn generic_overrides() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(overrides)]
+    struct Foo<T> {
+        #[default = "1"]
+        bar: T,
+    }
+
+    let mut overrides = HashMap::new();
+    overrides.insert("bar", "2");
+
+    assert_eq!(Foo::<u8>::default_with_overrides(&overrides).unwrap().bar, 2);
+}
@@ -0,0 +1,37 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[test]
+fn cfg_gated_defaults() {
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    enum Backend {
+        #[cfg(windows)]
+        #[default]
+        Dx12,
+        #[cfg(not(windows))]
+        #[default]
+        Vulkan,
+        Software,
+    }
+
+    #[cfg(windows)]
+    assert_eq!(Backend::default(), Backend::Dx12);
+    #[cfg(not(windows))]
+    assert_eq!(Backend::default(), Backend::Vulkan);
+}
+
+#[test]
+fn cfg_attr_gated_defaults() {
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    enum Backend {
+        #[cfg_attr(debug_assertions, default)]
+        Checked,
+        #[cfg_attr(not(debug_assertions), default)]
+        Fast,
+    }
+
+    let expected = if cfg!(debug_assertions) { Backend::Checked } else { Backend::Fast };
+    assert_eq!(Backend::default(), expected);
+}
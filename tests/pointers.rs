@@ -0,0 +1,74 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::os::raw::{c_char, c_void};
+
+#[test]
+fn raw_pointers_default_to_null() {
+    #[repr(C)]
+    #[derive(SpecifiedDefault)]
+    struct Options {
+        name: *const c_char,
+        user_data: *mut c_void,
+        #[default = "3"]
+        retries: u32,
+    }
+
+    let options = Options::default();
+    assert!(options.name.is_null());
+    assert!(options.user_data.is_null());
+    assert_eq!(options.retries, 3);
+}
+
+#[test]
+fn explicit_null_marker() {
+    #[repr(C)]
+    #[derive(SpecifiedDefault)]
+    struct Options<T> {
+        #[default(null)]
+        next: *mut T,
+    }
+
+    assert!(Options::<String>::default().next.is_null());
+}
+
+#[test]
+fn null_pointers_in_const_default() {
+    #[repr(C)]
+    #[derive(SpecifiedDefault)]
+    #[specified_default(default_const)]
+    struct Options {
+        name: *const c_char,
+        #[default = "1"]
+        version: u8,
+    }
+
+    const OPTIONS: Options = Options::default_const();
+    assert!(OPTIONS.name.is_null());
+    assert_eq!(OPTIONS.version, 1);
+}
+
+#[test]
+fn weak_pointers_point_to_nothing() {
+    use std::{rc, sync};
+
+    #[derive(SpecifiedDefault)]
+    #[specified_default(default_const)]
+    struct Node<T> {
+        parent: rc::Weak<T>,
+        #[default(null)]
+        owner: sync::Weak<String>,
+        #[default = "1"]
+        depth: u32,
+    }
+
+    struct NoDefault;
+
+    let node = Node::<NoDefault>::default();
+    assert!(node.parent.upgrade().is_none());
+    assert!(node.owner.upgrade().is_none());
+    assert_eq!(node.depth, 1);
+
+    const NODE: Node<u8> = Node::default_const();
+    assert!(NODE.parent.upgrade().is_none());
+}
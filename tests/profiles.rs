@@ -0,0 +1,37 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[test]
+fn debug_and_release() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(debug = "trace", release = "warn")]
+        bar: String,
+    }
+
+    if cfg!(debug_assertions) {
+        assert_eq!(Foo::default().bar, "trace");
+    } else {
+        assert_eq!(Foo::default().bar, "warn");
+    }
+}
+
+#[test]
+fn debug_and_release_with_via() {
+    struct Timeout(u64);
+
+    impl From<u64> for Timeout {
+        fn from(secs: u64) -> Timeout {
+            Timeout(secs)
+        }
+    }
+
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(via = "u64", debug = "300", release = "30")]
+        bar: Timeout,
+    }
+
+    let expected = if cfg!(debug_assertions) { 300 } else { 30 };
+    assert_eq!(Foo::default().bar.0, expected);
+}
@@ -0,0 +1,59 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn prototype_builds_once() {
+    static BUILDS: AtomicUsize = AtomicUsize::new(0);
+
+    fn table() -> Vec<u64> {
+        BUILDS.fetch_add(1, Ordering::SeqCst);
+        (0..16).map(|bit| 1 << bit).collect()
+    }
+
+    #[derive(Clone, SpecifiedDefault)]
+    #[specified_default(prototype)]
+    struct Renderer {
+        #[default(expr = "table()")]
+        table: Vec<u64>,
+        #[default = "60"]
+        fps: u32,
+    }
+
+    let first = Renderer { fps: 30, ..Renderer::default() };
+    let second = Renderer::default();
+
+    assert_eq!(BUILDS.load(Ordering::SeqCst), 1);
+    assert_eq!(first.table, second.table);
+    assert_eq!(first.fps, 30);
+    assert_eq!(second.fps, 60);
+}
+
+#[test]
+fn prototype_enum() {
+    #[allow(dead_code)]
+    #[derive(Clone, Debug, PartialEq, SpecifiedDefault)]
+    #[specified_default(prototype)]
+    enum Level {
+        Debug,
+        #[default]
+        Info,
+    }
+
+    assert_eq!(Level::default(), Level::Info);
+    assert_eq!(Level::default(), Level::Info);
+}
+
+#[test]
+fn prototype_with_default_ref() {
+    #[derive(Clone, SpecifiedDefault)]
+    #[specified_default(prototype, default_ref)]
+    struct Limits {
+        #[default = "1024"]
+        max_connections: u32,
+    }
+
+    assert_eq!(Limits::default().max_connections, 1024);
+    assert_eq!(Limits::default_ref().max_connections, 1024);
+}
@@ -0,0 +1,54 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[test]
+fn raw_string_values() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = r#"{"a": 1, "b": [2, 3]}"#]
+        bar: String,
+        #[default(value = r#"C:\temp\"quoted""#)]
+        baz: String,
+        #[default = r#"{"a": 1}"#]
+        qux: Option<String>,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar, r#"{"a": 1, "b": [2, 3]}"#);
+    assert_eq!(foo.baz, r#"C:\temp\"quoted""#);
+    assert_eq!(foo.qux.as_deref(), Some(r#"{"a": 1}"#));
+}
+
+#[test]
+fn multi_line_values() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "
+            SELECT id, name
+              FROM users
+             WHERE active
+        "]
+        bar: String,
+        #[default = r"line one
+line two\n"]
+        baz: String,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar, "\n            SELECT id, name\n              FROM users\n             WHERE active\n        ");
+    assert_eq!(foo.baz, "line one\nline two\\n");
+}
+
+#[test]
+fn multi_line_lists() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = r#"[
+            "a, b",
+            "c",
+        ]"#]
+        bar: Vec<String>,
+    }
+
+    assert_eq!(Foo::default().bar, vec!["a, b", "c"]);
+}
@@ -0,0 +1,51 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::collections::HashMap;
+
+#[test]
+fn redundant_defaults_pass_through_specified_values() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(redundant_defaults = "deny")]
+    struct Retries {
+        #[default = "3"]
+        attempts: u32,
+        #[default = "-0.0"]
+        offset: f64,
+        #[default = "true"]
+        enabled: bool,
+        #[default = "retry"]
+        label: String,
+        #[default(value = "[1, 2]")]
+        backoff: Vec<u64>,
+        last_error: Option<String>,
+    }
+
+    let retries = Retries::default();
+    assert_eq!(retries.attempts, 3);
+    assert!(retries.offset.is_sign_negative());
+    assert!(retries.enabled);
+    assert_eq!(retries.label, "retry");
+    assert_eq!(retries.backoff, [1, 2]);
+    assert_eq!(retries.last_error, None);
+}
+
+#[test]
+#[allow(deprecated)]
+fn redundant_defaults_only_warn() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(redundant_defaults = "warn")]
+    struct Cache {
+        #[default = "0"]
+        hits: u64,
+        #[default(empty)]
+        entries: HashMap<String, u64>,
+        #[default = "64"]
+        capacity: usize,
+    }
+
+    let cache = Cache::default();
+    assert_eq!(cache.hits, 0);
+    assert!(cache.entries.is_empty());
+    assert_eq!(cache.capacity, 64);
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "regex")]
+
+#[macro_use]
+extern crate specified_default_derive;
+extern crate regex;
+
+use regex::Regex;
+
+#[derive(SpecifiedDefault)]
+struct Validator {
+    #[default(regex = r"^\w+$")]
+    username: Regex,
+    #[default(regex = "[0-9]{3}")]
+    digits: regex::bytes::Regex,
+}
+
+#[test]
+fn patterns_are_compiled() {
+    let validator = Validator::default();
+    assert!(validator.username.is_match("alice_01"));
+    assert!(!validator.username.is_match("not valid"));
+    assert!(validator.digits.is_match(b"a123"));
+}
+
+#[test]
+fn patterns_are_shared() {
+    assert_eq!(Validator::default().username.as_str(), Validator::default().username.as_str());
+}
@@ -0,0 +1,52 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+mod foreign {
+    pub struct Window {
+        pub width: u32,
+        pub height: u32,
+        pub title: String,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq)]
+    pub enum Mode {
+        Windowed,
+        Fullscreen,
+    }
+}
+
+#[test]
+fn remote_struct() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(remote = "foreign::Window")]
+    #[allow(dead_code)]
+    struct WindowDef {
+        #[default = "640"]
+        width: u32,
+        #[default = "480"]
+        height: u32,
+
+        title: String,
+    }
+
+    let result = WindowDef::default();
+    assert_eq!(result.width, 640);
+    assert_eq!(result.height, 480);
+    assert_eq!(result.title, "");
+}
+
+#[test]
+fn remote_enum() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(remote = "foreign::Mode")]
+    #[allow(dead_code)]
+    enum ModeDef {
+        Windowed,
+
+        #[default]
+        Fullscreen,
+    }
+
+    assert_eq!(ModeDef::default(), foreign::Mode::Fullscreen);
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "rust_decimal")]
+
+#[macro_use]
+extern crate specified_default_derive;
+extern crate rust_decimal;
+
+use rust_decimal::Decimal;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(no_panic, default_const)]
+struct Plan {
+    #[default = "19.99"]
+    price: Decimal,
+    #[default = "-0.0001"]
+    adjustment: Decimal,
+    #[default = "[1.5, 2]"]
+    tiers: [Decimal; 2],
+}
+
+#[test]
+fn decimals_are_exact() {
+    let plan = Plan::default();
+    assert_eq!(plan.price, Decimal::new(1999, 2));
+    assert_eq!(plan.adjustment, Decimal::new(-1, 4));
+    assert_eq!(plan.tiers, [Decimal::new(15, 1), Decimal::from(2)]);
+}
+
+#[test]
+fn decimals_can_be_const() {
+    const PLAN: Plan = Plan::default_const();
+    assert_eq!(PLAN.price.to_string(), "19.99");
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "secrecy")]
+
+#[macro_use]
+extern crate specified_default_derive;
+extern crate secrecy;
+
+use secrecy::{ExposeSecret, Secret, SecretString};
+
+#[derive(Debug, SpecifiedDefault)]
+struct Credentials {
+    #[default(secret = "hunter2")]
+    password: SecretString,
+    #[default(secret_env = "SECRECY_TEST_API_KEY", secret = "fallback")]
+    api_key: Secret<String>,
+    #[default(secret_env = "SECRECY_TEST_UNSET")]
+    token: SecretString,
+}
+
+#[test]
+fn secrets_are_wrapped() {
+    let credentials = Credentials::default();
+    assert_eq!(credentials.password.expose_secret(), "hunter2");
+    assert!(!format!("{:?}", credentials).contains("hunter2"));
+}
+
+#[test]
+fn secrets_are_read_from_the_environment() {
+    assert_eq!(Credentials::default().api_key.expose_secret(), "fallback");
+    std::env::set_var("SECRECY_TEST_API_KEY", "from-env");
+    assert_eq!(Credentials::default().api_key.expose_secret(), "from-env");
+    assert_eq!(Credentials::default().token.expose_secret(), "");
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "semver")]
+
+#[macro_use]
+extern crate specified_default_derive;
+extern crate semver;
+
+use std::str::FromStr;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(default_const)]
+struct Manifest {
+    #[default = "1.2.3"]
+    protocol: semver::Version,
+    #[default = "0.1.0"]
+    minimum: ::semver::Version,
+}
+
+#[derive(SpecifiedDefault)]
+struct Release {
+    #[default = "2.0.0-rc.1+build.5"]
+    version: semver::Version,
+}
+
+#[test]
+fn versions_are_built() {
+    let manifest = Manifest::default();
+    assert_eq!(manifest.protocol, semver::Version::new(1, 2, 3));
+    assert_eq!(Manifest::default_const().minimum, semver::Version::new(0, 1, 0));
+}
+
+#[test]
+fn pre_releases_keep_their_metadata() {
+    let version = Release::default().version;
+    assert_eq!(version.pre.as_str(), "rc.1");
+    assert_eq!(version.build.as_str(), "build.5");
+}
+
+#[test]
+fn other_version_types_are_parsed() {
+    #[derive(Debug, PartialEq)]
+    enum Version {
+        V1,
+        V2,
+    }
+
+    impl FromStr for Version {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Version, String> {
+            match s {
+                "v1" => Ok(Version::V1),
+                "v2" => Ok(Version::V2),
+                _ => Err(format!("unknown version {}", s)),
+            }
+        }
+    }
+
+    #[derive(SpecifiedDefault)]
+    struct Protocol {
+        #[default = "v1"]
+        version: Version,
+    }
+
+    assert_eq!(Protocol::default().version, Version::V1);
+    let _ = Version::V2;
+}
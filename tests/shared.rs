@@ -0,0 +1,48 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::sync::Arc;
+
+#[test]
+fn shared_value_is_built_once() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(shared, value = "[1, 2, 3]")]
+        bar: Arc<Vec<u32>>,
+        #[default = "42"]
+        baz: u32,
+    }
+
+    let first = Foo::default();
+    let second = Foo::default();
+
+    assert_eq!(*first.bar, vec![1, 2, 3]);
+    assert_eq!(first.baz, 42);
+    assert!(Arc::ptr_eq(&first.bar, &second.bar));
+}
+
+#[test]
+fn shared_value_with_type_hint() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(shared, ty = "String", value = "template")]
+        bar: Arc<dyn AsRef<str> + Send + Sync>,
+    }
+
+    assert_eq!((*Foo::default().bar).as_ref(), "template");
+}
+
+#[test]
+fn shared_value_per_field() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(shared, value = "bar")]
+        bar: Arc<String>,
+        #[default(shared, value = "baz")]
+        baz: Arc<String>,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(*foo.bar, "bar");
+    assert_eq!(*foo.baz, "baz");
+}
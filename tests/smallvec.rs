@@ -0,0 +1,28 @@
+#![cfg(feature = "smallvec")]
+
+#[macro_use]
+extern crate specified_default_derive;
+extern crate smallvec;
+
+use smallvec::SmallVec;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(no_panic)]
+struct Route {
+    #[default = "[1, 2, 3]"]
+    hops: SmallVec<[u32; 4]>,
+    #[default = "[\"a\", \"b\"]"]
+    labels: smallvec::SmallVec<[&'static str; 2]>,
+    #[default = "[]"]
+    empty: SmallVec<[u8; 8]>,
+}
+
+#[test]
+fn collections_are_inline() {
+    let route = Route::default();
+    assert_eq!(&route.hops[..], &[1, 2, 3]);
+    assert!(!route.hops.spilled());
+    assert_eq!(&route.labels[..], &["a", "b"]);
+    assert!(!route.labels.spilled());
+    assert!(route.empty.is_empty());
+}
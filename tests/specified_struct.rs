@@ -0,0 +1,61 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+const SCENES: u32 = 2;
+
+#[allow(non_upper_case_globals)]
+const empty: u32 = 7;
+
+specified_struct! {
+    /// A window's settings.
+    #[derive(Debug, PartialEq)]
+    pub struct Config {
+        pub width: u32 = 640,
+        pub height: u32 = 480,
+        scenes: u32,
+        #[default = "untitled"]
+        title: String,
+        layers: Vec<u8> = vec![1, 2],
+    }
+
+    #[specified_default(default_const)]
+    struct Limits {
+        depth: u32 = SCENES * 4,
+        retries: u8 = 3,
+    }
+
+    struct Slot<T> where T: Copy {
+        size: usize = 16,
+        value: T,
+    }
+
+    struct Counter {
+        count: u32 = empty,
+    }
+}
+
+#[test]
+fn initializers_are_defaults() {
+    let config = Config::default();
+    assert_eq!((config.width, config.height, config.scenes), (640, 480, 0));
+    assert_eq!(config.title, "untitled");
+    assert_eq!(config.layers, [1, 2]);
+}
+
+#[test]
+fn container_keys_are_kept() {
+    const LIMITS: Limits = Limits::default_const();
+    assert_eq!((LIMITS.depth, LIMITS.retries), (8, 3));
+    assert_eq!(Limits::default().depth, 8);
+}
+
+#[test]
+fn generic_structs() {
+    let slot = Slot::<u8>::default();
+    assert_eq!((slot.size, slot.value), (16, 0));
+}
+
+#[test]
+fn values_named_like_keys_are_expressions() {
+    assert_eq!(Counter::default().count, 7);
+}
@@ -0,0 +1,55 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::num::NonZeroU16;
+
+#[test]
+fn try_via_integers() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(try_via = "u32", value = "8080")]
+        bar: u16,
+        #[default(try_via = "i64", value = "-5")]
+        baz: i8,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar, 8080);
+    assert_eq!(foo.baz, -5);
+}
+
+#[test]
+fn try_via_fallible_type() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(try_via = "u16", value = "443")]
+        bar: NonZeroU16,
+    }
+
+    assert_eq!(Foo::default().bar.get(), 443);
+}
+
+#[test]
+#[should_panic(expected = "Failed to convert 0")]
+fn try_via_failure_panics() {
+    #[allow(dead_code)]
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(try_via = "u16", value = "0")]
+        bar: NonZeroU16,
+    }
+
+    Foo::default();
+}
+
+#[test]
+fn try_via_no_panic() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(no_panic)]
+    struct Foo {
+        #[default(try_via = "u64", value = "255")]
+        bar: u8,
+    }
+
+    assert_eq!(Foo::default().bar, 255);
+}
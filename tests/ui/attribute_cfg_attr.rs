@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[specified_default]
+struct Foo {
+    #[cfg_attr(debug_assertions, default = "1")]
+    bar: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+struct Header {
+    #[default(base64 = "iVBORw==")]
+    magic: [u8; 8],
+}
+
+fn main() {}
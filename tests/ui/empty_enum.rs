@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+enum Never {}
+
+#[derive(SpecifiedDefault)]
+enum Gated {
+    #[cfg(any())]
+    #[default]
+    Unsupported,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(Debug)]
+enum Compression {
+    None,
+    Zstd,
+}
+
+#[derive(SpecifiedDefault)]
+struct Foo {
+    #[default(variant = "Compression::Zsdt")]
+    bar: Compression,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+struct Handle;
+
+#[derive(SpecifiedDefault)]
+struct Foo {
+    #[default = "1"]
+    bar: u32,
+    #[default = "stdout"]
+    handle: Handle,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+struct Settings {
+    #[default(from_ctx = "tenant_id")]
+    tenant_id: u64,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(from_docs)]
+struct Foo {
+    /// Default: 640
+    #[default = "800"]
+    width: u32,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[macro_use]
+extern crate specified_default_derive;
+extern crate heapless;
+
+#[derive(SpecifiedDefault)]
+struct Firmware {
+    #[default = "[1, 2, 3]"]
+    pins: heapless::Vec<u8, 2>,
+    #[default = "sensor-01"]
+    name: heapless::String<4>,
+}
+
+fn main() {}
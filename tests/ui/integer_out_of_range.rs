@@ -0,0 +1,15 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(default_const)]
+struct Foo {
+    #[default = "340282366920938463463374607431768211456"]
+    bar: u128,
+    #[default = "170141183460469231731687303715884105728"]
+    baz: i128,
+    #[default = "7u16"]
+    qux: u8,
+}
+
+fn main() {}
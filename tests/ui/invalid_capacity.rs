@@ -0,0 +1,19 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+struct Buffer {
+    #[default(capacity = "-1")]
+    bytes: Vec<u8>,
+    #[default(capacity = "18446744073709551616")]
+    line: String,
+}
+
+#[derive(SpecifiedDefault)]
+#[specified_default(default_const)]
+struct Pool {
+    #[default(capacity = "8")]
+    workers: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+struct Theme {
+    #[default = "#ff88"]
+    accent: [u8; 3],
+    #[default = "#ff880080"]
+    text: [u8; 3],
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::collections::{HashMap, HashSet};
+
+#[derive(SpecifiedDefault)]
+struct Router {
+    #[default = "{a = 1, a = 2}"]
+    routes: HashMap<String, u8>,
+    #[default = "{a = 1, b}"]
+    limits: HashMap<String, u8>,
+    #[default = "[x, y, x]"]
+    tags: HashSet<char>,
+}
+
+fn main() {}
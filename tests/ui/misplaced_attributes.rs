@@ -0,0 +1,20 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+#[default = "1"]
+struct Foo {
+    bar: u32,
+}
+
+#[derive(SpecifiedDefault)]
+enum Bar {
+    #[default = "Baz"]
+    Baz,
+    Qux {
+        #[default = "1"]
+        quux: u32,
+    },
+}
+
+fn main() {}
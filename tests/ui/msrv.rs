@@ -0,0 +1,41 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::sync::Arc;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(msrv = "1.36", default_ref)]
+struct Limits {
+    #[default = "1024"]
+    max_connections: u32,
+}
+
+#[derive(SpecifiedDefault)]
+#[specified_default(msrv = "1.69")]
+struct Renderer {
+    #[default(shared, value = "[1, 2, 4]")]
+    table: Arc<Vec<u64>>,
+}
+
+#[derive(SpecifiedDefault)]
+#[specified_default(msrv = "2018")]
+struct Edition {
+    #[default = "1"]
+    value: u32,
+}
+
+#[derive(SpecifiedDefault)]
+#[specified_default(msrv = "1.45", default_const)]
+struct Profile {
+    #[default(debug = "1", release = "3")]
+    opt_level: u32,
+}
+
+#[derive(SpecifiedDefault)]
+#[specified_default(msrv = "1.32", default_const)]
+struct Ancient {
+    #[default = "1"]
+    value: u32,
+}
+
+fn main() {}
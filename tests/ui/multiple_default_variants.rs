@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+enum Backend {
+    #[default]
+    Vulkan,
+    #[default]
+    Software,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::net::IpAddr;
+
+#[derive(SpecifiedDefault)]
+struct Server {
+    #[default(1.5 as u32)]
+    retries: u32,
+    #[default = ""]
+    address: IpAddr,
+    #[default = ""]
+    name: String,
+    #[default(debug = "info", release = "info")]
+    level: String,
+}
+
+fn main() {
+    // Warnings alone don't fail the build, so this error lets them be
+    // compared.
+    let _: () = Server::default();
+}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate specified_default_derive;
+extern crate heapless;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(no_panic)]
+struct Buffer<const N: usize> {
+    #[default = "[7, 8]"]
+    bytes: heapless::Vec<u8, N>,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(no_panic)]
+struct Foo {
+    #[default = "127.0.0.1:80"]
+    bar: std::net::SocketAddr,
+    #[default = "eight"]
+    baz: u32,
+    #[default = "1e40"]
+    qux: f32,
+}
+
+fn main() {}
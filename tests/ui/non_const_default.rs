@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(default_const)]
+struct Foo {
+    #[default = "bar"]
+    bar: String,
+    #[default = "300"]
+    baz: u8,
+    qux: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+struct Foo {
+    #[default(null)]
+    bar: Option<u32>,
+}
+
+fn main() {}
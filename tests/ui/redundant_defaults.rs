@@ -0,0 +1,22 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(redundant_defaults = "deny")]
+struct Retries {
+    #[default = "0"]
+    attempts: u32,
+    #[default = "false"]
+    enabled: bool,
+    #[default(empty)]
+    backoff: Vec<u64>,
+}
+
+#[derive(SpecifiedDefault)]
+#[specified_default(redundant_defaults = "forbid")]
+struct Level {
+    #[default = "1"]
+    value: u32,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+struct Frame {
+    #[default(repeat = "0xFF; 4")]
+    preamble: [u8; 8],
+}
+
+#[derive(SpecifiedDefault)]
+struct Buffer {
+    #[default(repeat = "0")]
+    padding: Vec<u8>,
+}
+
+#[derive(SpecifiedDefault)]
+struct Name {
+    #[default(repeat = "a; 3")]
+    text: String,
+}
+
+fn main() {}
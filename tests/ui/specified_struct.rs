@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+specified_struct! {
+    struct Config {
+        #[default = "480"]
+        height: u32 = 640,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+struct Foo {
+    #[default(try_via = "u32", value = "300")]
+    bar: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(overides)]
+struct Foo {
+    bar: u32,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(discriminant = 4)]
+enum Status {
+    Ok = 1,
+    Retry,
+    Failed,
+}
+
+fn main() {}
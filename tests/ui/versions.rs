@@ -0,0 +1,22 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(SpecifiedDefault)]
+struct Unpaired {
+    #[default(value = "10", since = "2.0")]
+    max_connections: u32,
+}
+
+#[derive(SpecifiedDefault)]
+struct Expression {
+    #[default(expr = "10", since = "2.0", previously = "5")]
+    max_connections: u32,
+}
+
+#[derive(SpecifiedDefault)]
+struct Unversioned {
+    #[default(value = "10", since = "two", previously = "5")]
+    max_connections: u32,
+}
+
+fn main() {}
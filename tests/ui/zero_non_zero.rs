@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::num::NonZeroU32;
+
+#[derive(SpecifiedDefault)]
+struct Foo {
+    #[default = "0"]
+    bar: NonZeroU32,
+}
+
+fn main() {}
@@ -0,0 +1,52 @@
+#![cfg(feature = "url")]
+
+#[macro_use]
+extern crate specified_default_derive;
+extern crate url;
+
+use std::str::FromStr;
+
+#[derive(SpecifiedDefault)]
+#[specified_default(no_panic)]
+struct Client {
+    #[default = "https://api.example.com/v1"]
+    endpoint: url::Url,
+    #[default = "HTTP://Example.com:80/a/../b"]
+    normalised: ::url::Url,
+    #[default = "wss://stream.example.com"]
+    stream: Option<url::Url>,
+}
+
+#[test]
+fn urls_are_built() {
+    let client = Client::default();
+    assert_eq!(client.endpoint.host_str(), Some("api.example.com"));
+    assert_eq!(client.stream.unwrap().scheme(), "wss");
+}
+
+#[test]
+fn urls_are_normalised() {
+    assert_eq!(Client::default().normalised.as_str(), "http://example.com/b");
+}
+
+#[test]
+fn other_url_types_are_parsed() {
+    #[derive(Debug, PartialEq)]
+    struct Url(String);
+
+    impl FromStr for Url {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Url, String> {
+            Ok(Url(s.to_owned()))
+        }
+    }
+
+    #[derive(SpecifiedDefault)]
+    struct Link {
+        #[default = "not a url"]
+        target: Url,
+    }
+
+    assert_eq!(Link::default().target, Url("not a url".to_owned()));
+}
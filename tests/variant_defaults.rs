@@ -0,0 +1,78 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(Debug, PartialEq, SpecifiedDefault)]
+#[specified_default(variant_defaults)]
+enum Transport {
+    #[default]
+    Tcp {
+        #[default = "localhost"]
+        host: String,
+        #[default = "8080"]
+        port: u16,
+    },
+    UnixSocket(#[default = "/tmp/app.sock"] String),
+    InMemory,
+    Quic {
+        #[default = "443"]
+        port: u16,
+        streams: Vec<u32>,
+    },
+}
+
+#[test]
+fn default_variant_is_built_from_its_fields() {
+    assert_eq!(Transport::default(), Transport::Tcp { host: "localhost".to_owned(), port: 8080 });
+}
+
+#[test]
+fn every_variant_has_a_constructor() {
+    assert_eq!(Transport::default_tcp(), Transport::default());
+    assert_eq!(Transport::default_unix_socket(), Transport::UnixSocket("/tmp/app.sock".to_owned()));
+    assert_eq!(Transport::default_in_memory(), Transport::InMemory);
+    assert_eq!(Transport::default_quic(), Transport::Quic { port: 443, streams: Vec::new() });
+}
+
+#[test]
+fn variants_are_looked_up_by_name() {
+    assert_eq!(Transport::default_for("Tcp"), Some(Transport::default()));
+    assert_eq!(Transport::default_for("unix_socket"), Some(Transport::default_unix_socket()));
+    assert_eq!(Transport::default_for("UnixSocket"), Some(Transport::default_unix_socket()));
+    assert_eq!(Transport::default_for("in_memory"), Some(Transport::InMemory));
+    assert_eq!(Transport::default_for("quic"), Some(Transport::default_quic()));
+    assert_eq!(Transport::default_for("udp"), None);
+    assert_eq!(Transport::default_for("TCP"), None);
+}
+
+#[test]
+fn acronyms_are_one_word() {
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    #[specified_default(variant_defaults)]
+    enum Endpoint {
+        #[default]
+        HTTPServer(#[default = "80"] u16),
+        Ipv6Only,
+        TCPProxy,
+    }
+
+    assert_eq!(Endpoint::default_http_server(), Endpoint::HTTPServer(80));
+    assert_eq!(Endpoint::default_ipv6_only(), Endpoint::Ipv6Only);
+    assert_eq!(Endpoint::default_tcp_proxy(), Endpoint::TCPProxy);
+    assert_eq!(Endpoint::default_for("http_server"), Some(Endpoint::HTTPServer(80)));
+    assert_eq!(Endpoint::default_for("HTTPServer"), Some(Endpoint::HTTPServer(80)));
+}
+
+#[test]
+fn generic_variants() {
+    #[derive(Debug, PartialEq, SpecifiedDefault)]
+    #[specified_default(variant_defaults)]
+    enum Slot<T> {
+        Empty,
+        #[default]
+        Full(#[default = "7"] T),
+    }
+
+    assert_eq!(Slot::<u8>::default(), Slot::Full(7));
+    assert_eq!(Slot::<u8>::default_empty(), Slot::Empty);
+    assert_eq!(Slot::<u8>::default_for("full"), Some(Slot::Full(7)));
+}
@@ -0,0 +1,50 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq)]
+enum Compression {
+    None,
+    Gzip(u8),
+    Zstd,
+}
+
+#[test]
+fn unit_variant() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(variant = "Compression::Zstd")]
+        bar: Compression,
+    }
+
+    assert_eq!(Foo::default().bar, Compression::Zstd);
+}
+
+#[test]
+fn variant_with_data() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(variant = "Compression::Gzip(6)")]
+        bar: Compression,
+    }
+
+    assert_eq!(Foo::default().bar, Compression::Gzip(6));
+}
+
+#[test]
+fn different_variants_per_struct() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default(variant = "Compression::None")]
+        bar: Compression,
+    }
+
+    #[derive(SpecifiedDefault)]
+    struct Baz {
+        #[default(variant = "Compression::Zstd")]
+        bar: Compression,
+    }
+
+    assert_eq!(Foo::default().bar, Compression::None);
+    assert_eq!(Baz::default().bar, Compression::Zstd);
+}
@@ -0,0 +1,52 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[derive(Debug, PartialEq, SpecifiedDefault)]
+struct Pool {
+    #[default(value = "10", since = "2.0", previously = "5")]
+    max_connections: u32,
+    #[default(value = "60", since = "1.3.1", previously = "30", via = "u16")]
+    idle_timeout: u64,
+    #[default = "primary"]
+    name: String,
+    retries: u8,
+}
+
+#[test]
+fn current_versions_give_the_default() {
+    assert_eq!(Pool::default_for_version("2.0"), Pool::default());
+    assert_eq!(Pool::default_for_version("2"), Pool::default());
+    assert_eq!(Pool::default_for_version("3.1.4"), Pool::default());
+}
+
+#[test]
+fn older_versions_give_previous_defaults() {
+    let pool = Pool::default_for_version("1.9");
+    assert_eq!(pool.max_connections, 5);
+    assert_eq!(pool.idle_timeout, 60);
+    assert_eq!(pool.name, "primary");
+    assert_eq!(pool.retries, 0);
+
+    let pool = Pool::default_for_version("1.3");
+    assert_eq!((pool.max_connections, pool.idle_timeout), (5, 30));
+    assert_eq!(Pool::default_for_version("1.3.1").idle_timeout, 60);
+    assert_eq!(Pool::default_for_version("1.10").max_connections, 5);
+}
+
+#[test]
+#[should_panic(expected = "`2.x` isn't a version such as `2.0`")]
+fn invalid_versions_panic() {
+    Pool::default_for_version("2.x");
+}
+
+#[test]
+fn generic_structs() {
+    #[derive(SpecifiedDefault)]
+    struct Slot<T> {
+        #[default(value = "8", since = "0.2", previously = "4")]
+        size: T,
+    }
+
+    assert_eq!(Slot::<u32>::default_for_version("0.1").size, 4);
+    assert_eq!(Slot::<u32>::default_for_version("0.2").size, 8);
+}
@@ -0,0 +1,103 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+use std::num::{NonZero, NonZeroI64, NonZeroU32};
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[test]
+fn non_zero_integers() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "8"]
+        bar: NonZeroU32,
+        #[default = "-3"]
+        baz: NonZeroI64,
+        #[default = "16"]
+        qux: NonZero<u8>,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar.get(), 8);
+    assert_eq!(foo.baz.get(), -3);
+    assert_eq!(foo.qux.get(), 16);
+}
+
+#[test]
+fn optional_wrappers() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "8"]
+        bar: Option<NonZeroU32>,
+        #[default = "64"]
+        baz: Option<Box<u64>>,
+        #[default = "[1, 2]"]
+        qux: Vec<Option<NonZeroU32>>,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(foo.bar, NonZeroU32::new(8));
+    assert_eq!(foo.baz, Some(Box::new(64)));
+    assert_eq!(foo.qux, vec![NonZeroU32::new(1), NonZeroU32::new(2)]);
+}
+
+#[test]
+fn smart_pointers() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "1.5"]
+        bar: Box<f64>,
+        #[default = "baz"]
+        baz: Rc<String>,
+        #[default = "[1, 2, 3]"]
+        qux: Arc<Vec<u8>>,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(*foo.bar, 1.5);
+    assert_eq!(*foo.baz, "baz");
+    assert_eq!(*foo.qux, vec![1, 2, 3]);
+}
+
+#[test]
+fn slice_pointers() {
+    #[derive(SpecifiedDefault)]
+    struct Foo {
+        #[default = "[1, 2, 3]"]
+        bar: Box<[u8]>,
+        #[default = "[a, b]"]
+        baz: Rc<[String]>,
+        #[default = "[]"]
+        qux: Arc<[u32]>,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(&*foo.bar, &[1, 2, 3]);
+    assert_eq!(&*foo.baz, &["a".to_owned(), "b".to_owned()]);
+    assert!(foo.qux.is_empty());
+}
+
+#[test]
+fn str_pointers() {
+    #[derive(SpecifiedDefault)]
+    #[specified_default(no_panic)]
+    struct Foo {
+        #[default = "main"]
+        bar: Box<str>,
+        #[default = "shared"]
+        baz: Rc<str>,
+        #[default = ""]
+        qux: Arc<str>,
+        #[default = "[x, y]"]
+        names: Vec<Arc<str>>,
+        #[default = "maybe"]
+        label: Option<Box<str>>,
+    }
+
+    let foo = Foo::default();
+    assert_eq!(&*foo.bar, "main");
+    assert_eq!(&*foo.baz, "shared");
+    assert!(foo.qux.is_empty());
+    assert_eq!(foo.names, vec![Arc::from("x"), Arc::from("y")]);
+    assert_eq!(foo.label.as_deref(), Some("maybe"));
+}
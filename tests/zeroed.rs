@@ -0,0 +1,39 @@
+#[macro_use]
+extern crate specified_default_derive;
+
+#[test]
+fn zeroed_arrays() {
+    #[derive(SpecifiedDefault)]
+    struct Frame {
+        #[default(zeroed)]
+        pixels: [[u8; 4]; 1024],
+        #[default(zeroed)]
+        depth: [f32; 1024],
+        #[default(zeroed)]
+        header: (u32, bool, char),
+        #[default = "60"]
+        rate: u32,
+    }
+
+    let frame = Frame::default();
+    assert!(frame.pixels.iter().all(|pixel| *pixel == [0; 4]));
+    assert!(frame.depth.iter().all(|&depth| depth == 0.0));
+    assert_eq!(frame.header, (0, false, '\0'));
+    assert_eq!(frame.rate, 60);
+}
+
+#[test]
+fn zeroed_boxes() {
+    #[derive(SpecifiedDefault)]
+    struct Buffers {
+        #[default(zeroed)]
+        audio: Box<[i16; 4 * 1024 * 1024]>,
+        #[default(zeroed)]
+        empty: Box<[u64; 0]>,
+    }
+
+    let buffers = Buffers::default();
+    assert_eq!(buffers.audio.len(), 4 * 1024 * 1024);
+    assert!(buffers.audio.iter().all(|&sample| sample == 0));
+    assert!(buffers.empty.is_empty());
+}